@@ -195,7 +195,7 @@ impl MainUi for CounterApp {
     fn handle_task_message(
         &mut self,
         task_name: &str,
-        message: Box<dyn std::any::Any + Send>,
+        message: std::sync::Arc<dyn std::any::Any + Send + Sync>,
         _ctx: &mut AppContext,
     ) -> bool {
         if task_name == "ticker" {
@@ -220,7 +220,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Build the application
     let app = AppBuilder::new()
         .main_ui(CounterApp::new())
-        //.add_task("ticker", TickerTask::new(Duration::from_secs(1)))
+        .add_task("ticker", TickerTask::new(Duration::from_secs(1)))
         .mouse_capture(true) // Enable mouse capture (default)
         .build()?;
 
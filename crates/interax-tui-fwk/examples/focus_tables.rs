@@ -6,21 +6,23 @@
 //! - Left/Right to switch focus between tables
 //! - Active table has selected row highlighted in an active color
 //! - Inactive table has selected row shown in a dimmed color
+//! - Using the built-in `SelectableTable` widget inside a `Tab`
 //!
 //! Controls:
 //! - Tab: Switch between tabs
 //! - Left/Right: Switch focus between tables (in Data tab)
 //! - Up/Down: Navigate rows in the focused table
+//! - </>: Select column, s: Sort, +/-: Resize column
 //! - q/Ctrl+C: Quit
 
 use interax_tui_fwk::{
-    AppBuilder, AppContext, Component, DrawContext, Event, EventResult, KeyCode, MainUi, Tab,
-    TabEventContext,
+    AppBuilder, AppContext, Component, DrawContext, Event, EventResult, KeyCode, MainUi,
+    SelectableTable, Tab, TabEventContext, TableColumn, Theme,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
@@ -66,105 +68,30 @@ impl Tab for WelcomeTab {
 // Data Tab with Two Tables
 // =============================================================================
 
-/// A simple table widget that can be focused.
-struct FocusableTable {
-    title: String,
-    items: Vec<(String, String, String)>,
-    selected: usize,
-}
-
-impl FocusableTable {
-    fn new(title: &str, items: Vec<(String, String, String)>) -> Self {
-        Self {
-            title: title.to_string(),
-            items,
-            selected: 0,
-        }
-    }
-
-    fn select_next(&mut self) {
-        if !self.items.is_empty() {
-            self.selected = (self.selected + 1) % self.items.len();
-        }
-    }
-
-    fn select_prev(&mut self) {
-        if !self.items.is_empty() {
-            self.selected = (self.selected + self.items.len() - 1) % self.items.len();
-        }
-    }
-
-    fn draw(&self, frame: &mut Frame, area: Rect, is_focused: bool) {
-        // Define colors based on focus state
-        let (border_color, selected_style, header_style) = if is_focused {
-            (
-                Color::Yellow,
-                Style::default()
-                    .bg(Color::Yellow)
-                    .fg(Color::Black)
-                    .add_modifier(Modifier::BOLD),
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )
-        } else {
-            (
-                Color::DarkGray,
-                Style::default().bg(Color::DarkGray).fg(Color::Black),
-                Style::default().fg(Color::DarkGray),
-            )
-        };
-
-        // Create rows
-        let rows: Vec<Row> = self
-            .items
-            .iter()
-            .enumerate()
-            .map(|(i, (col1, col2, col3))| {
-                let style = if i == self.selected {
-                    selected_style
-                } else {
-                    Style::default().fg(Color::White)
-                };
-                Row::new(vec![col1.clone(), col2.clone(), col3.clone()]).style(style)
-            })
-            .collect();
-
-        // Create table
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
-            ],
-        )
-        .header(
-            Row::new(vec!["Name", "Value", "Status"])
-                .style(header_style)
-                .bottom_margin(1),
-        )
-        .block(
-            Block::default()
-                .title(format!(
-                    " {} {} ",
-                    self.title,
-                    if is_focused { "●" } else { "○" }
-                ))
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(border_color)),
-        )
-        .row_highlight_style(selected_style);
-
-        // Render the table (selection is handled via row styling)
-        frame.render_widget(table, area);
-    }
+/// Create a table with the Name/Value/Status columns.
+fn table(id: &str, title: &str, items: Vec<(String, String, String)>) -> SelectableTable {
+    let mut table = SelectableTable::new(
+        id,
+        vec![
+            TableColumn::new("Name", 12),
+            TableColumn::new("Value", 10),
+            TableColumn::new("Status", 10),
+        ],
+    )
+    .title(title);
+    table.set_rows(
+        items
+            .into_iter()
+            .map(|(name, value, status)| vec![name, value, status])
+            .collect(),
+    );
+    table
 }
 
 /// The Data tab containing two side-by-side tables.
 struct DataTab {
-    left_table: FocusableTable,
-    right_table: FocusableTable,
+    left_table: SelectableTable,
+    right_table: SelectableTable,
     /// Which table is focused: 0 = left, 1 = right
     focused_table: usize,
 }
@@ -211,13 +138,13 @@ impl DataTab {
         ];
 
         Self {
-            left_table: FocusableTable::new("Items", left_items),
-            right_table: FocusableTable::new("Servers", right_items),
+            left_table: table("items", "Items", left_items),
+            right_table: table("servers", "Servers", right_items),
             focused_table: 0,
         }
     }
 
-    fn focused_table_mut(&mut self) -> &mut FocusableTable {
+    fn focused_table_mut(&mut self) -> &mut SelectableTable {
         if self.focused_table == 0 {
             &mut self.left_table
         } else {
@@ -243,10 +170,11 @@ impl Tab for DataTab {
             .split(area);
 
         // Draw tables with focus state
+        let theme = Theme::default();
         self.left_table
-            .draw(frame, chunks[0], self.focused_table == 0);
+            .render(frame, chunks[0], self.focused_table == 0, &theme);
         self.right_table
-            .draw(frame, chunks[1], self.focused_table == 1);
+            .render(frame, chunks[1], self.focused_table == 1, &theme);
     }
 
     fn handle_event(&mut self, event: &Event, _ctx: &mut TabEventContext) -> EventResult {
@@ -265,16 +193,8 @@ impl Tab for DataTab {
                     }
                     EventResult::Handled
                 }
-                // Navigate within focused table
-                KeyCode::Up => {
-                    self.focused_table_mut().select_prev();
-                    EventResult::Handled
-                }
-                KeyCode::Down => {
-                    self.focused_table_mut().select_next();
-                    EventResult::Handled
-                }
-                _ => EventResult::Unhandled,
+                // Navigate, sort and resize within the focused table
+                _ => self.focused_table_mut().handle_input(event),
             }
         } else {
             EventResult::Unhandled
@@ -316,7 +236,7 @@ impl Component for FocusTablesApp {
         let tabs_ctx = ctx.tabs();
         let active_tab = tabs_ctx.active_id().unwrap_or("unknown");
         let footer_text = if active_tab == "data" {
-            "Tab: Switch tabs | ←/→: Switch table focus | ↑/↓: Navigate rows | q: Quit"
+            "Tab: Switch tabs | ←/→: Switch table focus | ↑/↓: Navigate rows | s: Sort | q: Quit"
         } else {
             "Tab: Switch tabs | q: Quit"
         };
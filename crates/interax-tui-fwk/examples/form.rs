@@ -0,0 +1,151 @@
+//! Form example demonstrating the `Form` widget in interax-tui-fwk.
+//!
+//! This example shows:
+//! - Building a form from text, number, checkbox, select and radio fields
+//! - Per-field validators and a cross-field validator
+//! - Turning the submitted values into a typed command
+//!
+//! Controls:
+//! - Tab/Shift+Tab or Up/Down: Move between fields
+//! - Space: Toggle the checkbox
+//! - Left/Right: Change select and radio fields
+//! - Enter: Submit
+//! - Esc/Ctrl+C: Quit
+
+use interax_tui_fwk::{
+    AppBuilder, AppContext, Component, DrawContext, Event, EventResult, Field, FieldValue, Form,
+    FormError, KeyCode, MainUi,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// A command invocation built from the form values.
+struct Invoke {
+    endpoint: u16,
+    cluster: String,
+    command: String,
+    timed: bool,
+    timeout_ms: Option<u32>,
+    payload: String,
+}
+
+fn invoke_form() -> Form<Invoke> {
+    Form::new("invoke")
+        .title("Invoke command")
+        .submit_label("Invoke")
+        .field(
+            Field::number("endpoint", "Endpoint")
+                .range(0.0, 65534.0)
+                .required()
+                .placeholder("0-65534"),
+        )
+        .field(Field::select(
+            "cluster",
+            "Cluster",
+            ["OnOff", "LevelControl", "Identify"],
+        ))
+        .field(
+            Field::text("command", "Command")
+                .value("Toggle")
+                .required()
+                .validator(|value| match value {
+                    FieldValue::Text(text) if text.contains(' ') => {
+                        Err("no spaces allowed".to_string())
+                    }
+                    _ => Ok(()),
+                }),
+        )
+        .field(Field::checkbox("timed", "Timed"))
+        .field(Field::number("timeout", "Timeout (ms)").placeholder("required when timed"))
+        .field(Field::radio("payload", "Payload", ["None", "TLV", "JSON"]))
+        .validator(|values| {
+            if values.bool("timed") == Some(true) && values.number("timeout").is_none() {
+                return Err(FormError::field("timeout", "required for timed commands"));
+            }
+            Ok(())
+        })
+        .on_submit(|values| {
+            Ok(Invoke {
+                endpoint: values.number("endpoint").unwrap_or_default() as u16,
+                cluster: values.choice("cluster").unwrap_or_default().to_string(),
+                command: values.text("command").unwrap_or_default().to_string(),
+                timed: values.bool("timed").unwrap_or_default(),
+                timeout_ms: values.number("timeout").map(|ms| ms as u32),
+                payload: values.choice("payload").unwrap_or_default().to_string(),
+            })
+        })
+}
+
+/// The main application holding the form and the last invocation.
+struct FormApp {
+    form: Form<Invoke>,
+    last: Option<Invoke>,
+}
+
+impl Component for FormApp {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(12), Constraint::Min(3)])
+            .split(area);
+
+        self.form.draw(frame, chunks[0], ctx);
+
+        let text = match &self.last {
+            Some(invoke) => format!(
+                "{}::{} on endpoint {} (timed: {}, timeout: {:?} ms, payload: {})",
+                invoke.cluster,
+                invoke.command,
+                invoke.endpoint,
+                invoke.timed,
+                invoke.timeout_ms,
+                invoke.payload
+            ),
+            None => "Fill in the form and press Enter to invoke.".to_string(),
+        };
+        let output = Paragraph::new(text)
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .title("Last invocation")
+                    .borders(Borders::ALL),
+            );
+        frame.render_widget(output, chunks[1]);
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if event.is_quit() || event.is_key(KeyCode::Esc) {
+            ctx.quit();
+            return EventResult::Handled;
+        }
+
+        let result = self.form.handle_event(event, ctx);
+        if let Some(invoke) = self.form.take_submitted() {
+            self.last = Some(invoke);
+        }
+        result
+    }
+}
+
+impl MainUi for FormApp {}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let app = AppBuilder::new()
+        .main_ui(FormApp {
+            form: invoke_form(),
+            last: None,
+        })
+        .register_focus("invoke")
+        .initial_focus("invoke")
+        .mouse_capture(false)
+        .build()?;
+
+    app.run().await?;
+
+    Ok(())
+}
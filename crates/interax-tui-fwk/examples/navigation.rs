@@ -0,0 +1,267 @@
+//! Navigation example demonstrating the screen router in interax-tui-fwk.
+//!
+//! This example shows:
+//! - Registering named routes with parameters (`endpoint/:id`)
+//! - Pushing screens from a tab via `TabEventContext::navigate()`
+//! - Drill-down flows (list → detail → sub-detail)
+//! - Back/forward history and breadcrumbs
+//!
+//! Controls:
+//! - Up/Down: Select an endpoint (in the list)
+//! - Enter: Open the selected endpoint / drill down
+//! - Esc/Backspace: Go back
+//! - f: Go forward
+//! - q/Ctrl+C: Quit
+
+use interax_tui_fwk::{
+    AppBuilder, AppContext, Component, DrawContext, Event, EventResult, KeyCode, MainUi, Screen,
+    Tab, TabEventContext,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+// =============================================================================
+// Tabs
+// =============================================================================
+
+/// Tab listing the known endpoints.
+struct EndpointListTab {
+    endpoints: Vec<u32>,
+    selected: usize,
+}
+
+impl EndpointListTab {
+    fn new() -> Self {
+        Self {
+            endpoints: vec![1, 2, 7, 42],
+            selected: 0,
+        }
+    }
+}
+
+impl Tab for EndpointListTab {
+    fn id(&self) -> &str {
+        "endpoints"
+    }
+
+    fn title(&self) -> &str {
+        "Endpoints"
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let style = if i == self.selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                ListItem::new(format!("Endpoint {}", id)).style(style)
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title("Endpoints")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(list, area);
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut TabEventContext) -> EventResult {
+        if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Up => {
+                    self.selected = self.selected.saturating_sub(1);
+                    EventResult::Handled
+                }
+                KeyCode::Down => {
+                    self.selected = (self.selected + 1).min(self.endpoints.len() - 1);
+                    EventResult::Handled
+                }
+                KeyCode::Enter => {
+                    let id = self.endpoints[self.selected];
+                    ctx.navigate().push_route(&format!("endpoint/{}", id));
+                    EventResult::Handled
+                }
+                _ => EventResult::Unhandled,
+            }
+        } else {
+            EventResult::Unhandled
+        }
+    }
+}
+
+// =============================================================================
+// Screens
+// =============================================================================
+
+/// Detail screen for a single endpoint.
+struct EndpointScreen {
+    title: String,
+    id: u32,
+    visits: u32,
+}
+
+impl EndpointScreen {
+    fn new(id: u32) -> Self {
+        Self {
+            title: format!("Endpoint {}", id),
+            id,
+            visits: 0,
+        }
+    }
+}
+
+impl Screen for EndpointScreen {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let content = Paragraph::new(format!(
+            "Endpoint {}\n\n\
+             Shown {} time(s).\n\n\
+             Press Enter to open its attributes.",
+            self.id, self.visits
+        ))
+        .block(
+            Block::default()
+                .title(self.title.as_str())
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green)),
+        );
+        frame.render_widget(content, area);
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut TabEventContext) -> EventResult {
+        if event.is_key(KeyCode::Enter) {
+            ctx.navigate()
+                .push(Box::new(AttributesScreen { endpoint: self.id }));
+            return EventResult::Handled;
+        }
+        EventResult::Unhandled
+    }
+
+    fn on_activate(&mut self) {
+        self.visits += 1;
+    }
+}
+
+/// Sub-detail screen listing the attributes of an endpoint.
+struct AttributesScreen {
+    endpoint: u32,
+}
+
+impl Screen for AttributesScreen {
+    fn title(&self) -> &str {
+        "Attributes"
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let content = Paragraph::new(format!(
+            "Attributes of endpoint {}\n\n\
+             Press Esc to go back.",
+            self.endpoint
+        ))
+        .block(
+            Block::default()
+                .title("Attributes")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta)),
+        );
+        frame.render_widget(content, area);
+    }
+}
+
+// =============================================================================
+// Main Application
+// =============================================================================
+
+struct NavigationApp;
+
+impl Component for NavigationApp {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Tab bar / breadcrumbs
+                Constraint::Min(5),    // Content
+                Constraint::Length(3), // Footer
+            ])
+            .split(area);
+
+        let navigate = ctx.navigate();
+        if navigate.is_active() {
+            let mut crumbs = vec!["Endpoints"];
+            crumbs.extend(navigate.breadcrumbs());
+            let breadcrumbs = Paragraph::new(Line::from(crumbs.join(" › ")))
+                .style(Style::default().fg(Color::Yellow))
+                .block(Block::default().borders(Borders::BOTTOM));
+            frame.render_widget(breadcrumbs, chunks[0]);
+            navigate.draw_screen(frame, chunks[1]);
+        } else {
+            ctx.tabs().draw_tabbar(frame, chunks[0]);
+            ctx.tabs().draw_content(frame, chunks[1]);
+        }
+
+        let footer = Paragraph::new("↑/↓: Select | Enter: Open | Esc: Back | f: Forward | q: Quit")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(footer, chunks[2]);
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if event.is_quit() {
+            ctx.quit();
+            return EventResult::Handled;
+        }
+
+        if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Char('q') => {
+                    ctx.quit();
+                    EventResult::Handled
+                }
+                KeyCode::Esc | KeyCode::Backspace => ctx.navigate().back().into(),
+                KeyCode::Char('f') => ctx.navigate().forward().into(),
+                _ => EventResult::Unhandled,
+            }
+        } else {
+            EventResult::Unhandled
+        }
+    }
+}
+
+impl MainUi for NavigationApp {}
+
+// =============================================================================
+// Main
+// =============================================================================
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let app = AppBuilder::new()
+        .main_ui(NavigationApp)
+        .add_tab(EndpointListTab::new())
+        .route("endpoint/:id", |params| {
+            Box::new(EndpointScreen::new(params.parse("id").unwrap_or(0)))
+        })
+        .mouse_capture(false)
+        .build()?;
+
+    app.run().await?;
+
+    Ok(())
+}
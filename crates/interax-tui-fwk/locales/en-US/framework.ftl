@@ -0,0 +1,26 @@
+# Strings drawn by interax-tui-fwk itself.
+#
+# Applications can override any of these by adding a resource with the
+# same message ids to their `Localizer`.
+
+## Shown instead of the UI while the terminal is smaller than
+## `AppBuilder::min_size`
+
+fwk-too-small = Terminal too small
+fwk-too-small-size = { $width }×{ $height }
+fwk-too-small-need = Need at least { $width }×{ $height }
+
+## Command palette
+
+fwk-palette-title = Commands
+fwk-palette-placeholder = Type a command…
+fwk-palette-no-matches = No matching commands
+fwk-palette-go-to-tab = Go to tab: { $title }
+fwk-palette-focus = Focus: { $id }
+
+## Default toast titles
+
+fwk-toast-info = Info
+fwk-toast-success = Success
+fwk-toast-warning = Warning
+fwk-toast-error = Error
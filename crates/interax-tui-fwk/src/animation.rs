@@ -0,0 +1,546 @@
+//! Animation primitives.
+//!
+//! The app is event driven: it only draws when something happens. An
+//! animation needs frames while it runs, so it asks the `Animator` for
+//! them, and the app goes back to waiting for events once the last one
+//! is drawn. Nothing is scheduled between animations.
+//!
+//! - `Tween` moves a number from one value to another over a duration,
+//!   shaped by an `Easing`. Starting one with `Animator::animate` requests
+//!   frames until it ends.
+//! - `Spinner` and `ProgressBar` (in `widgets`) animate themselves while
+//!   they run.
+//! - `AppBuilder::tab_transition` slides the content of a newly selected
+//!   tab in, and `AppBuilder::focus_transition` makes
+//!   `FocusDrawContext::fade` blend the focused and unfocused styles when
+//!   focus moves.
+//!
+//! Get the animator with `AppContext::animator()`,
+//! `TabEventContext::animator()` or `DrawContext::animator()`. Frames are
+//! drawn at most at the rate set by `AppBuilder::frame_rate` (30 per
+//! second by default).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{Easing, Tween};
+//!
+//! struct Sidebar {
+//!     width: Tween,
+//! }
+//!
+//! impl Component for Sidebar {
+//!     fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+//!         if event.is_key(KeyCode::Char('b')) {
+//!             let target = if self.width.target() > 0.0 { 0.0 } else { 30.0 };
+//!             self.width.animate_to(target, Duration::from_millis(200));
+//!             ctx.animator().animate(&self.width);
+//!             return EventResult::Handled;
+//!         }
+//!         EventResult::Unhandled
+//!     }
+//!
+//!     fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+//!         let width = self.width.value().round() as u16;
+//!         // Draw with width...
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Style},
+};
+
+/// Frames drawn per second while animating, unless set with
+/// `AppBuilder::frame_rate`.
+const DEFAULT_FRAME_RATE: u32 = 30;
+
+/// How the progress of an animation maps to the progress of its value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Start slowly and speed up.
+    EaseIn,
+    /// Start fast and slow down.
+    #[default]
+    EaseOut,
+    /// Speed up, then slow down.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map `t`, clamped between 0 and 1, to the eased progress.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A number moving towards a target over time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    from: f64,
+    to: f64,
+    started: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Tween {
+    /// Create a tween resting at `value`.
+    pub fn new(value: f64) -> Self {
+        Self {
+            from: value,
+            to: value,
+            started: Instant::now(),
+            duration: Duration::ZERO,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Set the easing (defaults to `Easing::EaseOut`).
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Move from the current value to `to` over `duration`.
+    ///
+    /// Call `Animator::animate` with the tween to get frames until it
+    /// ends.
+    pub fn animate_to(&mut self, to: f64, duration: Duration) {
+        let now = Instant::now();
+        self.from = self.value_at(now);
+        self.to = to;
+        self.started = now;
+        self.duration = duration;
+    }
+
+    /// Jump to `value` without animating.
+    pub fn set(&mut self, value: f64) {
+        self.from = value;
+        self.to = value;
+        self.duration = Duration::ZERO;
+    }
+
+    /// The value now.
+    pub fn value(&self) -> f64 {
+        self.value_at(Instant::now())
+    }
+
+    /// The value at `now`.
+    pub fn value_at(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.started);
+        if elapsed >= self.duration {
+            return self.to;
+        }
+        let t = elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        self.from + (self.to - self.from) * self.easing.apply(t)
+    }
+
+    /// The value the tween is moving to.
+    pub fn target(&self) -> f64 {
+        self.to
+    }
+
+    /// Check if the tween is still moving.
+    pub fn is_running(&self) -> bool {
+        self.end().is_some_and(|end| end > Instant::now())
+    }
+
+    /// When the tween reaches its target, or `None` if it is resting.
+    pub fn end(&self) -> Option<Instant> {
+        (self.from != self.to && !self.duration.is_zero()).then(|| self.started + self.duration)
+    }
+}
+
+/// A transition in progress.
+#[derive(Debug)]
+struct Transition<T> {
+    started: Instant,
+    duration: Duration,
+    detail: T,
+}
+
+impl<T> Transition<T> {
+    /// Eased progress at `now`, or `None` once it is over.
+    fn progress(&self, now: Instant, easing: Easing) -> Option<f64> {
+        let elapsed = now.saturating_duration_since(self.started);
+        (elapsed < self.duration)
+            .then(|| easing.apply(elapsed.as_secs_f64() / self.duration.as_secs_f64()))
+    }
+}
+
+/// Focus moving from one element to another.
+#[derive(Debug)]
+struct FocusMove {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+struct State {
+    frame_interval: Duration,
+    /// When a single frame was requested.
+    requested: Option<Instant>,
+    /// Until when frames are drawn at the frame rate.
+    until: Option<Instant>,
+    last_frame: Option<Instant>,
+    tab_duration: Option<Duration>,
+    focus_duration: Option<Duration>,
+    /// The active tab and focused element as of the last check, or `None`
+    /// before the first one.
+    observed: Option<(usize, Option<String>)>,
+    /// Slide of the newly selected tab, `true` when moving forward.
+    tab: Option<Transition<bool>>,
+    focus: Option<Transition<FocusMove>>,
+}
+
+/// Requests frames for running animations, shared by every context.
+///
+/// Cloning an `Animator` gives another handle to the same animations, so
+/// a widget drawn inside a `Tab` can keep one to request its own frames.
+#[derive(Clone)]
+pub struct Animator {
+    state: Arc<Mutex<State>>,
+}
+
+impl Animator {
+    /// Create an animator drawing at the default frame rate.
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                frame_interval: Duration::from_secs(1) / DEFAULT_FRAME_RATE,
+                requested: None,
+                until: None,
+                last_frame: None,
+                tab_duration: None,
+                focus_duration: None,
+                observed: None,
+                tab: None,
+                focus: None,
+            })),
+        }
+    }
+
+    /// Request one frame, as soon as the frame rate allows.
+    pub fn request_frame(&self) {
+        let mut state = self.lock();
+        let now = Instant::now();
+        let at = state
+            .last_frame
+            .map_or(now, |last| (last + state.frame_interval).max(now));
+        state.request(at);
+    }
+
+    /// Request one frame at `at`, e.g. when a spinner shows its next
+    /// glyph.
+    pub fn request_frame_at(&self, at: Instant) {
+        self.lock().request(at);
+    }
+
+    /// Draw frames at the frame rate for `duration`.
+    pub fn animate_for(&self, duration: Duration) {
+        self.animate_until(Instant::now() + duration);
+    }
+
+    /// Draw frames at the frame rate until `deadline`, then a last one at
+    /// `deadline`.
+    pub fn animate_until(&self, deadline: Instant) {
+        let mut state = self.lock();
+        state.until = state.until.max(Some(deadline));
+    }
+
+    /// Draw frames until `tween` reaches its target.
+    pub fn animate(&self, tween: &Tween) {
+        if let Some(end) = tween.end() {
+            self.animate_until(end);
+        }
+    }
+
+    /// Check if frames are requested.
+    pub fn is_animating(&self) -> bool {
+        let state = self.lock();
+        state.requested.is_some() || state.until.is_some()
+    }
+
+    /// Time between two frames while animating.
+    pub fn frame_interval(&self) -> Duration {
+        self.lock().frame_interval
+    }
+
+    /// Draw at most `fps` frames per second while animating.
+    pub(crate) fn set_frame_rate(&self, fps: u32) {
+        self.lock().frame_interval = Duration::from_secs(1) / fps.max(1);
+    }
+
+    /// Slide tabs in over `duration` when the active tab changes.
+    pub(crate) fn set_tab_transition(&self, duration: Duration) {
+        self.lock().tab_duration = Some(duration);
+    }
+
+    /// Fade highlights over `duration` when focus moves.
+    pub(crate) fn set_focus_transition(&self, duration: Duration) {
+        self.lock().focus_duration = Some(duration);
+    }
+
+    /// Start the transitions for a change of the active tab or focused
+    /// element since the last call.
+    pub(crate) fn observe(&self, tab: usize, focused: Option<&str>, now: Instant) {
+        let mut state = self.lock();
+        let Some((last_tab, last_focused)) = state.observed.take() else {
+            state.observed = Some((tab, focused.map(String::from)));
+            return;
+        };
+        if let Some(duration) = state.tab_duration.filter(|_| tab != last_tab) {
+            state.tab = Some(Transition {
+                started: now,
+                duration,
+                detail: tab > last_tab,
+            });
+            state.until = state.until.max(Some(now + duration));
+        }
+        let focus_moved = last_focused.as_deref() != focused;
+        if let Some(duration) = state.focus_duration.filter(|_| focus_moved) {
+            state.focus = Some(Transition {
+                started: now,
+                duration,
+                detail: FocusMove {
+                    from: last_focused,
+                    to: focused.map(String::from),
+                },
+            });
+            state.until = state.until.max(Some(now + duration));
+        }
+        state.observed = Some((tab, focused.map(String::from)));
+    }
+
+    /// When the next frame is due, or `None` when nothing is animating.
+    pub(crate) fn next_frame(&self) -> Option<Instant> {
+        let state = self.lock();
+        let timed = state.until.map(|until| match state.last_frame {
+            Some(last) => (last + state.frame_interval).min(until),
+            None => until,
+        });
+        match (state.requested, timed) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Note that a frame is drawn at `now`, dropping the requests it
+    /// serves and the transitions that ended.
+    pub(crate) fn frame_started(&self, now: Instant) {
+        let mut state = self.lock();
+        state.last_frame = Some(now);
+        if state.requested.is_some_and(|at| at <= now) {
+            state.requested = None;
+        }
+        if state.until.is_some_and(|until| until <= now) {
+            state.until = None;
+        }
+        if state
+            .tab
+            .as_ref()
+            .is_some_and(|t| t.progress(now, Easing::EaseOut).is_none())
+        {
+            state.tab = None;
+        }
+        if state
+            .focus
+            .as_ref()
+            .is_some_and(|t| t.progress(now, Easing::EaseInOut).is_none())
+        {
+            state.focus = None;
+        }
+    }
+
+    /// How far the content of a `width` wide tab is shifted at `now`:
+    /// positive while it slides in from the right, negative from the left.
+    pub(crate) fn tab_shift(&self, width: u16, now: Instant) -> i32 {
+        let state = self.lock();
+        let Some(tab) = &state.tab else {
+            return 0;
+        };
+        let Some(progress) = tab.progress(now, Easing::EaseOut) else {
+            return 0;
+        };
+        let shift = ((1.0 - progress) * width as f64).round() as i32;
+        if tab.detail {
+            shift
+        } else {
+            -shift
+        }
+    }
+
+    /// How focused `id` looks at `now`, from 0 to 1, or `None` if focus is
+    /// not moving to or from it.
+    pub(crate) fn focus_level(&self, id: &str, now: Instant) -> Option<f64> {
+        let state = self.lock();
+        let focus = state.focus.as_ref()?;
+        let progress = focus.progress(now, Easing::EaseInOut)?;
+        if focus.detail.to.as_deref() == Some(id) {
+            Some(progress)
+        } else if focus.detail.from.as_deref() == Some(id) {
+            Some(1.0 - progress)
+        } else {
+            None
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl State {
+    fn request(&mut self, at: Instant) {
+        self.requested = Some(self.requested.map_or(at, |requested| requested.min(at)));
+    }
+}
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Animator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Animator")
+            .field("animating", &self.is_animating())
+            .finish()
+    }
+}
+
+/// Shift the cells of `area` by `dx` columns, blanking the ones uncovered.
+pub(crate) fn shift_area(buf: &mut Buffer, area: Rect, dx: i32) {
+    let area = area.intersection(buf.area);
+    if dx == 0 || area.is_empty() {
+        return;
+    }
+    for y in area.top()..area.bottom() {
+        let row: Vec<Cell> = (area.left()..area.right())
+            .map(|x| buf[(x, y)].clone())
+            .collect();
+        for (i, x) in (area.left()..area.right()).enumerate() {
+            let source = i as i32 - dx;
+            buf[(x, y)] = usize::try_from(source)
+                .ok()
+                .and_then(|source| row.get(source))
+                .cloned()
+                .unwrap_or_default();
+        }
+    }
+}
+
+/// Blend style `from` into style `to` by `t`, from 0 to 1.
+///
+/// RGB and the sixteen named colors are mixed; other colors and the
+/// modifiers switch halfway.
+pub fn blend(from: Style, to: Style, t: f64) -> Style {
+    let t = t.clamp(0.0, 1.0);
+    let mut style = if t < 0.5 { from } else { to };
+    style.fg = blend_color(from.fg, to.fg, t);
+    style.bg = blend_color(from.bg, to.bg, t);
+    style
+}
+
+fn blend_color(from: Option<Color>, to: Option<Color>, t: f64) -> Option<Color> {
+    let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (from.and_then(rgb), to.and_then(rgb)) else {
+        return if t < 0.5 { from } else { to };
+    };
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    Some(Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2)))
+}
+
+/// The RGB value of `color`, using the usual xterm palette for the named
+/// colors.
+fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    Some(match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::DarkGray => (127, 127, 127),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Reset | Color::Indexed(_) => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tweens_and_frames() {
+        assert_eq!(Easing::Linear.apply(0.25), 0.25);
+        assert_eq!(Easing::EaseOut.apply(2.0), 1.0);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5 && Easing::EaseOut.apply(0.5) > 0.5);
+
+        let ms = Duration::from_millis;
+        let mut tween = Tween::new(0.0).easing(Easing::Linear);
+        assert!(tween.end().is_none());
+        tween.animate_to(10.0, ms(100));
+        let start = tween.started;
+        assert_eq!(tween.value_at(start + ms(50)), 5.0);
+        assert_eq!(tween.value_at(start + ms(150)), 10.0);
+
+        // Frames are due until the tween ends, then the animator is idle
+        let animator = Animator::new();
+        animator.set_frame_rate(10);
+        animator.animate(&tween);
+        assert_eq!(animator.next_frame(), tween.end());
+        animator.frame_started(start);
+        assert_eq!(animator.next_frame(), Some(start + ms(100)));
+        animator.frame_started(start + ms(100));
+        assert_eq!(animator.next_frame(), None);
+
+        // Selecting a later tab slides it in from the right
+        animator.set_tab_transition(ms(100));
+        animator.observe(0, None, start);
+        animator.observe(1, None, start);
+        assert_eq!(animator.tab_shift(40, start), 40);
+        assert_eq!(animator.tab_shift(40, start + ms(100)), 0);
+    }
+
+    #[test]
+    fn test_blend_and_shift() {
+        let from = Style::default().fg(Color::Black);
+        let to = Style::default().fg(Color::Rgb(200, 100, 0));
+        assert_eq!(blend(from, to, 0.5).fg, Some(Color::Rgb(100, 50, 0)));
+        assert_eq!(blend(from, to, 1.0), to);
+
+        let mut buf = Buffer::with_lines(["abcd"]);
+        shift_area(&mut buf, Rect::new(0, 0, 4, 1), 2);
+        assert_eq!(buf, Buffer::with_lines(["  ab"]));
+    }
+}
@@ -3,21 +3,53 @@
 //! This module provides the `AppBuilder` for constructing applications
 //! and the `App` struct that runs the main event loop.
 
-use std::time::Duration;
+use std::io;
+#[cfg(all(unix, feature = "server"))]
+use std::io::Write;
+#[cfg(feature = "persist")]
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use crossterm::event::EventStream;
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
+use ratatui::layout::Rect;
 use tokio::sync::mpsc;
 use tokio::sync::watch;
+use tokio::time::Interval;
 
-use crate::bus::{MessageBus, TaskMessage, TaskSender};
+use crate::animation::Animator;
+use crate::bus::{MessageBus, QueueDepths, TaskMessage, TaskSender};
+use crate::clipboard::Clipboard;
 use crate::component::MainUi;
-use crate::context::{AppContext, DrawContext, TabEventContext};
-use crate::event::Event;
-use crate::focus::FocusManager;
+use crate::context::{AppContext, DrawContext, Services, TabEventContext};
+use crate::effect::{EffectOutput, Effects};
+use crate::event::{Event, KeyCode, KeyModifiers};
+use crate::focus::{EventResult, FocusManager};
+use crate::l10n::Localizer;
+use crate::notify::Notifier;
+use crate::palette::{CommandPalette, CommandTarget, PaletteAction, PaletteEntry};
+#[cfg(feature = "persist")]
+use crate::persist::{session_path, Session};
+#[cfg(feature = "record")]
+use crate::record::{MessageCodec, Recording};
+use crate::responsive::{draw_size_guard, is_too_small, Breakpoint, Breakpoints};
+use crate::router::{BoxedScreen, RouteParams, Router};
+use crate::stats::FrameStats;
+use crate::status::StatusBar;
+use crate::store::Store;
 use crate::tabs::{Tab, TabManager};
 use crate::task::{BoxedTaskFuture, Task, TaskContext, TaskFactory, TaskHandle};
 use crate::terminal::{install_panic_hook, Terminal, TerminalConfig, TerminalError};
+use crate::theme::Theme;
+use crate::timer::{Timer, Timers};
+
+/// Session key of the active tab id.
+#[cfg(feature = "persist")]
+const SESSION_TAB_KEY: &str = "app.tab";
+
+/// Session key of the focused element id.
+#[cfg(feature = "persist")]
+const SESSION_FOCUS_KEY: &str = "app.focus";
 
 /// Error type for application operations.
 #[derive(Debug)]
@@ -88,10 +120,36 @@ impl std::fmt::Display for BuildError {
 
 impl std::error::Error for BuildError {}
 
+/// Wait for the next tick, or forever without a tick rate.
+async fn next_tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Receive the next replayed event, or wait forever without a replay.
+async fn next_replayed(replay: &mut Option<mpsc::Receiver<Event>>) -> Option<Event> {
+    match replay {
+        Some(replay) => replay.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Sleep until `deadline`, or forever without one.
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+        None => std::future::pending().await,
+    }
+}
+
 /// A pending task to be spawned when the app runs.
-struct PendingTask {
-    name: &'static str,
-    factory: TaskFactory,
+pub(crate) struct PendingTask {
+    pub(crate) name: &'static str,
+    pub(crate) factory: TaskFactory,
 }
 
 /// Builder for constructing a TUI application.
@@ -119,8 +177,23 @@ pub struct AppBuilder<M: MainUi> {
     bus: MessageBus,
     tab_manager: TabManager,
     focus_manager: FocusManager,
+    router: Router,
+    theme: Theme,
     tick_rate: Option<Duration>,
     mouse_capture: bool,
+    palette_key: Option<(KeyCode, KeyModifiers)>,
+    debug_overlay_key: Option<(KeyCode, KeyModifiers)>,
+    show_status_bar: bool,
+    breakpoints: Breakpoints,
+    min_size: Option<(u16, u16)>,
+    store: Store,
+    l10n: Localizer,
+    clipboard: Clipboard,
+    animator: Animator,
+    #[cfg(feature = "persist")]
+    session_path: Option<PathBuf>,
+    #[cfg(feature = "record")]
+    recording: Recording,
 }
 
 impl<M: MainUi + 'static> AppBuilder<M> {
@@ -132,8 +205,23 @@ impl<M: MainUi + 'static> AppBuilder<M> {
             bus: MessageBus::new(),
             tab_manager: TabManager::new(),
             focus_manager: FocusManager::new(),
+            router: Router::new(),
+            theme: Theme::default(),
             tick_rate: None,
             mouse_capture: true,
+            palette_key: Some((KeyCode::Char('p'), KeyModifiers::CONTROL)),
+            debug_overlay_key: None,
+            show_status_bar: false,
+            breakpoints: Breakpoints::default(),
+            min_size: None,
+            store: Store::new(),
+            l10n: Localizer::new(),
+            clipboard: Clipboard::new(),
+            animator: Animator::new(),
+            #[cfg(feature = "persist")]
+            session_path: None,
+            #[cfg(feature = "record")]
+            recording: Recording::new(),
         }
     }
 
@@ -164,6 +252,30 @@ impl<M: MainUi + 'static> AppBuilder<M> {
         self
     }
 
+    /// Register a named route for screen navigation.
+    ///
+    /// Pattern segments starting with `:` capture parameters, which are
+    /// passed to the factory. Screens are created when navigated to via
+    /// `ctx.navigate().push_route(path)`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let app = AppBuilder::new()
+    ///     .main_ui(MyApp::new())
+    ///     .route("endpoint/:id", |params| {
+    ///         Box::new(EndpointScreen::new(params.parse("id").unwrap_or(0)))
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn route<F>(mut self, pattern: &str, factory: F) -> Self
+    where
+        F: Fn(&RouteParams) -> BoxedScreen + Send + 'static,
+    {
+        self.router.route(pattern, factory);
+        self
+    }
+
     /// Add a background task.
     ///
     /// The task will be spawned when the application runs and will
@@ -183,10 +295,20 @@ impl<M: MainUi + 'static> AppBuilder<M> {
         self
     }
 
+    /// Subscribe to the messages of every task added so far or later.
+    ///
+    /// The app's `MainUi` keeps receiving them too; use this to feed a
+    /// logger or a second view from the same tasks.
+    pub fn subscribe(&self) -> mpsc::Receiver<TaskMessage> {
+        self.bus.subscribe()
+    }
+
     /// Set an optional tick rate for periodic updates.
     ///
-    /// If set, the main UI's `tick()` method will be called at this interval.
-    /// Leave unset for pure event-driven operation (recommended for "quiet" apps).
+    /// If set, the main UI's `tick()` method will be called at this interval,
+    /// then `Tab::tick` of the active tab and of the tabs that tick when
+    /// inactive. Leave unset for pure event-driven operation (recommended for
+    /// "quiet" apps); components can set their own timers instead.
     pub fn tick_rate(mut self, rate: Duration) -> Self {
         self.tick_rate = Some(rate);
         self
@@ -203,6 +325,185 @@ impl<M: MainUi + 'static> AppBuilder<M> {
         self
     }
 
+    /// Enable or disable the built-in command palette (enabled by default).
+    ///
+    /// The palette lists the commands of the `MainUi` and the active tab,
+    /// the other tabs and the registered focus ids. See `Component::commands`.
+    pub fn command_palette(mut self, enabled: bool) -> Self {
+        self.palette_key = if enabled {
+            Some((KeyCode::Char('p'), KeyModifiers::CONTROL))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Set the key that opens the command palette (Ctrl+P by default).
+    pub fn command_palette_key(mut self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.palette_key = Some((code, modifiers));
+        self
+    }
+
+    /// Set the key that toggles the debug overlay (disabled by default).
+    ///
+    /// The overlay shows the `FrameStats` of the event loop in the top
+    /// right corner and is updated on every redraw.
+    pub fn debug_overlay_key(mut self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.debug_overlay_key = Some((code, modifiers));
+        self
+    }
+
+    /// Draw at most `fps` frames per second while animations run (30 by
+    /// default).
+    ///
+    /// This does not add a tick: the app still only draws when something
+    /// happens or an animation requests a frame.
+    pub fn frame_rate(self, fps: u32) -> Self {
+        self.animator.set_frame_rate(fps);
+        self
+    }
+
+    /// Slide the content of a newly selected tab in over `duration`, from
+    /// the side of the tab bar it was selected towards.
+    pub fn tab_transition(self, duration: Duration) -> Self {
+        self.animator.set_tab_transition(duration);
+        self
+    }
+
+    /// Fade highlights over `duration` when focus moves.
+    ///
+    /// Components pick their styles with `FocusDrawContext::fade` to
+    /// follow the transition.
+    pub fn focus_transition(self, duration: Duration) -> Self {
+        self.animator.set_focus_transition(duration);
+        self
+    }
+
+    /// Reserve the bottom row of the terminal for the status bar.
+    ///
+    /// Components update it through `AppContext::status_bar()`. The main
+    /// UI is drawn in the remaining area.
+    pub fn status_bar(mut self, enabled: bool) -> Self {
+        self.show_status_bar = enabled;
+        self
+    }
+
+    /// Save the session state of `app_name` in the XDG state directory.
+    ///
+    /// The active tab, the focused element and whatever components and
+    /// tabs write in `save_state` are written on shutdown and restored on
+    /// the next start. Does nothing if no state directory can be found.
+    #[cfg(feature = "persist")]
+    pub fn persist(mut self, app_name: &str) -> Self {
+        self.session_path = session_path(app_name);
+        self
+    }
+
+    /// Save the session state in a specific file.
+    #[cfg(feature = "persist")]
+    pub fn persist_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.session_path = Some(path.into());
+        self
+    }
+
+    /// Record terminal events and registered task messages to `path`.
+    ///
+    /// See `record_messages` for which messages are recorded.
+    #[cfg(feature = "record")]
+    pub fn record(mut self, path: impl Into<PathBuf>) -> Self {
+        self.recording.set_record_path(path.into());
+        self
+    }
+
+    /// Replay a recording made with `record` instead of running the tasks.
+    ///
+    /// Live input other than Ctrl+C/Ctrl+Q is ignored until the replay ends.
+    #[cfg(feature = "record")]
+    pub fn replay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.recording.set_replay_path(path.into());
+        self
+    }
+
+    /// Set how much faster than recorded a replay runs.
+    ///
+    /// `1.0` (the default) keeps the original timing and `f64::INFINITY`
+    /// replays without delays.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is not positive.
+    #[cfg(feature = "record")]
+    pub fn replay_speed(mut self, speed: f64) -> Self {
+        self.recording.set_replay_speed(speed);
+        self
+    }
+
+    /// Record and replay the messages of `task_name`, which must have type `T`.
+    ///
+    /// Messages of tasks without a registered type are neither recorded
+    /// nor replayed.
+    #[cfg(feature = "record")]
+    pub fn record_messages<T>(mut self, task_name: &'static str) -> Self
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+    {
+        self.recording.add_codec(MessageCodec::new::<T>(task_name));
+        self
+    }
+
+    /// Use `store` as the application's shared state.
+    ///
+    /// Create the store before the UI so components can subscribe to it
+    /// with `Store::signal` and `Store::select`. Tasks reach it via
+    /// `TaskContext::store()`.
+    pub fn store(mut self, store: Store) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Use `l10n` for framework and application strings.
+    ///
+    /// Add the application's resources before building, and keep a clone
+    /// in tabs that need to look up messages. The default has only the
+    /// framework's strings.
+    pub fn localizer(mut self, l10n: Localizer) -> Self {
+        self.l10n = l10n;
+        self
+    }
+
+    /// Use `clipboard` for `AppContext::clipboard`.
+    ///
+    /// The default copies via OSC 52 only; pass
+    /// `Clipboard::with_local_fallback()` to also use a local clipboard
+    /// command.
+    pub fn clipboard(mut self, clipboard: Clipboard) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
+    /// Set the widths at which the compact, normal and wide layouts apply.
+    ///
+    /// See `MainUi::on_breakpoint` and `DrawContext::breakpoint`.
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Show a "terminal too small" screen instead of the UI while the
+    /// terminal is smaller than `width` × `height`.
+    pub fn min_size(mut self, width: u16, height: u16) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Set the theme used by the tab bar and built-in widgets.
+    ///
+    /// Components can read it while drawing via `DrawContext::theme()`.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
     /// Build the application.
     ///
     /// Returns an error if no main UI was provided.
@@ -212,10 +513,37 @@ impl<M: MainUi + 'static> AppBuilder<M> {
         Ok(App {
             main_ui,
             tasks: self.tasks,
-            bus: self.bus,
             tab_manager: self.tab_manager,
             focus_manager: self.focus_manager,
+            router: self.router,
+            theme: self.theme,
             tick_rate: self.tick_rate,
+            depths: self.bus.depths(),
+            bus: self.bus,
+            palette: self
+                .palette_key
+                .map(|(code, modifiers)| CommandPalette::new(code, modifiers)),
+            debug_overlay_key: self.debug_overlay_key,
+            show_debug_overlay: false,
+            stats: FrameStats::new(),
+            services: Services {
+                notifier: Notifier::new(),
+                status_bar: StatusBar::new(),
+                store: self.store,
+                l10n: self.l10n,
+                clipboard: self.clipboard,
+                effects: Effects::new(),
+                timers: Timers::default(),
+                animator: self.animator,
+            },
+            show_status_bar: self.show_status_bar,
+            breakpoints: self.breakpoints,
+            breakpoint: Breakpoint::default(),
+            min_size: self.min_size,
+            #[cfg(feature = "persist")]
+            session_path: self.session_path,
+            #[cfg(feature = "record")]
+            recording: self.recording,
             terminal_config: TerminalConfig {
                 mouse_capture: self.mouse_capture,
             },
@@ -255,7 +583,23 @@ pub struct App<M: MainUi> {
     bus: MessageBus,
     tab_manager: TabManager,
     focus_manager: FocusManager,
+    router: Router,
+    theme: Theme,
     tick_rate: Option<Duration>,
+    depths: QueueDepths,
+    palette: Option<CommandPalette>,
+    debug_overlay_key: Option<(KeyCode, KeyModifiers)>,
+    show_debug_overlay: bool,
+    stats: FrameStats,
+    services: Services,
+    show_status_bar: bool,
+    breakpoints: Breakpoints,
+    breakpoint: Breakpoint,
+    min_size: Option<(u16, u16)>,
+    #[cfg(feature = "persist")]
+    session_path: Option<PathBuf>,
+    #[cfg(feature = "record")]
+    recording: Recording,
     terminal_config: TerminalConfig,
 }
 
@@ -264,30 +608,81 @@ impl<M: MainUi + 'static> App<M> {
     ///
     /// This sets up the terminal, spawns background tasks, and runs
     /// the main event loop until the application quits.
-    pub async fn run(mut self) -> Result<(), AppError> {
+    pub async fn run(self) -> Result<(), AppError> {
         // Install panic hook for terminal restoration
         install_panic_hook();
 
         // Set up terminal with configuration
-        let mut terminal = Terminal::with_config(self.terminal_config.clone())?;
+        let terminal = Terminal::with_config(self.terminal_config.clone())?;
+        let events = EventStream::new().map(|event| event.map(Event::from));
+        self.run_on(terminal, events).await
+    }
 
+    /// Run the application on a remote terminal of `width` by `height`
+    /// cells, reading its events from `events`.
+    #[cfg(all(unix, feature = "server"))]
+    pub(crate) async fn run_remote<S>(
+        self,
+        output: Box<dyn Write + Send>,
+        (width, height): (u16, u16),
+        events: S,
+    ) -> Result<(), AppError>
+    where
+        S: Stream<Item = io::Result<Event>> + Unpin,
+    {
+        let terminal = Terminal::remote(output, width, height, self.terminal_config.clone())?;
+        self.run_on(terminal, events).await
+    }
+
+    /// Sender feeding messages into this app's event loop.
+    #[cfg(all(unix, feature = "server"))]
+    pub(crate) fn message_sender(&self) -> mpsc::Sender<TaskMessage> {
+        self.bus.unified_sender()
+    }
+
+    /// Run the application on `terminal` with events from `events`.
+    async fn run_on<S>(mut self, mut terminal: Terminal, events: S) -> Result<(), AppError>
+    where
+        S: Stream<Item = io::Result<Event>> + Unpin,
+    {
         // Set up cancellation for tasks
         let (cancel_tx, cancel_rx) = watch::channel(false);
 
         // Take the unified message receiver
         let mut message_rx = self.bus.take_receiver().expect("receiver already taken");
 
+        // Open the recording and start the replay
+        #[cfg(feature = "record")]
+        let replay_rx = self.recording.start(self.bus.unified_sender())?;
+        #[cfg(not(feature = "record"))]
+        let replay_rx = None;
+
+        // A replay feeds the recorded messages instead of running the tasks
+        if replay_rx.is_some() {
+            self.tasks.clear();
+        }
+
         // Spawn all tasks
         let mut task_handles: Vec<TaskHandle> = Vec::with_capacity(self.tasks.len());
         for pending in self.tasks.drain(..) {
-            let ctx = TaskContext::new(cancel_rx.clone());
+            let ctx = TaskContext::new(cancel_rx.clone(), self.services.clone());
             let future = (pending.factory)(ctx);
             let handle = tokio::spawn(future);
             task_handles.push(TaskHandle::new(pending.name, handle));
         }
 
+        // Restore the previous session
+        #[cfg(feature = "persist")]
+        self.restore_session();
+
         // Run the event loop
-        let result = self.run_event_loop(&mut terminal, &mut message_rx).await;
+        let result = self
+            .run_event_loop(&mut terminal, events, &mut message_rx, replay_rx)
+            .await;
+
+        // Save the session, even if the loop failed
+        #[cfg(feature = "persist")]
+        self.save_session();
 
         // Signal all tasks to stop
         let _ = cancel_tx.send(true);
@@ -305,140 +700,284 @@ impl<M: MainUi + 'static> App<M> {
     }
 
     /// The main event loop.
-    async fn run_event_loop(
+    async fn run_event_loop<S>(
         &mut self,
         terminal: &mut Terminal,
+        mut event_stream: S,
         message_rx: &mut mpsc::Receiver<TaskMessage>,
-    ) -> Result<(), AppError> {
-        // Create the event stream for terminal events
-        let mut event_stream = EventStream::new();
-
+        mut replay_rx: Option<mpsc::Receiver<Event>>,
+    ) -> Result<(), AppError>
+    where
+        S: Stream<Item = io::Result<Event>> + Unpin,
+    {
         // Optional tick interval
         let mut tick_interval = self.tick_rate.map(tokio::time::interval);
 
+        // Handles used to wait for toast and store changes while `self` is
+        // borrowed
+        let notifier = self.services.notifier.clone();
+        let store = self.services.store.clone();
+        let l10n = self.services.l10n.clone();
+        let clipboard = self.services.clipboard.clone();
+        let mut effect_rx = self
+            .services
+            .effects
+            .take_receiver()
+            .expect("receiver already taken");
+
+        // Effects of this tab are cancelled when it is deactivated
+        let mut active_tab = self.active_tab_id();
+
+        // Tell the UI which layout and locale to start with
+        let size = terminal.size()?;
+        self.update_breakpoint(size.width, terminal, true);
+        self.update_locale();
+
         // Initial draw
+        self.observe_transitions();
+        self.stats = FrameStats::new();
         self.draw(terminal)?;
 
         // Track if we should quit
         let mut should_quit = false;
 
         loop {
+            // Set when a handler calls `AppContext::open_command_palette`
+            let mut open_palette = false;
+
+            // Set by the branches that handle their wake-up right away, so
+            // the handling does not count as idle time
+            let waiting = Instant::now();
+            let mut woke = None;
+
+            // Wake up when the next toast times out or timer fires
+            let toast_expiry = self.services.notifier.next_expiry();
+            let timer_deadline = self.services.timers.next_deadline();
+            let frame_deadline = self.services.animator.next_frame();
+
             // Wait for an event
-            let (needs_redraw, event_to_dispatch) = if let Some(ref mut interval) = tick_interval {
-                tokio::select! {
-                    biased;
-
-                    // Terminal events (keyboard, mouse, resize)
-                    event = event_stream.next() => {
-                        match event {
-                            Some(Ok(crossterm_event)) => {
-                                let event = Event::from(crossterm_event);
+            let (needs_redraw, event_to_dispatch) = tokio::select! {
+                biased;
+
+                // Terminal events (keyboard, mouse, resize)
+                event = event_stream.next() => {
+                    match event {
+                        Some(Ok(event)) => {
+                            if replay_rx.is_some() && !event.is_quit() {
+                                // Only quitting interrupts a replay
+                                (false, None)
+                            } else {
+                                #[cfg(feature = "record")]
+                                self.recording.event(&event);
                                 (true, Some(event))
                             }
-                            Some(Err(e)) => return Err(AppError::Io(e)),
-                            None => break, // Stream ended
                         }
+                        Some(Err(e)) => return Err(AppError::Io(e)),
+                        None => break, // Stream ended
                     }
+                }
 
-                    // Messages from background tasks
-                    msg = message_rx.recv() => {
-                        match msg {
-                            Some(task_message) => {
-                                let mut ctx = AppContext::new(
-                                    terminal,
-                                    &mut self.tab_manager,
-                                    &mut self.focus_manager,
-                                );
-                                let redraw = self.main_ui.handle_task_message(
-                                    task_message.task_name,
-                                    task_message.payload,
-                                    &mut ctx,
-                                );
-                                should_quit = ctx.should_quit();
-                                (redraw, None)
-                            }
-                            None => break, // All senders dropped
+                // Messages from background tasks
+                msg = message_rx.recv() => {
+                    match msg {
+                        Some(task_message) => {
+                            woke = Some(Instant::now());
+                            self.depths.remove(task_message.task_name);
+                            #[cfg(feature = "record")]
+                            self.recording.message(&task_message);
+                            let mut ctx = AppContext::new(
+                                terminal,
+                                &mut self.tab_manager,
+                                &mut self.focus_manager,
+                                &mut self.router,
+                                &self.services,
+                                self.breakpoint,
+                            );
+                            let redraw = self.main_ui.handle_task_message(
+                                task_message.task_name,
+                                task_message.payload,
+                                &mut ctx,
+                            );
+                            should_quit = ctx.should_quit();
+                            open_palette = ctx.open_palette;
+                            (redraw, None)
                         }
+                        // The bus keeps a sender alive, so this only happens on shutdown
+                        None => break,
                     }
+                }
 
-                    // Tick timer
-                    _ = interval.tick() => {
-                        let mut ctx = AppContext::new(
-                            terminal,
-                            &mut self.tab_manager,
-                            &mut self.focus_manager,
-                        );
-                        self.main_ui.tick(&mut ctx);
-                        should_quit = ctx.should_quit();
+                // Results of effects spawned by handlers
+                Some(mut output) = effect_rx.recv() => {
+                    woke = Some(Instant::now());
+                    // A cancelled effect may have completed in the meantime
+                    if self.services.effects.complete(&output) {
+                        should_quit = self.dispatch_effect(&mut output, terminal);
                         (true, None)
+                    } else {
+                        (false, None)
                     }
                 }
-            } else {
-                // No tick timer - pure event-driven
-                tokio::select! {
-                    biased;
-
-                    // Terminal events (keyboard, mouse, resize)
-                    event = event_stream.next() => {
-                        match event {
-                            Some(Ok(crossterm_event)) => {
-                                let event = Event::from(crossterm_event);
-                                (true, Some(event))
-                            }
-                            Some(Err(e)) => return Err(AppError::Io(e)),
-                            None => break, // Stream ended
+
+                // Recorded terminal events (never fires without a replay)
+                event = next_replayed(&mut replay_rx) => {
+                    match event {
+                        Some(event) => (true, Some(event)),
+                        // The replay ended; take live input again
+                        None => {
+                            replay_rx = None;
+                            (false, None)
                         }
                     }
+                }
 
-                    // Messages from background tasks
-                    msg = message_rx.recv() => {
-                        match msg {
-                            Some(task_message) => {
-                                let mut ctx = AppContext::new(
-                                    terminal,
-                                    &mut self.tab_manager,
-                                    &mut self.focus_manager,
-                                );
-                                let redraw = self.main_ui.handle_task_message(
-                                    task_message.task_name,
-                                    task_message.payload,
-                                    &mut ctx,
-                                );
-                                should_quit = ctx.should_quit();
-                                (redraw, None)
-                            }
-                            None => {
-                                // All senders dropped - if no tasks, this is expected
-                                // Keep running as long as there are terminal events
-                                (false, None)
-                            }
-                        }
+                // Tick timer (never fires without a tick rate)
+                _ = next_tick(&mut tick_interval) => {
+                    woke = Some(Instant::now());
+                    let mut ctx = AppContext::new(
+                        terminal,
+                        &mut self.tab_manager,
+                        &mut self.focus_manager,
+                        &mut self.router,
+                        &self.services,
+                        self.breakpoint,
+                    );
+                    self.main_ui.tick(&mut ctx);
+                    should_quit = ctx.should_quit();
+                    open_palette = ctx.open_palette;
+
+                    // Then the tabs, which the main UI does not own
+                    let mut tab_ctx =
+                        TabEventContext::new(terminal, &mut self.focus_manager, &self.services);
+                    self.tab_manager.tick_scoped(&mut tab_ctx);
+                    should_quit = should_quit || tab_ctx.should_quit();
+                    for navigation in tab_ctx.take_navigation() {
+                        self.router.apply(navigation);
+                    }
+                    (true, None)
+                }
+
+                // Toasts added or dismissed, possibly from a task
+                _ = notifier.changed() => (true, None),
+
+                // A store slice was written, possibly from a task
+                _ = store.changed() => (true, None),
+
+                // The locale was switched or resources were added
+                _ = l10n.changed() => {
+                    woke = Some(Instant::now());
+                    self.update_locale();
+                    (true, None)
+                }
+
+                // Text was copied from a handle outside the event loop
+                _ = clipboard.changed() => (false, None),
+
+                // A toast timed out
+                _ = sleep_until(toast_expiry) => {
+                    self.services.notifier.expire(Instant::now());
+                    (true, None)
+                }
+
+                // Timers set by handlers are due
+                _ = sleep_until(timer_deadline) => {
+                    let now = Instant::now();
+                    woke = Some(now);
+                    let mut redraw = false;
+                    for timer in self.services.timers.take_due(now) {
+                        let (handled, quit) = self.dispatch_timer(&timer, terminal);
+                        redraw |= handled;
+                        should_quit |= quit;
                     }
+                    (redraw, None)
                 }
+
+                // A running animation needs its next frame
+                _ = sleep_until(frame_deadline) => (true, None),
             };
+            let woke = woke.unwrap_or_else(Instant::now);
+            self.stats.record_wakeup(woke - waiting);
+
+            // Switch layouts when a resize crosses a breakpoint
+            if let Some(Event::Resize { width, height }) = event_to_dispatch {
+                terminal.resize(width, height)?;
+                self.update_breakpoint(width, terminal, false);
+            }
 
             // Dispatch event if we have one
             if let Some(event) = event_to_dispatch {
-                // Two-phase event dispatch to handle borrow conflicts:
-                //
-                // Phase 1: MainUi handles the event (can handle quit, tab switching, etc.)
-                let main_result = {
-                    let mut ctx =
-                        AppContext::new(terminal, &mut self.tab_manager, &mut self.focus_manager);
-                    let result = self.main_ui.handle_event(&event, &mut ctx);
-                    should_quit = ctx.should_quit();
-                    result
-                };
+                if self.is_debug_overlay_key(&event) {
+                    self.show_debug_overlay = !self.show_debug_overlay;
+                } else if self.palette_consumes(&event) {
+                    // The open palette takes all input until it closes
+                    let action = self
+                        .palette
+                        .as_mut()
+                        .and_then(|palette| palette.handle_input(&event));
+                    if let Some(action) = action {
+                        should_quit = self.run_palette_action(action, terminal);
+                    }
+                } else if self
+                    .palette
+                    .as_ref()
+                    .is_some_and(|palette| palette.is_trigger(&event))
+                {
+                    open_palette = true;
+                } else {
+                    // Two-phase event dispatch to handle borrow conflicts:
+                    //
+                    // Phase 1: MainUi handles the event (can handle quit, tab switching, etc.)
+                    let main_result = {
+                        let mut ctx = AppContext::new(
+                            terminal,
+                            &mut self.tab_manager,
+                            &mut self.focus_manager,
+                            &mut self.router,
+                            &self.services,
+                            self.breakpoint,
+                        );
+                        let result = self.main_ui.handle_event(&event, &mut ctx);
+                        should_quit = ctx.should_quit();
+                        open_palette = ctx.open_palette;
+                        result
+                    };
 
-                // Phase 2: If MainUi didn't handle it, delegate to active tab
-                // Uses TabEventContext which doesn't include TabManager, avoiding borrow conflicts
-                if main_result.should_propagate() && !should_quit {
-                    let mut tab_ctx = TabEventContext::new(terminal, &mut self.focus_manager);
-                    self.tab_manager.handle_event(&event, &mut tab_ctx);
-                    should_quit = should_quit || tab_ctx.should_quit();
+                    // Phase 2: If MainUi didn't handle it, delegate to the current screen,
+                    // or to the active tab when no screen is shown.
+                    // Uses TabEventContext which doesn't include TabManager, avoiding borrow conflicts
+                    if main_result.should_propagate() && !should_quit {
+                        let mut tab_ctx =
+                            TabEventContext::new(terminal, &mut self.focus_manager, &self.services)
+                                .in_tab(active_tab.as_deref());
+                        if self.router.is_empty() {
+                            self.tab_manager.handle_event(&event, &mut tab_ctx);
+                        } else {
+                            self.router.handle_event(&event, &mut tab_ctx);
+                        }
+                        should_quit = should_quit || tab_ctx.should_quit();
+
+                        // Apply navigation requested by the tab or screen
+                        for navigation in tab_ctx.take_navigation() {
+                            self.router.apply(navigation);
+                        }
+                    }
                 }
             }
 
+            if open_palette {
+                self.open_palette();
+            }
+
+            self.cancel_stale_effects(&mut active_tab);
+            self.observe_transitions();
+
+            // Send copied text to the terminal, even when quitting
+            for sequence in self.services.clipboard.take_sequences() {
+                terminal.write_raw(sequence.as_bytes())?;
+            }
+
+            self.stats.record_event(woke.elapsed());
+
             // Check if we should quit
             if should_quit {
                 break;
@@ -455,11 +994,319 @@ impl<M: MainUi + 'static> App<M> {
 
     /// Draw the UI.
     fn draw(&mut self, terminal: &mut Terminal) -> Result<(), AppError> {
-        let draw_ctx = DrawContext::new(&self.tab_manager, &self.focus_manager);
+        let started = Instant::now();
+        self.stats.queue_depths = self.depths.snapshot();
+        self.stats.running_effects = self.services.effects.len();
+        self.stats.scheduled_timers = self.services.timers.len();
+        self.services.animator.frame_started(started);
+        let draw_ctx = DrawContext::new(
+            &self.tab_manager,
+            &self.focus_manager,
+            &self.router,
+            &self.theme,
+            self.breakpoint,
+            &self.stats,
+            &self.services,
+        );
+        let palette = self.palette.as_ref().filter(|palette| palette.is_open());
         terminal.draw(|frame| {
-            let area = frame.area();
+            let mut area = frame.area();
+            if let Some(min_size) = self.min_size.filter(|&min| is_too_small(area, min)) {
+                draw_size_guard(frame, area, min_size, &self.theme, &self.services.l10n);
+                return;
+            }
+            if self.show_status_bar && area.height > 1 {
+                area.height -= 1;
+                let bar = Rect {
+                    y: area.bottom(),
+                    height: 1,
+                    ..area
+                };
+                self.services.status_bar.render(frame, bar, &self.theme);
+            }
             self.main_ui.draw(frame, area, &draw_ctx);
+            self.services
+                .notifier
+                .render(frame, area, &self.theme, &self.services.l10n);
+            if let Some(palette) = palette {
+                palette.render(frame, area, &self.theme);
+            }
+            if self.show_debug_overlay {
+                self.stats.render(frame, area, &self.theme);
+            }
         })?;
+        self.stats.record_frame(started.elapsed());
         Ok(())
     }
+
+    /// Load the session file and restore the tabs, main UI, active tab and
+    /// focus.
+    #[cfg(feature = "persist")]
+    fn restore_session(&mut self) {
+        let Some(path) = &self.session_path else {
+            return;
+        };
+        let session = Session::load(path);
+        self.tab_manager.restore_state(&session);
+        self.main_ui.restore_state(&session);
+        if let Some(id) = session.get::<String>(SESSION_TAB_KEY, 1) {
+            self.tab_manager.select_by_id(&id);
+        }
+        if let Some(id) = session.get::<String>(SESSION_FOCUS_KEY, 1) {
+            self.focus_manager.set_focus(&id);
+        }
+    }
+
+    /// Collect the session state and write the session file.
+    ///
+    /// Failing to write only loses the session, so errors are ignored.
+    #[cfg(feature = "persist")]
+    fn save_session(&self) {
+        let Some(path) = &self.session_path else {
+            return;
+        };
+        let mut session = Session::new();
+        if let Some(tab) = self.tab_manager.active_tab() {
+            session.set(SESSION_TAB_KEY, 1, &tab.id());
+        }
+        if let Some(id) = self.focus_manager.focused_id() {
+            session.set(SESSION_FOCUS_KEY, 1, &id);
+        }
+        self.tab_manager.save_state(&mut session);
+        self.main_ui.save_state(&mut session);
+        let _ = session.write(path);
+    }
+
+    /// Let the tabs and the main UI refresh their localized strings.
+    fn update_locale(&mut self) {
+        let l10n = &self.services.l10n;
+        self.tab_manager.set_locale(l10n);
+        self.main_ui.on_locale_change(l10n);
+    }
+
+    /// Classify `width` and notify the UI if the breakpoint changed (or
+    /// unconditionally with `force`).
+    fn update_breakpoint(&mut self, width: u16, terminal: &mut Terminal, force: bool) {
+        let breakpoint = self.breakpoints.classify(width);
+        if breakpoint == self.breakpoint && !force {
+            return;
+        }
+        self.breakpoint = breakpoint;
+        self.tab_manager.set_breakpoint(breakpoint);
+        let mut ctx = AppContext::new(
+            terminal,
+            &mut self.tab_manager,
+            &mut self.focus_manager,
+            &mut self.router,
+            &self.services,
+            self.breakpoint,
+        );
+        self.main_ui.on_breakpoint(breakpoint, &mut ctx);
+    }
+
+    /// Hand an effect result to the main UI, then to the current screen or
+    /// the active tab.
+    ///
+    /// Returns `true` if a handler requested to quit.
+    fn dispatch_effect(&mut self, output: &mut EffectOutput, terminal: &mut Terminal) -> bool {
+        let mut ctx = AppContext::new(
+            terminal,
+            &mut self.tab_manager,
+            &mut self.focus_manager,
+            &mut self.router,
+            &self.services,
+            self.breakpoint,
+        );
+        let result = self.main_ui.handle_effect(output, &mut ctx);
+        if ctx.should_quit() || !result.should_propagate() {
+            return ctx.should_quit();
+        }
+
+        let active_tab = self.active_tab_id();
+        let mut tab_ctx = TabEventContext::new(terminal, &mut self.focus_manager, &self.services)
+            .in_tab(active_tab.as_deref());
+        if self.router.is_empty() {
+            self.tab_manager.handle_effect(output, &mut tab_ctx);
+        } else {
+            self.router.handle_effect(output, &mut tab_ctx);
+        }
+        for navigation in tab_ctx.take_navigation() {
+            self.router.apply(navigation);
+        }
+        tab_ctx.should_quit()
+    }
+
+    /// Hand a timer to the main UI, then to the current screen or the
+    /// active tab.
+    ///
+    /// Returns if a handler took it and if one requested to quit.
+    fn dispatch_timer(&mut self, timer: &Timer, terminal: &mut Terminal) -> (bool, bool) {
+        let mut ctx = AppContext::new(
+            terminal,
+            &mut self.tab_manager,
+            &mut self.focus_manager,
+            &mut self.router,
+            &self.services,
+            self.breakpoint,
+        );
+        let result = self.main_ui.handle_timer(timer, &mut ctx);
+        if ctx.should_quit() || !result.should_propagate() {
+            return (result.is_handled(), ctx.should_quit());
+        }
+
+        let active_tab = self.active_tab_id();
+        let mut tab_ctx = TabEventContext::new(terminal, &mut self.focus_manager, &self.services)
+            .in_tab(timer.tab().or(active_tab.as_deref()));
+        let result = match timer.tab() {
+            // A timer of a tab ticking in the background goes to that tab
+            Some(id) if Some(id) != active_tab.as_deref() => match self.tab_manager.get_mut(id) {
+                Some(tab) => tab.handle_timer(timer, &mut tab_ctx),
+                None => EventResult::Unhandled,
+            },
+            _ if self.router.is_empty() => self.tab_manager.handle_timer(timer, &mut tab_ctx),
+            _ => self.router.handle_timer(timer, &mut tab_ctx),
+        };
+        for navigation in tab_ctx.take_navigation() {
+            self.router.apply(navigation);
+        }
+        (result.is_handled(), tab_ctx.should_quit())
+    }
+
+    /// Id of the active tab.
+    fn active_tab_id(&self) -> Option<String> {
+        self.tab_manager
+            .active_tab()
+            .map(|tab| tab.id().to_string())
+    }
+
+    /// Cancel the effects and timers of the tab that was active until now,
+    /// if it was deactivated, and of components whose focus id was
+    /// unregistered.
+    fn cancel_stale_effects(&self, active_tab: &mut Option<String>) {
+        let Services {
+            effects, timers, ..
+        } = &self.services;
+        if self.tab_manager.active_tab().map(|tab| tab.id()) != active_tab.as_deref() {
+            if let Some(tab) = active_tab {
+                effects.cancel_tab(tab);
+                if !self.tab_manager.is_ticking_when_inactive(tab) {
+                    timers.cancel_tab(tab);
+                }
+            }
+            *active_tab = self.active_tab_id();
+        }
+        let registered = self.focus_manager.focus_order();
+        effects.cancel_orphans(registered);
+        timers.cancel_orphans(registered);
+    }
+
+    /// Start the tab and focus transitions for changes made while
+    /// handling the last wake-up.
+    fn observe_transitions(&self) {
+        self.services.animator.observe(
+            self.tab_manager.active_index(),
+            self.focus_manager.focused_id(),
+            Instant::now(),
+        );
+    }
+
+    /// Check if `event` is the key that toggles the debug overlay.
+    fn is_debug_overlay_key(&self, event: &Event) -> bool {
+        self.debug_overlay_key
+            .is_some_and(|(code, modifiers)| event.is_key_with_modifiers(code, modifiers))
+    }
+
+    /// Check if the open command palette takes `event`.
+    fn palette_consumes(&self, event: &Event) -> bool {
+        self.palette
+            .as_ref()
+            .is_some_and(|palette| palette.is_open())
+            && matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_))
+    }
+
+    /// Open the command palette over the current commands, tabs and focus ids.
+    fn open_palette(&mut self) {
+        if self.palette.is_none() {
+            return;
+        }
+
+        let l10n = &self.services.l10n;
+        let mut entries: Vec<PaletteEntry> = self
+            .main_ui
+            .commands()
+            .into_iter()
+            .map(|command| PaletteEntry::command(command, CommandTarget::Main))
+            .collect();
+        if self.router.is_empty() {
+            entries.extend(
+                self.tab_manager
+                    .commands()
+                    .into_iter()
+                    .map(|command| PaletteEntry::command(command, CommandTarget::Tab)),
+            );
+        }
+        let active = self.tab_manager.active_index();
+        entries.extend(
+            self.tab_manager
+                .list()
+                .iter()
+                .filter(|tab| tab.enabled && tab.index != active)
+                .map(|tab| PaletteEntry::tab(&tab.id, &tab.title, l10n)),
+        );
+        entries.extend(
+            self.focus_manager
+                .focus_order()
+                .iter()
+                .map(|id| PaletteEntry::focus(id, l10n)),
+        );
+
+        if let Some(palette) = self.palette.as_mut() {
+            palette.open(entries, l10n);
+        }
+    }
+
+    /// Run an entry chosen in the command palette.
+    ///
+    /// Returns `true` if the handler requested to quit.
+    fn run_palette_action(&mut self, action: PaletteAction, terminal: &mut Terminal) -> bool {
+        match action {
+            PaletteAction::Command {
+                target: CommandTarget::Main,
+                id,
+            } => {
+                let mut ctx = AppContext::new(
+                    terminal,
+                    &mut self.tab_manager,
+                    &mut self.focus_manager,
+                    &mut self.router,
+                    &self.services,
+                    self.breakpoint,
+                );
+                self.main_ui.on_command(&id, &mut ctx);
+                ctx.should_quit()
+            }
+            PaletteAction::Command {
+                target: CommandTarget::Tab,
+                id,
+            } => {
+                let active_tab = self.active_tab_id();
+                let mut tab_ctx =
+                    TabEventContext::new(terminal, &mut self.focus_manager, &self.services)
+                        .in_tab(active_tab.as_deref());
+                self.tab_manager.handle_command(&id, &mut tab_ctx);
+                for navigation in tab_ctx.take_navigation() {
+                    self.router.apply(navigation);
+                }
+                tab_ctx.should_quit()
+            }
+            PaletteAction::SelectTab(id) => {
+                self.tab_manager.select_by_id(&id);
+                false
+            }
+            PaletteAction::Focus(id) => {
+                self.focus_manager.set_focus(&id);
+                false
+            }
+        }
+    }
 }
@@ -2,9 +2,15 @@
 //!
 //! This module provides a typed channel registry for communication
 //! between background tasks and the main UI.
+//!
+//! Every message is delivered to the unified receiver taken by the app
+//! and to every receiver created with `MessageBus::subscribe`, so one task
+//! can feed several views, a logger or a recorder. Payloads are shared
+//! behind an `Arc`, so broadcasting never copies a message.
 
 use std::any::Any;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use tokio::sync::mpsc;
 
@@ -12,26 +18,29 @@ use tokio::sync::mpsc;
 pub const DEFAULT_CHANNEL_SIZE: usize = 32;
 
 /// A type-erased message that can be sent through the bus.
+///
+/// Cloning is cheap: every clone shares the same payload.
+#[derive(Clone)]
 pub struct TaskMessage {
     /// The name of the task that sent this message.
     pub task_name: &'static str,
     /// The message payload.
-    pub payload: Box<dyn Any + Send>,
+    pub payload: Arc<dyn Any + Send + Sync>,
 }
 
 impl TaskMessage {
     /// Create a new task message.
-    pub fn new<T: Any + Send + 'static>(task_name: &'static str, message: T) -> Self {
+    pub fn new<T: Any + Send + Sync + 'static>(task_name: &'static str, message: T) -> Self {
         Self {
             task_name,
-            payload: Box::new(message),
+            payload: Arc::new(message),
         }
     }
 
     /// Try to downcast the message to a specific type.
-    pub fn downcast<T: Any + Send + 'static>(self) -> Result<T, Self> {
+    pub fn downcast<T: Any + Send + Sync + 'static>(self) -> Result<Arc<T>, Self> {
         match self.payload.downcast::<T>() {
-            Ok(msg) => Ok(*msg),
+            Ok(msg) => Ok(msg),
             Err(payload) => Err(Self {
                 task_name: self.task_name,
                 payload,
@@ -40,9 +49,17 @@ impl TaskMessage {
     }
 
     /// Try to get a reference to the message as a specific type.
-    pub fn downcast_ref<T: Any + Send + 'static>(&self) -> Option<&T> {
+    pub fn downcast_ref<T: Any + Send + Sync + 'static>(&self) -> Option<&T> {
         self.payload.downcast_ref()
     }
+
+    /// Take the payload back out of a message nobody received.
+    fn into_inner<T: Any + Send + Sync + 'static>(self) -> T {
+        let payload = self.payload.downcast::<T>().expect("type mismatch");
+        Arc::try_unwrap(payload)
+            .ok()
+            .expect("undelivered message is not shared")
+    }
 }
 
 /// Error returned when sending a message fails.
@@ -80,18 +97,32 @@ pub struct MessageBus {
     /// Registered task names for validation.
     registered_tasks: HashMap<&'static str, ()>,
     /// Unified channel for receiving messages from all tasks.
+    #[cfg(any(feature = "record", all(unix, feature = "server")))]
     unified_tx: mpsc::Sender<TaskMessage>,
     unified_rx: Option<mpsc::Receiver<TaskMessage>>,
+    /// Every channel a message is delivered to, including the unified one.
+    targets: Targets,
+    /// Messages waiting in the unified channel, per task.
+    depths: QueueDepths,
 }
 
 impl MessageBus {
     /// Create a new empty message bus.
     pub fn new() -> Self {
         let (unified_tx, unified_rx) = mpsc::channel(DEFAULT_CHANNEL_SIZE * 4);
+        let targets = Targets::default();
+        let depths = QueueDepths::default();
+        targets.push(Target {
+            tx: unified_tx.clone(),
+            depths: Some(depths.clone()),
+        });
         Self {
             registered_tasks: HashMap::new(),
+            #[cfg(any(feature = "record", all(unix, feature = "server")))]
             unified_tx,
             unified_rx: Some(unified_rx),
+            targets,
+            depths,
         }
     }
 
@@ -100,12 +131,15 @@ impl MessageBus {
     /// Returns a sender that the task can use to send messages.
     /// Messages sent through this sender will be forwarded to the
     /// unified receiver with the task name attached.
-    pub fn register<T: Any + Send + 'static>(&mut self, task_name: &'static str) -> TaskSender<T> {
+    pub fn register<T: Any + Send + Sync + 'static>(
+        &mut self,
+        task_name: &'static str,
+    ) -> TaskSender<T> {
         self.registered_tasks.insert(task_name, ());
 
         TaskSender {
             task_name,
-            unified_tx: self.unified_tx.clone(),
+            targets: self.targets.clone(),
             _marker: std::marker::PhantomData,
         }
     }
@@ -114,14 +148,14 @@ impl MessageBus {
     ///
     /// This is useful when you need additional senders for an already
     /// registered task.
-    pub fn sender<T: Any + Send + 'static>(
+    pub fn sender<T: Any + Send + Sync + 'static>(
         &self,
         task_name: &'static str,
     ) -> Option<TaskSender<T>> {
         if self.registered_tasks.contains_key(task_name) {
             Some(TaskSender {
                 task_name,
-                unified_tx: self.unified_tx.clone(),
+                targets: self.targets.clone(),
                 _marker: std::marker::PhantomData,
             })
         } else {
@@ -137,6 +171,27 @@ impl MessageBus {
         self.unified_rx.take()
     }
 
+    /// Subscribe to the messages of all tasks.
+    ///
+    /// The receiver gets every message sent after this call, alongside the
+    /// unified receiver and other subscribers. Drop it to unsubscribe.
+    pub fn subscribe(&self) -> mpsc::Receiver<TaskMessage> {
+        let (tx, rx) = mpsc::channel(DEFAULT_CHANNEL_SIZE * 4);
+        self.targets.push(Target { tx, depths: None });
+        rx
+    }
+
+    /// Counters of the messages waiting in the unified channel.
+    pub(crate) fn depths(&self) -> QueueDepths {
+        self.depths.clone()
+    }
+
+    /// Create an untyped sender into the unified channel.
+    #[cfg(any(feature = "record", all(unix, feature = "server")))]
+    pub(crate) fn unified_sender(&self) -> mpsc::Sender<TaskMessage> {
+        self.unified_tx.clone()
+    }
+
     /// Check if a task is registered.
     pub fn has_task(&self, task_name: &str) -> bool {
         self.registered_tasks.contains_key(task_name)
@@ -154,10 +209,97 @@ impl Default for MessageBus {
     }
 }
 
-/// A typed sender wrapper that forwards messages to the unified channel.
+/// Number of messages each task has queued and the receiver has not taken
+/// yet.
+///
+/// Senders count a message before delivering it and the receiver uncounts
+/// it when taken, so a message is never uncounted before it was counted.
+/// Messages fed into the channel by other means are not counted.
+#[derive(Clone, Default)]
+pub(crate) struct QueueDepths(Arc<Mutex<HashMap<&'static str, usize>>>);
+
+impl QueueDepths {
+    fn add(&self, task_name: &'static str) {
+        *self.lock().entry(task_name).or_default() += 1;
+    }
+
+    /// Uncount a message of `task_name` taken by the receiver.
+    pub(crate) fn remove(&self, task_name: &'static str) {
+        if let Some(depth) = self.lock().get_mut(task_name) {
+            *depth = depth.saturating_sub(1);
+        }
+    }
+
+    /// The depth of every task that sent a message, sorted by task name.
+    pub(crate) fn snapshot(&self) -> Vec<(&'static str, usize)> {
+        let mut depths: Vec<_> = self.lock().iter().map(|(&name, &n)| (name, n)).collect();
+        depths.sort_unstable();
+        depths
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<&'static str, usize>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A channel messages are delivered to.
+#[derive(Clone)]
+struct Target {
+    tx: mpsc::Sender<TaskMessage>,
+    /// Set for the unified channel, whose queue is measured.
+    depths: Option<QueueDepths>,
+}
+
+impl Target {
+    /// Count a message of `task_name` about to be delivered.
+    fn count(&self, task_name: &'static str) {
+        if let Some(depths) = &self.depths {
+            depths.add(task_name);
+        }
+    }
+
+    /// Uncount a message of `task_name` that was not delivered.
+    fn uncount(&self, task_name: &'static str) {
+        if let Some(depths) = &self.depths {
+            depths.remove(task_name);
+        }
+    }
+}
+
+/// The channels messages are delivered to, shared by the bus and its
+/// senders so later subscriptions reach running tasks.
+#[derive(Clone, Default)]
+struct Targets(Arc<Mutex<Vec<Target>>>);
+
+impl Targets {
+    fn push(&self, tx: Target) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(tx);
+    }
+
+    fn snapshot(&self) -> Vec<Target> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Forget the channels whose receiver was dropped.
+    fn prune(&self) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|target| !target.tx.is_closed());
+    }
+}
+
+/// A typed sender wrapper that forwards messages to the unified channel
+/// and every subscriber.
 pub struct TaskSender<T> {
     task_name: &'static str,
-    unified_tx: mpsc::Sender<TaskMessage>,
+    targets: Targets,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -165,42 +307,74 @@ impl<T> Clone for TaskSender<T> {
     fn clone(&self) -> Self {
         Self {
             task_name: self.task_name,
-            unified_tx: self.unified_tx.clone(),
+            targets: self.targets.clone(),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<T: Any + Send + 'static> TaskSender<T> {
+impl<T: Any + Send + Sync + 'static> TaskSender<T> {
     /// Send a message.
     ///
-    /// This wraps the message and forwards it to the unified channel
-    /// with the task name attached.
+    /// This wraps the message and forwards it to the unified channel and
+    /// every subscriber with the task name attached. Waits while a
+    /// receiver is full. Fails only if every receiver was dropped.
     pub async fn send(&self, message: T) -> Result<(), SendError<T>> {
         let task_message = TaskMessage::new(self.task_name, message);
-        self.unified_tx.send(task_message).await.map_err(|e| {
-            // Extract the original message from TaskMessage
-            let payload = e.0.payload;
-            let msg = payload
-                .downcast::<T>()
-                .expect("type mismatch in TaskSender");
-            SendError(*msg)
-        })
+        let mut delivered = false;
+        let mut closed = false;
+        for target in self.targets.snapshot() {
+            target.count(self.task_name);
+            match target.tx.send(task_message.clone()).await {
+                Ok(()) => delivered = true,
+                Err(_) => {
+                    target.uncount(self.task_name);
+                    closed = true;
+                }
+            }
+        }
+        if closed {
+            self.targets.prune();
+        }
+        if delivered {
+            Ok(())
+        } else {
+            Err(SendError(task_message.into_inner()))
+        }
     }
 
     /// Try to send a message without blocking.
+    ///
+    /// Receivers that are full miss the message. Fails only if no
+    /// receiver got it.
     pub fn try_send(&self, message: T) -> Result<(), TrySendError<T>> {
         let task_message = TaskMessage::new(self.task_name, message);
-        self.unified_tx.try_send(task_message).map_err(|e| match e {
-            mpsc::error::TrySendError::Full(tm) => {
-                let msg = tm.payload.downcast::<T>().expect("type mismatch");
-                TrySendError::Full(*msg)
-            }
-            mpsc::error::TrySendError::Closed(tm) => {
-                let msg = tm.payload.downcast::<T>().expect("type mismatch");
-                TrySendError::Closed(*msg)
+        let mut delivered = false;
+        let mut full = false;
+        let mut closed = false;
+        for target in self.targets.snapshot() {
+            target.count(self.task_name);
+            match target.tx.try_send(task_message.clone()) {
+                Ok(()) => delivered = true,
+                Err(error) => {
+                    target.uncount(self.task_name);
+                    match error {
+                        mpsc::error::TrySendError::Full(_) => full = true,
+                        mpsc::error::TrySendError::Closed(_) => closed = true,
+                    }
+                }
             }
-        })
+        }
+        if closed {
+            self.targets.prune();
+        }
+        if delivered {
+            Ok(())
+        } else if full {
+            Err(TrySendError::Full(task_message.into_inner()))
+        } else {
+            Err(TrySendError::Closed(task_message.into_inner()))
+        }
     }
 
     /// Get the task name associated with this sender.
@@ -228,3 +402,36 @@ impl<T> std::fmt::Display for TrySendError<T> {
 }
 
 impl<T: std::fmt::Debug> std::error::Error for TrySendError<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_reach_every_subscriber() {
+        let mut bus = MessageBus::new();
+        let sender = bus.register::<String>("controller");
+        let mut unified = bus.take_receiver().unwrap();
+        let mut logger = bus.subscribe();
+
+        sender.try_send("node-1".to_string()).unwrap();
+        assert_eq!(bus.depths().snapshot(), vec![("controller", 1)]);
+        let a = unified.try_recv().unwrap();
+        let b = logger.try_recv().unwrap();
+        assert!(Arc::ptr_eq(&a.payload, &b.payload));
+        assert_eq!(
+            b.downcast::<String>().ok().as_deref().map(String::as_str),
+            Some("node-1")
+        );
+
+        // Dropped receivers are skipped; with none left the message comes back
+        drop(unified);
+        sender.try_send("node-2".to_string()).unwrap();
+        assert!(logger.try_recv().is_ok());
+        drop(logger);
+        assert!(matches!(
+            sender.try_send("node-3".to_string()),
+            Err(TrySendError::Closed(message)) if message == "node-3"
+        ));
+    }
+}
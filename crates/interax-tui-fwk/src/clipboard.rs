@@ -0,0 +1,290 @@
+//! Clipboard support for the TUI framework.
+//!
+//! `Clipboard::copy` queues text that the app writes to the terminal with
+//! the OSC 52 escape sequence once the current event is handled. The
+//! terminal puts it on the clipboard of the machine it runs on, so copying
+//! works over SSH too. Inside tmux the sequence is passed through to the
+//! outer terminal. Terminals without OSC 52 support ignore it.
+//!
+//! Because OSC 52 support cannot be detected, a clipboard created with
+//! `Clipboard::with_local_fallback` also pipes the text to a local
+//! clipboard command (`wl-copy`, `xclip`, `xsel`, `pbcopy` or `clip.exe`)
+//! when the app does not run over SSH.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppBuilder, Clipboard};
+//!
+//! // In an event handler
+//! ctx.clipboard().copy(endpoint.id.clone());
+//! ctx.notifier().info("Copied endpoint ID");
+//!
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .clipboard(Clipboard::with_local_fallback())
+//!     .build()?;
+//! ```
+
+use std::env;
+use std::fmt;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+
+use tokio::sync::Notify;
+
+/// Largest encoded payload sent with OSC 52.
+///
+/// Several terminals drop longer sequences; such text only goes to the
+/// local clipboard.
+const MAX_OSC52_LEN: usize = 100_000;
+
+/// Local clipboard commands, tried in order by `LocalClipboard::detect`.
+///
+/// Each entry is the environment variable that must be set (if any), the
+/// program and its arguments.
+const LOCAL_COMMANDS: &[(Option<&str>, &str, &[&str])] = &[
+    (Some("WAYLAND_DISPLAY"), "wl-copy", &[]),
+    (Some("DISPLAY"), "xclip", &["-selection", "clipboard"]),
+    (Some("DISPLAY"), "xsel", &["--clipboard", "--input"]),
+    (None, "pbcopy", &[]),
+    (Some("WSL_DISTRO_NAME"), "clip.exe", &[]),
+];
+
+struct State {
+    last: Option<String>,
+    pending: Vec<String>,
+    local: Option<LocalClipboard>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    notify: Notify,
+}
+
+/// A handle to the system clipboard.
+///
+/// Cloning is cheap and every clone shares the same queue, so a handle can
+/// be moved into a task.
+#[derive(Clone)]
+pub struct Clipboard {
+    shared: Arc<Shared>,
+}
+
+impl Clipboard {
+    /// Create a clipboard that only copies via OSC 52.
+    pub fn new() -> Self {
+        Self::with_local(None)
+    }
+
+    /// Create a clipboard that also copies with the first local clipboard
+    /// command found, unless the app runs over SSH.
+    pub fn with_local_fallback() -> Self {
+        Self::with_local(LocalClipboard::detect())
+    }
+
+    /// Create a clipboard that also copies with `local`.
+    pub fn with_local(local: Option<LocalClipboard>) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    last: None,
+                    pending: Vec::new(),
+                    local,
+                }),
+                notify: Notify::new(),
+            }),
+        }
+    }
+
+    /// Copy `text` to the clipboard.
+    pub fn copy(&self, text: impl Into<String>) {
+        let text = text.into();
+        {
+            let mut state = self.lock();
+            state.last = Some(text.clone());
+            state.pending.push(text);
+        }
+        self.shared.notify.notify_one();
+    }
+
+    /// The text copied last through this clipboard.
+    ///
+    /// The system clipboard cannot be read back, so this only covers
+    /// copies made by the app itself.
+    pub fn last(&self) -> Option<String> {
+        self.lock().last.clone()
+    }
+
+    /// Wait until text is copied.
+    pub async fn changed(&self) {
+        self.shared.notify.notified().await;
+    }
+
+    /// Take the copied text not written to the terminal yet, as OSC 52
+    /// sequences, and hand it to the local clipboard.
+    pub(crate) fn take_sequences(&self) -> Vec<String> {
+        let mut state = self.lock();
+        let pending = std::mem::take(&mut state.pending);
+        if let Some(local) = &state.local {
+            for text in &pending {
+                local.copy(text.clone());
+            }
+        }
+        let tmux = env::var_os("TMUX").is_some();
+        pending
+            .iter()
+            .filter_map(|text| osc52(text, tmux))
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for Clipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("Clipboard")
+            .field("pending", &state.pending.len())
+            .field("local", &state.local)
+            .finish()
+    }
+}
+
+/// A local command that reads text to copy from its standard input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalClipboard {
+    program: String,
+    args: Vec<String>,
+}
+
+impl LocalClipboard {
+    /// Use `program` with `args` as the local clipboard.
+    pub fn new(program: impl Into<String>, args: &[&str]) -> Self {
+        Self {
+            program: program.into(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// Find a clipboard command for the current session.
+    ///
+    /// Returns `None` over SSH, where a local command would copy on the
+    /// remote host, or if no known command is installed.
+    pub fn detect() -> Option<Self> {
+        if env::var_os("SSH_CONNECTION").is_some() || env::var_os("SSH_TTY").is_some() {
+            return None;
+        }
+        LOCAL_COMMANDS
+            .iter()
+            .filter(|(var, ..)| var.is_none_or(|var| env::var_os(var).is_some()))
+            .find(|(_, program, _)| is_installed(program))
+            .map(|(_, program, args)| Self::new(*program, args))
+    }
+
+    /// Pipe `text` to the command on a background thread.
+    fn copy(&self, text: String) {
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        // Failures are ignored: the fallback is best effort and OSC 52 may
+        // have worked
+        thread::spawn(move || {
+            let Ok(mut child) = command.spawn() else {
+                return;
+            };
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+        });
+    }
+}
+
+/// Check if `program` is an executable in `PATH`.
+fn is_installed(program: &str) -> bool {
+    env::var_os("PATH")
+        .is_some_and(|path| env::split_paths(&path).any(|dir| dir.join(program).is_file()))
+}
+
+/// Build the OSC 52 sequence copying `text`, wrapped for tmux if needed.
+///
+/// Returns `None` if the text is too long to send.
+fn osc52(text: &str, tmux: bool) -> Option<String> {
+    let encoded = base64(text.as_bytes());
+    if encoded.len() > MAX_OSC52_LEN {
+        return None;
+    }
+    let sequence = format!("\x1b]52;c;{}\x07", encoded);
+    if tmux {
+        // tmux passes DCS payloads through with every ESC doubled
+        Some(format!(
+            "\x1bPtmux;{}\x1b\\",
+            sequence.replace('\x1b', "\x1b\x1b")
+        ))
+    } else {
+        Some(sequence)
+    }
+}
+
+/// Standard base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequences() {
+        assert_eq!(base64(b"node-1"), "bm9kZS0x");
+        assert_eq!(base64(b"ab"), "YWI=");
+        assert_eq!(base64(b"a"), "YQ==");
+        assert_eq!(osc52("ab", false).unwrap(), "\x1b]52;c;YWI=\x07");
+        assert_eq!(
+            osc52("ab", true).unwrap(),
+            "\x1bPtmux;\x1b\x1b]52;c;YWI=\x07\x1b\\"
+        );
+        assert!(osc52(&"x".repeat(MAX_OSC52_LEN), false).is_none());
+
+        let clipboard = Clipboard::new();
+        clipboard.copy("ep-42");
+        assert_eq!(clipboard.last().as_deref(), Some("ep-42"));
+        assert_eq!(clipboard.take_sequences().len(), 1);
+        assert!(clipboard.take_sequences().is_empty());
+    }
+}
@@ -5,8 +5,15 @@
 use ratatui::{layout::Rect, Frame};
 
 use crate::context::{AppContext, DrawContext};
+use crate::effect::EffectOutput;
 use crate::event::Event;
 use crate::focus::EventResult;
+use crate::l10n::Localizer;
+use crate::palette::Command;
+#[cfg(feature = "persist")]
+use crate::persist::Session;
+use crate::responsive::Breakpoint;
+use crate::timer::Timer;
 
 /// A UI component that can draw itself and handle events.
 ///
@@ -90,14 +97,106 @@ pub trait Component: Send {
         EventResult::Unhandled
     }
 
+    /// Handle the result of an effect spawned with `AppContext::spawn_effect`.
+    ///
+    /// Results are dispatched like events, until a component returns
+    /// `Handled`. Check `output.id()` or `output.owner()` to recognize your
+    /// own effects, and `output.take()` to get the typed result.
+    ///
+    /// The default implementation offers the result to `children_mut()`.
+    fn handle_effect(&mut self, output: &mut EffectOutput, ctx: &mut AppContext) -> EventResult {
+        for child in self.children_mut() {
+            let result = child.handle_effect(output, ctx);
+            if !result.should_propagate() {
+                return result;
+            }
+        }
+        EventResult::Unhandled
+    }
+
+    /// Handle a timer set with `AppContext::set_timeout` or
+    /// `AppContext::set_interval`.
+    ///
+    /// Timers are dispatched like events, until a component returns
+    /// `Handled`. Check `timer.id()` to recognize your own timers.
+    ///
+    /// The default implementation offers the timer to `children_mut()`.
+    fn handle_timer(&mut self, timer: &Timer, ctx: &mut AppContext) -> EventResult {
+        for child in self.children_mut() {
+            let result = child.handle_timer(timer, ctx);
+            if !result.should_propagate() {
+                return result;
+            }
+        }
+        EventResult::Unhandled
+    }
+
     /// Called on each tick cycle if the app has a tick rate configured.
     ///
     /// The `ctx` parameter provides access to application-level controls.
     ///
     /// Use this for periodic updates like animations or polling.
-    /// The default implementation does nothing.
+    /// The default implementation ticks `children_mut()`.
+    fn tick(&mut self, ctx: &mut AppContext) {
+        for child in self.children_mut() {
+            child.tick(ctx);
+        }
+    }
+
+    /// The child components of a container.
+    ///
+    /// The default `tick`, `handle_timer` and `handle_effect` forward to
+    /// them, so a container only lists its children to pass these on.
+    /// Events are not forwarded; containers decide how to route input.
+    ///
+    /// The default implementation returns no children.
+    fn children_mut(&mut self) -> Vec<&mut dyn Component> {
+        vec![]
+    }
+
+    // --- Command palette ---
+
+    /// Commands this component offers in the command palette.
+    ///
+    /// The framework only asks the `MainUi`; containers should append the
+    /// commands of their child components.
+    fn commands(&self) -> Vec<Command> {
+        vec![]
+    }
+
+    /// Run a command chosen in the command palette.
+    ///
+    /// Containers should forward unknown ids to their child components.
+    /// The default implementation returns `Unhandled`.
+    #[allow(unused_variables)]
+    fn on_command(&mut self, id: &str, ctx: &mut AppContext) -> EventResult {
+        EventResult::Unhandled
+    }
+
+    // --- Localization ---
+
+    /// Refresh cached localized strings.
+    ///
+    /// Called on the `MainUi` at startup and whenever the locale changes.
+    /// Containers should forward to their child components. Strings looked
+    /// up while drawing via `DrawContext::tr` need no refreshing.
     #[allow(unused_variables)]
-    fn tick(&mut self, ctx: &mut AppContext) {}
+    fn on_locale_change(&mut self, l10n: &Localizer) {}
+
+    // --- Session state ---
+
+    /// Save state to restore on the next start.
+    ///
+    /// Only called on shutdown when `AppBuilder::persist` is set.
+    /// Containers should forward to their child components.
+    #[cfg(feature = "persist")]
+    #[allow(unused_variables)]
+    fn save_state(&self, session: &mut Session) {}
+
+    /// Restore state saved by `save_state`, before the first draw.
+    #[cfg(feature = "persist")]
+    #[allow(unused_variables)]
+    fn restore_state(&mut self, session: &Session) {}
 
     // --- Focus methods ---
 
@@ -189,16 +288,26 @@ pub trait MainUi: Component {
     /// The `task_name` identifies which task sent the message.
     /// The `ctx` parameter provides access to application-level controls.
     ///
+    /// The message is shared with any other subscriber of the bus; use
+    /// `Arc::downcast` to get the typed message.
+    ///
     /// Returns `true` if a redraw is needed after processing the message.
     #[allow(unused_variables)]
     fn handle_task_message(
         &mut self,
         task_name: &str,
-        message: Box<dyn std::any::Any + Send>,
+        message: std::sync::Arc<dyn std::any::Any + Send + Sync>,
         ctx: &mut AppContext,
     ) -> bool {
         false
     }
+
+    /// Called at startup and whenever a resize crosses a breakpoint.
+    ///
+    /// Use this to switch to an alternative layout, e.g. to collapse
+    /// panes in compact terminals. The default implementation does nothing.
+    #[allow(unused_variables)]
+    fn on_breakpoint(&mut self, breakpoint: Breakpoint, ctx: &mut AppContext) {}
 }
 
 /// A boxed component for type-erased storage.
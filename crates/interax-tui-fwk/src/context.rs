@@ -4,11 +4,87 @@
 //! and draw methods, allowing components to control application behavior
 //! and access shared state.
 
-use ratatui::{layout::Rect, Frame};
+use std::future::Future;
+use std::time::{Duration, Instant};
 
+use ratatui::{layout::Rect, style::Style, Frame};
+
+use crate::animation::{self, Animator};
+use crate::clipboard::Clipboard;
+use crate::effect::{EffectId, Effects, Scope};
 use crate::focus::FocusManager;
+use crate::l10n::Localizer;
+use crate::notify::Notifier;
+use crate::responsive::Breakpoint;
+use crate::router::{BoxedScreen, Navigation, Router};
+use crate::stats::FrameStats;
+use crate::status::StatusBar;
+use crate::store::Store;
 use crate::tabs::{TabInfo, TabManager};
 use crate::terminal::{Terminal, TerminalError};
+use crate::theme::Theme;
+use crate::timer::Timers;
+
+// =============================================================================
+// Services - Shared handles reachable from every context
+// =============================================================================
+
+/// Shared handles owned by the app and lent to every context.
+#[derive(Clone, Default)]
+pub(crate) struct Services {
+    pub(crate) notifier: Notifier,
+    pub(crate) status_bar: StatusBar,
+    pub(crate) store: Store,
+    pub(crate) l10n: Localizer,
+    pub(crate) clipboard: Clipboard,
+    pub(crate) effects: Effects,
+    pub(crate) timers: Timers,
+    pub(crate) animator: Animator,
+}
+
+impl Services {
+    /// Spawn an effect addressed to `owner`, cancelled with `tab` and, if
+    /// it is a registered focus id, with `owner`.
+    fn spawn_effect<F>(
+        &self,
+        focus_manager: &FocusManager,
+        owner: Option<String>,
+        tab: Option<String>,
+        future: F,
+    ) -> EffectId
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let scope = scope(focus_manager, &owner, tab);
+        self.effects.spawn(owner, scope, future)
+    }
+
+    /// Set a timer owned by the focused component.
+    fn set_timer(
+        &self,
+        focus_manager: &FocusManager,
+        tab: Option<String>,
+        id: &str,
+        delay: Duration,
+        interval: Option<Duration>,
+    ) {
+        let owner = focus_manager.focused_id().map(String::from);
+        let scope = scope(focus_manager, &owner, tab);
+        self.timers.set(id, owner, scope, delay, interval);
+    }
+}
+
+/// Scope of an effect or timer of `owner`, which only cancels it with
+/// `owner` if `owner` is a registered focus id.
+fn scope(focus_manager: &FocusManager, owner: &Option<String>, tab: Option<String>) -> Scope {
+    Scope {
+        owner: owner
+            .clone()
+            .filter(|owner| focus_manager.focus_order().contains(owner)),
+        tab,
+    }
+}
 
 // =============================================================================
 // TabEventContext - Context for Tab event handlers (no TabManager access)
@@ -24,22 +100,44 @@ use crate::terminal::{Terminal, TerminalError};
 /// - Toggle mouse capture
 /// - Access terminal state
 /// - Navigate focus
+/// - Request screen navigation
+/// - Show toasts, update the status bar and write to the store
+/// - Copy text to the clipboard
+/// - Spawn async effects and set timers
 pub struct TabEventContext<'a> {
     pub(crate) terminal: &'a mut Terminal,
     pub(crate) focus_manager: &'a mut FocusManager,
+    pub(crate) services: &'a Services,
     pub(crate) should_quit: bool,
+    pub(crate) navigation: Vec<Navigation>,
+    /// The top-level tab handling the event, if any.
+    pub(crate) tab: Option<String>,
 }
 
 impl<'a> TabEventContext<'a> {
     /// Create a new tab event context.
-    pub(crate) fn new(terminal: &'a mut Terminal, focus_manager: &'a mut FocusManager) -> Self {
+    pub(crate) fn new(
+        terminal: &'a mut Terminal,
+        focus_manager: &'a mut FocusManager,
+        services: &'a Services,
+    ) -> Self {
         Self {
             terminal,
             focus_manager,
+            services,
             should_quit: false,
+            navigation: Vec::new(),
+            tab: None,
         }
     }
 
+    /// Scope the effects and timers started through this context to the
+    /// tab `id`.
+    pub(crate) fn in_tab(mut self, id: Option<&str>) -> Self {
+        self.tab = id.map(String::from);
+        self
+    }
+
     /// Request the application to quit.
     #[inline]
     pub fn quit(&mut self) {
@@ -75,6 +173,143 @@ impl<'a> TabEventContext<'a> {
             manager: self.focus_manager,
         }
     }
+
+    /// Access the toast notification queue.
+    #[inline]
+    pub fn notifier(&self) -> &Notifier {
+        &self.services.notifier
+    }
+
+    /// Access the status bar.
+    #[inline]
+    pub fn status_bar(&self) -> &StatusBar {
+        &self.services.status_bar
+    }
+
+    /// Access the shared state store.
+    #[inline]
+    pub fn store(&self) -> &Store {
+        &self.services.store
+    }
+
+    /// Access the localizer, e.g. to switch the locale.
+    #[inline]
+    pub fn l10n(&self) -> &Localizer {
+        &self.services.l10n
+    }
+
+    /// Access the clipboard, e.g. to copy an identifier.
+    #[inline]
+    pub fn clipboard(&self) -> &Clipboard {
+        &self.services.clipboard
+    }
+
+    /// Access the animator, e.g. to start a tween or a spinner.
+    #[inline]
+    pub fn animator(&self) -> &Animator {
+        &self.services.animator
+    }
+
+    /// Run `future` on the runtime and deliver its result to the focused
+    /// component via `handle_effect`.
+    ///
+    /// The effect is cancelled when the tab handling this event is
+    /// deactivated. See the `effect` module.
+    pub fn spawn_effect<F>(&mut self, future: F) -> EffectId
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let owner = self.focus_manager.focused_id().map(String::from);
+        self.services
+            .spawn_effect(self.focus_manager, owner, self.tab.clone(), future)
+    }
+
+    /// Run `future` on the runtime and deliver its result to the component
+    /// with focus id `owner`.
+    pub fn spawn_effect_for<F>(&mut self, owner: &str, future: F) -> EffectId
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.services.spawn_effect(
+            self.focus_manager,
+            Some(owner.to_string()),
+            self.tab.clone(),
+            future,
+        )
+    }
+
+    /// Cancel an effect, dropping its result.
+    ///
+    /// Returns `false` if it already completed or was cancelled.
+    pub fn cancel_effect(&mut self, id: EffectId) -> bool {
+        self.services.effects.cancel(id)
+    }
+
+    /// Fire `handle_timer` with a `Timer` named `id` once, after `delay`.
+    ///
+    /// Replaces any timer with the same id. The timer belongs to the
+    /// focused component, and to the tab handling this event. See the `timer` module.
+    pub fn set_timeout(&mut self, id: &str, delay: Duration) {
+        self.services
+            .set_timer(self.focus_manager, self.tab.clone(), id, delay, None);
+    }
+
+    /// Fire `handle_timer` with a `Timer` named `id` every `period`, until
+    /// cancelled.
+    pub fn set_interval(&mut self, id: &str, period: Duration) {
+        self.services.set_timer(
+            self.focus_manager,
+            self.tab.clone(),
+            id,
+            period,
+            Some(period),
+        );
+    }
+
+    /// Fire `handle_timer` once no call with the same `id` was made for
+    /// `delay`.
+    ///
+    /// Each call restarts the delay, e.g. to act when typing pauses.
+    pub fn debounce(&mut self, id: &str, delay: Duration) {
+        self.set_timeout(id, delay);
+    }
+
+    /// Check if the action `id` may run now, at most once per `period`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if ctx.throttle("refresh", Duration::from_secs(1)) {
+    ///     self.refresh();
+    /// }
+    /// ```
+    pub fn throttle(&mut self, id: &str, period: Duration) -> bool {
+        self.services.timers.throttle(id, period, Instant::now())
+    }
+
+    /// Cancel the timer `id`.
+    ///
+    /// Returns `false` if no such timer is scheduled.
+    pub fn cancel_timer(&mut self, id: &str) -> bool {
+        self.services.timers.cancel(id)
+    }
+
+    /// Request screen navigation.
+    ///
+    /// Requests are queued and applied after the current handler returns.
+    #[inline]
+    pub fn navigate(&mut self) -> NavigateRequestContext<'_> {
+        NavigateRequestContext {
+            queue: &mut self.navigation,
+        }
+    }
+
+    /// Take the queued navigation requests.
+    pub(crate) fn take_navigation(&mut self) -> Vec<Navigation> {
+        std::mem::take(&mut self.navigation)
+    }
 }
 
 // =============================================================================
@@ -89,6 +324,9 @@ impl<'a> TabEventContext<'a> {
 /// - Access terminal state
 /// - Control tab selection
 /// - Navigate focus
+/// - Navigate between screens
+/// - Show toasts and update the status bar
+/// - Spawn async effects and set timers
 ///
 /// # Example
 ///
@@ -116,7 +354,11 @@ pub struct AppContext<'a> {
     pub(crate) terminal: &'a mut Terminal,
     pub(crate) tab_manager: &'a mut TabManager,
     pub(crate) focus_manager: &'a mut FocusManager,
+    pub(crate) router: &'a mut Router,
+    pub(crate) services: &'a Services,
+    pub(crate) breakpoint: Breakpoint,
     pub(crate) should_quit: bool,
+    pub(crate) open_palette: bool,
 }
 
 impl<'a> AppContext<'a> {
@@ -125,12 +367,19 @@ impl<'a> AppContext<'a> {
         terminal: &'a mut Terminal,
         tab_manager: &'a mut TabManager,
         focus_manager: &'a mut FocusManager,
+        router: &'a mut Router,
+        services: &'a Services,
+        breakpoint: Breakpoint,
     ) -> Self {
         Self {
             terminal,
             tab_manager,
             focus_manager,
+            router,
+            services,
+            breakpoint,
             should_quit: false,
+            open_palette: false,
         }
     }
 
@@ -167,6 +416,147 @@ impl<'a> AppContext<'a> {
         self.terminal.size()
     }
 
+    /// Access the toast notification queue.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ctx.notifier().warning("Endpoint 3 did not respond");
+    /// ```
+    #[inline]
+    pub fn notifier(&self) -> &Notifier {
+        &self.services.notifier
+    }
+
+    /// Access the status bar shown with `AppBuilder::status_bar(true)`.
+    #[inline]
+    pub fn status_bar(&self) -> &StatusBar {
+        &self.services.status_bar
+    }
+
+    /// Access the shared state store.
+    #[inline]
+    pub fn store(&self) -> &Store {
+        &self.services.store
+    }
+
+    /// Access the localizer, e.g. to switch the locale.
+    #[inline]
+    pub fn l10n(&self) -> &Localizer {
+        &self.services.l10n
+    }
+
+    /// Access the clipboard, e.g. to copy an identifier.
+    #[inline]
+    pub fn clipboard(&self) -> &Clipboard {
+        &self.services.clipboard
+    }
+
+    /// Access the animator, e.g. to start a tween or a spinner.
+    #[inline]
+    pub fn animator(&self) -> &Animator {
+        &self.services.animator
+    }
+
+    /// The breakpoint of the current terminal width.
+    #[inline]
+    pub fn breakpoint(&self) -> Breakpoint {
+        self.breakpoint
+    }
+
+    /// Run `future` on the runtime and deliver its result to the focused
+    /// component via `handle_effect`.
+    ///
+    /// The effect is cancelled when that component's focus id is
+    /// unregistered. See the `effect` module.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let client = self.client.clone();
+    /// self.loading = Some(ctx.spawn_effect(async move { client.endpoints().await }));
+    /// ```
+    pub fn spawn_effect<F>(&mut self, future: F) -> EffectId
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let owner = self.focus_manager.focused_id().map(String::from);
+        self.services
+            .spawn_effect(self.focus_manager, owner, None, future)
+    }
+
+    /// Run `future` on the runtime and deliver its result to the component
+    /// with focus id `owner`.
+    pub fn spawn_effect_for<F>(&mut self, owner: &str, future: F) -> EffectId
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.services
+            .spawn_effect(self.focus_manager, Some(owner.to_string()), None, future)
+    }
+
+    /// Cancel an effect, dropping its result.
+    ///
+    /// Returns `false` if it already completed or was cancelled.
+    pub fn cancel_effect(&mut self, id: EffectId) -> bool {
+        self.services.effects.cancel(id)
+    }
+
+    /// Fire `handle_timer` with a `Timer` named `id` once, after `delay`.
+    ///
+    /// Replaces any timer with the same id. The timer belongs to the
+    /// focused component. See the `timer` module.
+    pub fn set_timeout(&mut self, id: &str, delay: Duration) {
+        self.services
+            .set_timer(self.focus_manager, None, id, delay, None);
+    }
+
+    /// Fire `handle_timer` with a `Timer` named `id` every `period`, until
+    /// cancelled.
+    pub fn set_interval(&mut self, id: &str, period: Duration) {
+        self.services
+            .set_timer(self.focus_manager, None, id, period, Some(period));
+    }
+
+    /// Fire `handle_timer` once no call with the same `id` was made for
+    /// `delay`.
+    ///
+    /// Each call restarts the delay, e.g. to act when typing pauses.
+    pub fn debounce(&mut self, id: &str, delay: Duration) {
+        self.set_timeout(id, delay);
+    }
+
+    /// Check if the action `id` may run now, at most once per `period`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if ctx.throttle("refresh", Duration::from_secs(1)) {
+    ///     self.refresh();
+    /// }
+    /// ```
+    pub fn throttle(&mut self, id: &str, period: Duration) -> bool {
+        self.services.timers.throttle(id, period, Instant::now())
+    }
+
+    /// Cancel the timer `id`.
+    ///
+    /// Returns `false` if no such timer is scheduled.
+    pub fn cancel_timer(&mut self, id: &str) -> bool {
+        self.services.timers.cancel(id)
+    }
+
+    /// Open the command palette after the current handler returns.
+    ///
+    /// Does nothing if the palette was disabled with
+    /// `AppBuilder::command_palette(false)`.
+    #[inline]
+    pub fn open_command_palette(&mut self) {
+        self.open_palette = true;
+    }
+
     /// Access tab controls for event handling.
     ///
     /// Use this to select tabs, navigate between tabs, etc.
@@ -214,6 +604,50 @@ impl<'a> AppContext<'a> {
             manager: self.focus_manager,
         }
     }
+
+    /// Access screen navigation for event handling.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Drill down into a detail screen
+    /// ctx.navigate().push(Box::new(DetailScreen::new(item)));
+    ///
+    /// // Or use a named route registered with `AppBuilder::route`
+    /// ctx.navigate().push_route("endpoint/42");
+    ///
+    /// // Go back
+    /// ctx.navigate().back();
+    /// ```
+    #[inline]
+    pub fn navigate(&mut self) -> NavigateEventContext<'_> {
+        NavigateEventContext {
+            router: self.router,
+        }
+    }
+
+    /// Run a closure with a `TabEventContext` borrowed from this context.
+    ///
+    /// Use this to forward events from a component to an embedded `Tab`,
+    /// such as a `TabGroup`. A quit or navigation requested by the tab
+    /// is propagated.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+    ///     ctx.with_tab_context(|tab_ctx| self.sub_tabs.handle_event(event, tab_ctx))
+    /// }
+    /// ```
+    pub fn with_tab_context<R>(&mut self, f: impl FnOnce(&mut TabEventContext<'_>) -> R) -> R {
+        let mut tab_ctx = TabEventContext::new(self.terminal, self.focus_manager, self.services);
+        let result = f(&mut tab_ctx);
+        self.should_quit = self.should_quit || tab_ctx.should_quit();
+        for navigation in tab_ctx.take_navigation() {
+            self.router.apply(navigation);
+        }
+        result
+    }
 }
 
 /// Focus controls available during event handling.
@@ -273,6 +707,138 @@ impl FocusEventContext<'_> {
     }
 }
 
+/// Screen navigation controls available during event handling.
+///
+/// Access this through `AppContext::navigate()`. Changes are applied immediately.
+pub struct NavigateEventContext<'a> {
+    router: &'a mut Router,
+}
+
+impl NavigateEventContext<'_> {
+    /// Push a screen on top of the navigation stack.
+    ///
+    /// This clears the forward history.
+    pub fn push(&mut self, screen: BoxedScreen) {
+        self.router.push(screen);
+    }
+
+    /// Push a screen created from a named route, e.g. `endpoint/42`.
+    ///
+    /// Returns `false` if no route matches the path.
+    pub fn push_route(&mut self, path: &str) -> bool {
+        self.router.push_route(path)
+    }
+
+    /// Replace the current screen.
+    pub fn replace(&mut self, screen: BoxedScreen) {
+        self.router.replace(screen);
+    }
+
+    /// Replace the current screen with one created from a named route.
+    ///
+    /// Returns `false` if no route matches the path.
+    pub fn replace_route(&mut self, path: &str) -> bool {
+        self.router.replace_route(path)
+    }
+
+    /// Remove and discard the current screen.
+    ///
+    /// Returns `true` if a screen was removed.
+    pub fn pop(&mut self) -> bool {
+        self.router.pop()
+    }
+
+    /// Go back one screen, keeping it in the forward history.
+    pub fn back(&mut self) -> bool {
+        self.router.back()
+    }
+
+    /// Go forward to the screen most recently left with `back()`.
+    pub fn forward(&mut self) -> bool {
+        self.router.forward()
+    }
+
+    /// Remove all screens and return to the tabs.
+    pub fn clear(&mut self) {
+        self.router.clear();
+    }
+
+    /// Check if a screen is currently shown.
+    pub fn is_active(&self) -> bool {
+        !self.router.is_empty()
+    }
+
+    /// Get the number of screens on the stack.
+    pub fn depth(&self) -> usize {
+        self.router.depth()
+    }
+
+    /// Check if `back()` would change the current screen.
+    pub fn can_go_back(&self) -> bool {
+        self.router.can_go_back()
+    }
+
+    /// Check if `forward()` would change the current screen.
+    pub fn can_go_forward(&self) -> bool {
+        self.router.can_go_forward()
+    }
+
+    /// Get the route path of the current screen, if it was created from a route.
+    pub fn current_path(&self) -> Option<&str> {
+        self.router.current_path()
+    }
+}
+
+/// Screen navigation requests available to tabs and screens.
+///
+/// Access this through `TabEventContext::navigate()`. Requests are queued
+/// and applied by the framework after the handler returns.
+pub struct NavigateRequestContext<'a> {
+    queue: &'a mut Vec<Navigation>,
+}
+
+impl NavigateRequestContext<'_> {
+    /// Push a screen on top of the navigation stack.
+    pub fn push(&mut self, screen: BoxedScreen) {
+        self.queue.push(Navigation::Push(screen));
+    }
+
+    /// Push a screen created from a named route, e.g. `endpoint/42`.
+    pub fn push_route(&mut self, path: &str) {
+        self.queue.push(Navigation::PushRoute(path.to_string()));
+    }
+
+    /// Replace the current screen.
+    pub fn replace(&mut self, screen: BoxedScreen) {
+        self.queue.push(Navigation::Replace(screen));
+    }
+
+    /// Replace the current screen with one created from a named route.
+    pub fn replace_route(&mut self, path: &str) {
+        self.queue.push(Navigation::ReplaceRoute(path.to_string()));
+    }
+
+    /// Remove and discard the current screen.
+    pub fn pop(&mut self) {
+        self.queue.push(Navigation::Pop);
+    }
+
+    /// Go back one screen, keeping it in the forward history.
+    pub fn back(&mut self) {
+        self.queue.push(Navigation::Back);
+    }
+
+    /// Go forward to the screen most recently left with `back()`.
+    pub fn forward(&mut self) {
+        self.queue.push(Navigation::Forward);
+    }
+
+    /// Remove all screens and return to the tabs.
+    pub fn clear(&mut self) {
+        self.queue.push(Navigation::Clear);
+    }
+}
+
 /// Tab controls available during event handling.
 ///
 /// Access this through `AppContext::tabs()`.
@@ -375,6 +941,11 @@ impl TabsEventContext<'_> {
 /// - Tab bar and content drawing
 /// - Tab information
 /// - Focus state (for visual highlighting)
+/// - The current navigation screen
+/// - The application theme
+/// - The current breakpoint
+/// - Localized messages
+/// - Event loop measurements
 ///
 /// # Example
 ///
@@ -401,25 +972,88 @@ impl TabsEventContext<'_> {
 pub struct DrawContext<'a> {
     pub(crate) tab_manager: &'a TabManager,
     pub(crate) focus_manager: &'a FocusManager,
+    pub(crate) router: &'a Router,
+    pub(crate) theme: &'a Theme,
+    pub(crate) breakpoint: Breakpoint,
+    pub(crate) l10n: &'a Localizer,
+    pub(crate) stats: &'a FrameStats,
+    pub(crate) animator: &'a Animator,
 }
 
 impl<'a> DrawContext<'a> {
     /// Create a new draw context.
-    pub(crate) fn new(tab_manager: &'a TabManager, focus_manager: &'a FocusManager) -> Self {
+    pub(crate) fn new(
+        tab_manager: &'a TabManager,
+        focus_manager: &'a FocusManager,
+        router: &'a Router,
+        theme: &'a Theme,
+        breakpoint: Breakpoint,
+        stats: &'a FrameStats,
+        services: &'a Services,
+    ) -> Self {
         Self {
             tab_manager,
             focus_manager,
+            router,
+            theme,
+            breakpoint,
+            l10n: &services.l10n,
+            stats,
+            animator: &services.animator,
         }
     }
 
+    /// Access the animator, e.g. to request the next frame of an
+    /// animation drawn from the clock.
+    #[inline]
+    pub fn animator(&self) -> &Animator {
+        self.animator
+    }
+
+    /// Measurements of the event loop, as of the previous frame.
+    #[inline]
+    pub fn stats(&self) -> &FrameStats {
+        self.stats
+    }
+
+    /// Access the localizer.
+    #[inline]
+    pub fn l10n(&self) -> &Localizer {
+        self.l10n
+    }
+
+    /// Look up a localized message without arguments.
+    ///
+    /// Use `l10n().format` for messages with arguments.
+    #[inline]
+    pub fn tr(&self, id: &str) -> String {
+        self.l10n.message(id)
+    }
+
+    /// The breakpoint of the current terminal width.
+    ///
+    /// Use this to pick a layout, e.g. with `Responsive::get`.
+    #[inline]
+    pub fn breakpoint(&self) -> Breakpoint {
+        self.breakpoint
+    }
+
     /// Access tab information and drawing methods.
     #[inline]
     pub fn tabs(&self) -> TabsDrawContext<'_> {
         TabsDrawContext {
             manager: self.tab_manager,
+            theme: self.theme,
+            animator: self.animator,
         }
     }
 
+    /// Access the application theme.
+    #[inline]
+    pub fn theme(&self) -> &Theme {
+        self.theme
+    }
+
     /// Access focus state for visual rendering.
     ///
     /// Use this to check if elements are focused for highlighting.
@@ -427,15 +1061,69 @@ impl<'a> DrawContext<'a> {
     pub fn focus(&self) -> FocusDrawContext<'_> {
         FocusDrawContext {
             manager: self.focus_manager,
+            animator: self.animator,
+        }
+    }
+
+    /// Access the navigation stack for rendering.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if ctx.navigate().is_active() {
+    ///     ctx.navigate().draw_screen(frame, area);
+    /// } else {
+    ///     ctx.tabs().draw_content(frame, area);
+    /// }
+    /// ```
+    #[inline]
+    pub fn navigate(&self) -> NavigateDrawContext<'_> {
+        NavigateDrawContext {
+            router: self.router,
         }
     }
 }
 
+/// Navigation drawing context available during rendering.
+///
+/// Access this through `DrawContext::navigate()`.
+pub struct NavigateDrawContext<'a> {
+    router: &'a Router,
+}
+
+impl NavigateDrawContext<'_> {
+    /// Check if a screen is currently shown.
+    pub fn is_active(&self) -> bool {
+        !self.router.is_empty()
+    }
+
+    /// Get the number of screens on the stack.
+    pub fn depth(&self) -> usize {
+        self.router.depth()
+    }
+
+    /// Titles of all screens on the stack, from the bottom up.
+    pub fn breadcrumbs(&self) -> Vec<&str> {
+        self.router.breadcrumbs()
+    }
+
+    /// Get the route path of the current screen, if it was created from a route.
+    pub fn current_path(&self) -> Option<&str> {
+        self.router.current_path()
+    }
+
+    /// Draw the current screen.
+    pub fn draw_screen(&self, frame: &mut Frame, area: Rect) {
+        self.router.draw(frame, area);
+    }
+}
+
 /// Focus drawing context available during rendering.
 ///
 /// Access this through `DrawContext::focus()`.
 pub struct FocusDrawContext<'a> {
     manager: &'a FocusManager,
+    animator: &'a Animator,
 }
 
 impl FocusDrawContext<'_> {
@@ -457,6 +1145,22 @@ impl FocusDrawContext<'_> {
     pub fn is_in_focus_chain(&self, id: &str) -> bool {
         self.manager.is_in_focus_chain(id)
     }
+
+    /// How focused an element looks, from 0 to 1.
+    ///
+    /// This is 0 or 1, except while focus moves to or from `id` with a
+    /// transition set by `AppBuilder::focus_transition`.
+    pub fn focus_level(&self, id: &str) -> f64 {
+        self.animator
+            .focus_level(id, Instant::now())
+            .unwrap_or(if self.is_focused(id) { 1.0 } else { 0.0 })
+    }
+
+    /// Pick the style of an element, blending `unfocused` and `focused`
+    /// while its focus transition runs.
+    pub fn fade(&self, id: &str, unfocused: Style, focused: Style) -> Style {
+        animation::blend(unfocused, focused, self.focus_level(id))
+    }
 }
 
 /// Tab drawing context available during rendering.
@@ -464,6 +1168,8 @@ impl FocusDrawContext<'_> {
 /// Access this through `DrawContext::tabs()`.
 pub struct TabsDrawContext<'a> {
     manager: &'a TabManager,
+    theme: &'a Theme,
+    animator: &'a Animator,
 }
 
 impl TabsDrawContext<'_> {
@@ -495,15 +1201,18 @@ impl TabsDrawContext<'_> {
     /// Draw the tab bar to the given area.
     ///
     /// This renders a horizontal tab bar showing all registered tabs,
-    /// with the active tab highlighted.
+    /// with the active tab highlighted using the application theme.
     pub fn draw_tabbar(&self, frame: &mut Frame, area: Rect) {
-        self.manager.draw_tabbar(frame, area);
+        self.manager.draw_tabbar_themed(frame, area, self.theme);
     }
 
     /// Draw the content of the currently active tab.
     ///
-    /// This calls the active tab's `draw` method with the given area.
+    /// This calls the active tab's `draw` method with the given area, and
+    /// slides the content in while a tab transition runs.
     pub fn draw_content(&self, frame: &mut Frame, area: Rect) {
         self.manager.draw_content(frame, area);
+        let shift = self.animator.tab_shift(area.width, Instant::now());
+        animation::shift_area(frame.buffer_mut(), area, shift);
     }
 }
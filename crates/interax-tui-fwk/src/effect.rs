@@ -0,0 +1,302 @@
+//! One-shot async effects started from event handlers.
+//!
+//! Event handlers are synchronous. `AppContext::spawn_effect` runs a future
+//! on the runtime instead, for example a network request started by a key
+//! press, and hands its result back to the UI as an `EffectOutput` once it
+//! completes. The output goes to `Component::handle_effect` of the main UI
+//! first, then to the current screen or the active tab, the same way events
+//! are dispatched. Containers such as `LayoutView` and `TabGroup` forward
+//! it to their children until one takes it.
+//!
+//! An effect belongs to the component that had focus when it was spawned
+//! (or the owner passed to `spawn_effect_for`), and, when spawned by a tab,
+//! to that tab. It is cancelled, and its result dropped, when its owner is
+//! unregistered from focus, when its tab is deactivated, or by
+//! `cancel_effect`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppContext, Component, EffectId, EffectOutput, Event, EventResult};
+//!
+//! struct EndpointList {
+//!     loading: Option<EffectId>,
+//!     endpoints: Vec<Endpoint>,
+//! }
+//!
+//! impl Component for EndpointList {
+//!     fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+//!         if event.is_key(KeyCode::Char('r')) {
+//!             self.loading = Some(ctx.spawn_effect(fetch_endpoints()));
+//!             return EventResult::Handled;
+//!         }
+//!         EventResult::Unhandled
+//!     }
+//!
+//!     fn handle_effect(&mut self, output: &mut EffectOutput, ctx: &mut AppContext) -> EventResult {
+//!         if Some(output.id()) != self.loading {
+//!             return EventResult::Unhandled;
+//!         }
+//!         self.loading = None;
+//!         match output.take::<Result<Vec<Endpoint>, FetchError>>() {
+//!             Some(Ok(endpoints)) => self.endpoints = endpoints,
+//!             Some(Err(e)) => ctx.notifier().error(e.to_string()),
+//!             None => {}
+//!         }
+//!         EventResult::Handled
+//!     }
+//! }
+//! ```
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tokio::sync::mpsc;
+use tokio::task::AbortHandle;
+
+/// Identifies a spawned effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EffectId(u64);
+
+/// The result of a completed effect, on its way to the component that
+/// spawned it.
+pub struct EffectOutput {
+    id: EffectId,
+    owner: Option<String>,
+    value: Option<Box<dyn Any + Send>>,
+}
+
+impl EffectOutput {
+    /// The id returned when the effect was spawned.
+    pub fn id(&self) -> EffectId {
+        self.id
+    }
+
+    /// The focus id of the component that spawned the effect.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Check if the effect belongs to the component with focus id `id`.
+    pub fn is_owned_by(&self, id: &str) -> bool {
+        self.owner.as_deref() == Some(id)
+    }
+
+    /// Take the result if it is a `T`.
+    ///
+    /// Returns `None` if it is another type or was already taken.
+    pub fn take<T: Any>(&mut self) -> Option<T> {
+        match self.value.take()?.downcast::<T>() {
+            Ok(value) => Some(*value),
+            Err(value) => {
+                self.value = Some(value);
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Debug for EffectOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EffectOutput")
+            .field("id", &self.id)
+            .field("owner", &self.owner)
+            .field("taken", &self.value.is_none())
+            .finish()
+    }
+}
+
+/// What cancels an effect or a timer besides an explicit cancel.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Scope {
+    /// Focus id whose unregistration cancels it.
+    pub(crate) owner: Option<String>,
+    /// Id of the tab whose deactivation cancels it.
+    pub(crate) tab: Option<String>,
+}
+
+impl Scope {
+    /// Check if it belongs to the tab `tab`.
+    pub(crate) fn in_tab(&self, tab: &str) -> bool {
+        self.tab.as_deref() == Some(tab)
+    }
+
+    /// Check if its owner is no longer among the `registered` focus ids.
+    pub(crate) fn is_orphan(&self, registered: &[String]) -> bool {
+        self.owner
+            .as_ref()
+            .is_some_and(|owner| !registered.contains(owner))
+    }
+}
+
+/// A running effect.
+struct Running {
+    scope: Scope,
+    abort: AbortHandle,
+}
+
+struct State {
+    next_id: u64,
+    running: HashMap<EffectId, Running>,
+    rx: Option<mpsc::UnboundedReceiver<EffectOutput>>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    tx: mpsc::UnboundedSender<EffectOutput>,
+}
+
+/// The effects spawned by the UI, shared by every context.
+#[derive(Clone)]
+pub(crate) struct Effects {
+    shared: Arc<Shared>,
+}
+
+impl Effects {
+    pub(crate) fn new() -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    next_id: 0,
+                    running: HashMap::new(),
+                    rx: Some(rx),
+                }),
+                tx,
+            }),
+        }
+    }
+
+    /// Run `future` on the runtime and send its output, addressed to
+    /// `owner`, to the receiver.
+    pub(crate) fn spawn<F>(&self, owner: Option<String>, scope: Scope, future: F) -> EffectId
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let mut state = self.lock();
+        let id = EffectId(state.next_id);
+        state.next_id += 1;
+
+        let tx = self.shared.tx.clone();
+        let handle = tokio::spawn(async move {
+            let value = future.await;
+            let _ = tx.send(EffectOutput {
+                id,
+                owner,
+                value: Some(Box::new(value)),
+            });
+        });
+        state.running.insert(
+            id,
+            Running {
+                scope,
+                abort: handle.abort_handle(),
+            },
+        );
+        id
+    }
+
+    /// Cancel the effect `id`.
+    ///
+    /// Returns `false` if it already completed or was cancelled.
+    pub(crate) fn cancel(&self, id: EffectId) -> bool {
+        match self.lock().running.remove(&id) {
+            Some(running) => {
+                running.abort.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel the effects spawned by the tab `tab`.
+    pub(crate) fn cancel_tab(&self, tab: &str) {
+        self.cancel_where(|running| running.scope.in_tab(tab));
+    }
+
+    /// Cancel the effects whose owner is no longer registered.
+    pub(crate) fn cancel_orphans(&self, registered: &[String]) {
+        self.cancel_where(|running| running.scope.is_orphan(registered));
+    }
+
+    fn cancel_where(&self, cancel: impl Fn(&Running) -> bool) {
+        self.lock().running.retain(|_, running| {
+            if cancel(running) {
+                running.abort.abort();
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Mark the effect of `output` as completed.
+    ///
+    /// Returns `false` if it was cancelled, in which case the output must
+    /// be dropped.
+    pub(crate) fn complete(&self, output: &EffectOutput) -> bool {
+        self.lock().running.remove(&output.id).is_some()
+    }
+
+    /// Take the receiver of completed effects.
+    pub(crate) fn take_receiver(&self) -> Option<mpsc::UnboundedReceiver<EffectOutput>> {
+        self.lock().rx.take()
+    }
+
+    /// Number of running effects.
+    pub(crate) fn len(&self) -> usize {
+        self.lock().running.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for Effects {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_effects_complete_or_cancel() {
+        let effects = Effects::new();
+        let mut rx = effects.take_receiver().unwrap();
+
+        let id = effects.spawn(Some("list".into()), Scope::default(), async { 42u32 });
+        let mut output = rx.recv().await.unwrap();
+        assert_eq!(output.id(), id);
+        assert!(output.is_owned_by("list"));
+        assert!(effects.complete(&output));
+        assert_eq!(output.take::<String>(), None);
+        assert_eq!(output.take::<u32>(), Some(42));
+        assert_eq!(output.take::<u32>(), None);
+
+        let scope = |owner: Option<&str>, tab: Option<&str>| Scope {
+            owner: owner.map(String::from),
+            tab: tab.map(String::from),
+        };
+        let pending = std::future::pending::<()>;
+        let owned = effects.spawn(None, scope(Some("list"), None), pending());
+        let scoped = effects.spawn(None, scope(None, Some("logs")), pending());
+        let other = effects.spawn(Some("custom".into()), scope(None, None), pending());
+        effects.cancel_orphans(&["table".to_string()]);
+        effects.cancel_tab("logs");
+        assert_eq!(effects.len(), 1);
+        assert!(!effects.cancel(owned));
+        assert!(!effects.cancel(scoped));
+        assert!(effects.cancel(other));
+    }
+}
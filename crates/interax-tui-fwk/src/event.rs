@@ -8,6 +8,10 @@ use crossterm::event::{Event as CrosstermEvent, KeyEvent, MouseEvent};
 
 /// Unified event type for the TUI framework.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    any(feature = "record", feature = "server"),
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// Keyboard event
     Key(KeyEvent),
@@ -0,0 +1,410 @@
+//! Localization of framework and application strings with Project Fluent.
+//!
+//! A `Localizer` holds Fluent (`.ftl`) resources per locale and resolves
+//! message ids against the best match for the requested locales, falling
+//! back to `en-US`. The requested locales default to the user's
+//! `LANGUAGE`, `LC_ALL`, `LC_MESSAGES` or `LANG` environment variable.
+//!
+//! Components look messages up while drawing via `DrawContext::tr()`.
+//! Strings that are cached, such as tab titles returned by `Tab::title`,
+//! are refreshed in `Tab::on_locale_change` and
+//! `Component::on_locale_change`, which run at startup and whenever the
+//! locale is switched with `Localizer::set_locale`. Switching also redraws
+//! the UI.
+//!
+//! The framework's own strings use ids starting with `fwk-` (see
+//! `locales/en-US/framework.ftl`); an application resource with the same
+//! ids overrides them.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppBuilder, FluentArgs, Localizer};
+//!
+//! let l10n = Localizer::new();
+//! l10n.load_dir("locales")?; // locales/en-US/app.ftl, locales/de/app.ftl, ...
+//!
+//! // While drawing
+//! let mut args = FluentArgs::new();
+//! args.set("count", nodes.len());
+//! let title = ctx.l10n().format("nodes-title", &args);
+//!
+//! // In an event handler
+//! ctx.l10n().set_locale("de")?;
+//!
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .localizer(l10n)
+//!     .build()?;
+//! ```
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use tokio::sync::Notify;
+use unic_langid::LanguageIdentifier;
+
+/// Locale used when no requested locale is available.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The framework's own strings.
+const FRAMEWORK_FTL: &str = include_str!("../locales/en-US/framework.ftl");
+
+/// Error returned when adding resources or switching locales.
+#[derive(Debug)]
+pub enum L10nError {
+    /// A locale is not a valid language identifier.
+    InvalidLocale(String),
+    /// A resource has syntax errors; valid messages were still added.
+    Parse {
+        /// Locale of the resource.
+        locale: String,
+        /// Description of each error.
+        errors: Vec<String>,
+    },
+    /// Reading a resource directory failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for L10nError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            L10nError::InvalidLocale(locale) => write!(f, "invalid locale: {}", locale),
+            L10nError::Parse { locale, errors } => {
+                write!(f, "{} errors in {} resource", errors.len(), locale)
+            }
+            L10nError::Io(err) => write!(f, "IO error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for L10nError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            L10nError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for L10nError {
+    fn from(err: io::Error) -> Self {
+        L10nError::Io(err)
+    }
+}
+
+type Bundle = FluentBundle<Arc<FluentResource>>;
+
+struct State {
+    /// Resources by locale, in the order they were added.
+    resources: Vec<(LanguageIdentifier, Vec<Arc<FluentResource>>)>,
+    requested: Vec<LanguageIdentifier>,
+    default: LanguageIdentifier,
+    /// One bundle per negotiated locale, best first.
+    bundles: Vec<Bundle>,
+}
+
+impl State {
+    /// Negotiate the locales and rebuild the fallback chain of bundles.
+    fn rebuild(&mut self) {
+        let available: Vec<&LanguageIdentifier> =
+            self.resources.iter().map(|(locale, _)| locale).collect();
+        let default = &self.default;
+        let locales: Vec<LanguageIdentifier> = negotiate_languages(
+            &self.requested,
+            &available,
+            Some(&default),
+            NegotiationStrategy::Filtering,
+        )
+        .into_iter()
+        .map(|locale| (*locale).clone())
+        .collect();
+
+        self.bundles = locales
+            .into_iter()
+            .map(|locale| {
+                let mut bundle = Bundle::new_concurrent(vec![locale.clone()]);
+                // Bidi isolation marks would throw off width calculations
+                bundle.set_use_isolating(false);
+                for (_, resources) in self.resources.iter().filter(|(l, _)| *l == locale) {
+                    for resource in resources {
+                        bundle.add_resource_overriding(resource.clone());
+                    }
+                }
+                bundle
+            })
+            .collect();
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        self.bundles.iter().find_map(|bundle| {
+            let pattern = bundle.get_message(id)?.value()?;
+            let mut errors = Vec::new();
+            Some(
+                bundle
+                    .format_pattern(pattern, args, &mut errors)
+                    .into_owned(),
+            )
+        })
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    notify: Notify,
+}
+
+/// Shared Fluent resources and the negotiated locale.
+///
+/// Cloning is cheap and every clone shares the same resources, so tabs and
+/// tasks can keep their own handle.
+#[derive(Clone)]
+pub struct Localizer {
+    shared: Arc<Shared>,
+}
+
+impl Localizer {
+    /// Create a localizer with the framework's strings, requesting the
+    /// locales from the environment.
+    pub fn new() -> Self {
+        let default: LanguageIdentifier = DEFAULT_LOCALE.parse().expect("valid default locale");
+        let localizer = Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    resources: Vec::new(),
+                    requested: env_locales(),
+                    default,
+                    bundles: Vec::new(),
+                }),
+                notify: Notify::new(),
+            }),
+        };
+        localizer
+            .add_resource(DEFAULT_LOCALE, FRAMEWORK_FTL)
+            .expect("framework resource parses");
+        localizer
+    }
+
+    /// Add a Fluent resource for `locale`.
+    ///
+    /// Messages override earlier ones with the same id. On syntax errors
+    /// the valid messages are still added and the errors are returned.
+    pub fn add_resource(&self, locale: &str, source: &str) -> Result<(), L10nError> {
+        let langid = parse_locale(locale)?;
+        let (resource, errors) = match FluentResource::try_new(source.to_string()) {
+            Ok(resource) => (resource, Vec::new()),
+            Err((resource, errors)) => (resource, errors),
+        };
+        {
+            let mut state = self.lock();
+            let resource = Arc::new(resource);
+            match state.resources.iter_mut().find(|(l, _)| *l == langid) {
+                Some((_, resources)) => resources.push(resource),
+                None => state.resources.push((langid, vec![resource])),
+            }
+            state.rebuild();
+        }
+        self.shared.notify.notify_one();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(L10nError::Parse {
+                locale: locale.to_string(),
+                errors: errors.iter().map(ToString::to_string).collect(),
+            })
+        }
+    }
+
+    /// Add every `.ftl` file in the locale directories under `dir`, e.g.
+    /// `dir/en-US/app.ftl` and `dir/de/app.ftl`.
+    ///
+    /// Stops at the first error; resources added before it are kept.
+    pub fn load_dir(&self, dir: impl AsRef<Path>) -> Result<(), L10nError> {
+        let mut locales: Vec<_> = fs::read_dir(dir)?.collect::<Result<_, _>>()?;
+        locales.sort_by_key(|entry| entry.file_name());
+        for locale in locales {
+            if !locale.file_type()?.is_dir() {
+                continue;
+            }
+            let name = locale.file_name().to_string_lossy().into_owned();
+            let mut files: Vec<_> = fs::read_dir(locale.path())?.collect::<Result<_, _>>()?;
+            files.sort_by_key(|entry| entry.file_name());
+            for file in files {
+                let path = file.path();
+                if path.extension().is_some_and(|ext| ext == "ftl") {
+                    self.add_resource(&name, &fs::read_to_string(path)?)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Switch to `locale`, falling back to `en-US` for missing messages.
+    pub fn set_locale(&self, locale: &str) -> Result<(), L10nError> {
+        self.set_locales(&[locale])
+    }
+
+    /// Switch to the first available of `locales`, in order of preference.
+    pub fn set_locales(&self, locales: &[&str]) -> Result<(), L10nError> {
+        let requested = locales
+            .iter()
+            .map(|locale| parse_locale(locale))
+            .collect::<Result<Vec<_>, _>>()?;
+        {
+            let mut state = self.lock();
+            state.requested = requested;
+            state.rebuild();
+        }
+        self.shared.notify.notify_one();
+        Ok(())
+    }
+
+    /// The locale messages are looked up in first.
+    pub fn locale(&self) -> String {
+        let state = self.lock();
+        state
+            .bundles
+            .first()
+            .and_then(|bundle| bundle.locales.first())
+            .unwrap_or(&state.default)
+            .to_string()
+    }
+
+    /// Locales that have resources, in the order they were added.
+    pub fn available_locales(&self) -> Vec<String> {
+        self.lock()
+            .resources
+            .iter()
+            .map(|(locale, _)| locale.to_string())
+            .collect()
+    }
+
+    /// Check if a message with a value exists in any negotiated locale.
+    pub fn has_message(&self, id: &str) -> bool {
+        self.lock().format(id, None).is_some()
+    }
+
+    /// Look up a message without arguments.
+    ///
+    /// Returns the id itself if no negotiated locale has the message, so
+    /// missing translations stay visible instead of breaking the UI.
+    pub fn message(&self, id: &str) -> String {
+        self.lock()
+            .format(id, None)
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Look up a message with arguments.
+    ///
+    /// Returns the id itself if no negotiated locale has the message.
+    pub fn format(&self, id: &str, args: &FluentArgs) -> String {
+        self.lock()
+            .format(id, Some(args))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Wait until resources are added or the locale is switched.
+    pub async fn changed(&self) {
+        self.shared.notify.notified().await;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Localizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localizer")
+            .field("locale", &self.locale())
+            .field("available", &self.available_locales())
+            .finish()
+    }
+}
+
+fn parse_locale(locale: &str) -> Result<LanguageIdentifier, L10nError> {
+    locale
+        .parse()
+        .map_err(|_| L10nError::InvalidLocale(locale.to_string()))
+}
+
+/// Requested locales from the POSIX locale environment variables.
+fn env_locales() -> Vec<LanguageIdentifier> {
+    // LANGUAGE is a colon-separated preference list and wins over the rest
+    let value = ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty());
+    value.as_deref().map(posix_locales).unwrap_or_default()
+}
+
+/// Convert POSIX locale names such as `de_DE.UTF-8@euro` to language
+/// identifiers, skipping `C` and `POSIX`.
+fn posix_locales(value: &str) -> Vec<LanguageIdentifier> {
+    value
+        .split(':')
+        .filter_map(|locale| {
+            let locale = locale.split(['.', '@']).next()?;
+            if locale.is_empty() || locale == "C" || locale == "POSIX" {
+                return None;
+            }
+            locale.replace('_', "-").parse().ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiation_and_fallback() {
+        let l10n = Localizer::new();
+        l10n.add_resource("en-US", "hello = Hello, { $name }!\nbye = Bye")
+            .unwrap();
+        l10n.add_resource("de", "hello = Hallo, { $name }!")
+            .unwrap();
+
+        l10n.set_locale("de-AT").unwrap();
+        assert_eq!(l10n.locale(), "de");
+        let mut args = FluentArgs::new();
+        args.set("name", "Ada");
+        assert_eq!(l10n.format("hello", &args), "Hallo, Ada!");
+        // Missing in German, so English is used
+        assert_eq!(l10n.message("bye"), "Bye");
+        assert_eq!(l10n.message("fwk-palette-title"), "Commands");
+        assert_eq!(l10n.message("missing"), "missing");
+
+        l10n.set_locale("fr").unwrap();
+        assert_eq!(l10n.locale(), "en-US");
+        assert!(matches!(
+            l10n.add_resource("de", "broken ="),
+            Err(L10nError::Parse { .. })
+        ));
+
+        assert_eq!(
+            posix_locales("de_DE.UTF-8@euro:C:pl"),
+            vec![
+                "de-DE".parse::<LanguageIdentifier>().unwrap(),
+                "pl".parse().unwrap()
+            ]
+        );
+    }
+}
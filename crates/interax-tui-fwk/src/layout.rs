@@ -0,0 +1,1028 @@
+//! Declarative layouts with resizable split panes.
+//!
+//! A `LayoutTree` describes the screen as nested rows, columns and splits.
+//! Rows and columns use fixed ratatui constraints; splits divide their area
+//! by weights that the user can change with the keyboard or by dragging the
+//! border between two panes. Panes and splits have ids, so they can be
+//! collapsed, maximized and looked up after the layout is computed.
+//!
+//! `LayoutView` owns a tree plus the components mounted into its panes and
+//! is itself a `Component`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{LayoutNode, LayoutTree, LayoutView};
+//!
+//! let tree = LayoutTree::new(LayoutNode::column([
+//!     LayoutNode::pane("header").length(3),
+//!     LayoutNode::hsplit("main", [
+//!         LayoutNode::pane("endpoints"),
+//!         LayoutNode::vsplit("detail", [
+//!             LayoutNode::pane("attributes"),
+//!             LayoutNode::pane("events"),
+//!         ]),
+//!     ])
+//!     .weights([1.0, 2.0]),
+//! ]));
+//!
+//! let view = LayoutView::new(tree)
+//!     .mount("header", Header::new())
+//!     .mount("endpoints", SelectableTable::new("endpoints", columns))
+//!     .mount("attributes", attributes_view)
+//!     .mount("events", events_view);
+//! ```
+//!
+//! With a pane focused, Alt+arrow keys move the nearest splitter and Alt+M
+//! toggles maximizing the pane. Splitters can also be dragged with the mouse.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
+
+use crate::component::{BoxedComponent, Component};
+use crate::context::{AppContext, DrawContext};
+use crate::event::{Event, KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+use crate::focus::EventResult;
+use crate::l10n::Localizer;
+use crate::palette::Command;
+#[cfg(feature = "persist")]
+use crate::persist::{Persist, Session};
+
+/// Smallest share of a pair of panes a splitter can leave to either pane.
+const MIN_SHARE: f32 = 0.05;
+
+/// Fraction of a split moved by one resize key press.
+const KEY_STEP: f32 = 0.05;
+
+#[derive(Debug, Clone)]
+enum NodeKind {
+    Pane(String),
+    Stack {
+        direction: Direction,
+        children: Vec<LayoutNode>,
+    },
+    Split {
+        id: String,
+        direction: Direction,
+        children: Vec<LayoutNode>,
+        weights: Vec<f32>,
+    },
+}
+
+/// A node of a `LayoutTree`.
+///
+/// The constraint set with `length`, `percentage`, `min`, `max` or `fill`
+/// sizes the node inside a row or column. Inside a split, nodes are sized
+/// by the split's weights instead.
+#[derive(Debug, Clone)]
+pub struct LayoutNode {
+    kind: NodeKind,
+    constraint: Constraint,
+}
+
+impl LayoutNode {
+    fn new(kind: NodeKind) -> Self {
+        Self {
+            kind,
+            constraint: Constraint::Fill(1),
+        }
+    }
+
+    /// A leaf that a component can be mounted into.
+    pub fn pane(id: impl Into<String>) -> Self {
+        Self::new(NodeKind::Pane(id.into()))
+    }
+
+    /// Children laid out side by side with fixed constraints.
+    pub fn row(children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::new(NodeKind::Stack {
+            direction: Direction::Horizontal,
+            children: children.into_iter().collect(),
+        })
+    }
+
+    /// Children stacked top to bottom with fixed constraints.
+    pub fn column(children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::new(NodeKind::Stack {
+            direction: Direction::Vertical,
+            children: children.into_iter().collect(),
+        })
+    }
+
+    /// Children side by side, separated by resizable splitters.
+    pub fn hsplit(id: impl Into<String>, children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::split(id.into(), Direction::Horizontal, children)
+    }
+
+    /// Children stacked top to bottom, separated by resizable splitters.
+    pub fn vsplit(id: impl Into<String>, children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::split(id.into(), Direction::Vertical, children)
+    }
+
+    fn split(
+        id: String,
+        direction: Direction,
+        children: impl IntoIterator<Item = LayoutNode>,
+    ) -> Self {
+        let children: Vec<LayoutNode> = children.into_iter().collect();
+        let weights = vec![1.0; children.len()];
+        Self::new(NodeKind::Split {
+            id,
+            direction,
+            children,
+            weights,
+        })
+    }
+
+    /// Set the initial weights of a split's children (ignored for other
+    /// nodes or when the count does not match).
+    pub fn weights(mut self, weights: impl IntoIterator<Item = f32>) -> Self {
+        if let NodeKind::Split {
+            children,
+            weights: current,
+            ..
+        } = &mut self.kind
+        {
+            let weights: Vec<f32> = weights.into_iter().map(|w| w.max(0.0)).collect();
+            if weights.len() == children.len() {
+                *current = weights;
+            }
+        }
+        self
+    }
+
+    /// Use a fixed size inside a row or column.
+    pub fn length(self, length: u16) -> Self {
+        self.constraint(Constraint::Length(length))
+    }
+
+    /// Use a percentage of the parent inside a row or column.
+    pub fn percentage(self, percentage: u16) -> Self {
+        self.constraint(Constraint::Percentage(percentage))
+    }
+
+    /// Use at least `min` cells inside a row or column.
+    pub fn min(self, min: u16) -> Self {
+        self.constraint(Constraint::Min(min))
+    }
+
+    /// Use at most `max` cells inside a row or column.
+    pub fn max(self, max: u16) -> Self {
+        self.constraint(Constraint::Max(max))
+    }
+
+    /// Share the remaining space with `weight` inside a row or column
+    /// (the default is `fill(1)`).
+    pub fn fill(self, weight: u16) -> Self {
+        self.constraint(Constraint::Fill(weight))
+    }
+
+    /// Use an arbitrary ratatui constraint inside a row or column.
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.constraint = constraint;
+        self
+    }
+
+    /// The id of a pane or split.
+    pub fn id(&self) -> Option<&str> {
+        match &self.kind {
+            NodeKind::Pane(id) | NodeKind::Split { id, .. } => Some(id),
+            NodeKind::Stack { .. } => None,
+        }
+    }
+
+    fn children(&self) -> &[LayoutNode] {
+        match &self.kind {
+            NodeKind::Pane(_) => &[],
+            NodeKind::Stack { children, .. } | NodeKind::Split { children, .. } => children,
+        }
+    }
+
+    fn find(&self, id: &str) -> Option<&LayoutNode> {
+        if self.id() == Some(id) {
+            return Some(self);
+        }
+        self.children().iter().find_map(|child| child.find(id))
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.find(id).is_some()
+    }
+
+    fn weights_mut(&mut self, split_id: &str) -> Option<&mut Vec<f32>> {
+        match &mut self.kind {
+            NodeKind::Split { id, weights, .. } if id == split_id => Some(weights),
+            NodeKind::Pane(_) => None,
+            NodeKind::Stack { children, .. } | NodeKind::Split { children, .. } => children
+                .iter_mut()
+                .find_map(|child| child.weights_mut(split_id)),
+        }
+    }
+
+    fn for_each_split<'a>(&'a self, f: &mut impl FnMut(&'a str, &'a [f32])) {
+        if let NodeKind::Split { id, weights, .. } = &self.kind {
+            f(id, weights);
+        }
+        for child in self.children() {
+            child.for_each_split(f);
+        }
+    }
+
+    /// Splits on the way to `id` with the index of the child containing it,
+    /// outermost first.
+    fn split_path(&self, id: &str, path: &mut Vec<SplitStep>) -> bool {
+        if self.id() == Some(id) {
+            return true;
+        }
+        for (index, child) in self.children().iter().enumerate() {
+            if let NodeKind::Split {
+                id: split_id,
+                direction,
+                children,
+                ..
+            } = &self.kind
+            {
+                path.push(SplitStep {
+                    split: split_id.clone(),
+                    direction: *direction,
+                    child: index,
+                    siblings: children
+                        .iter()
+                        .map(|c| c.id().map(str::to_string))
+                        .collect(),
+                });
+            }
+            if child.split_path(id, path) {
+                return true;
+            }
+            if matches!(self.kind, NodeKind::Split { .. }) {
+                path.pop();
+            }
+        }
+        false
+    }
+}
+
+struct SplitStep {
+    split: String,
+    direction: Direction,
+    child: usize,
+    siblings: Vec<Option<String>>,
+}
+
+/// The border between two panes of a split, recorded while computing.
+#[derive(Debug, Clone)]
+struct Splitter {
+    split: String,
+    direction: Direction,
+    left: usize,
+    right: usize,
+    /// First cell of the left pane along the split axis.
+    start: u16,
+    /// First cell of the right pane along the split axis.
+    pos: u16,
+    /// One past the last cell of the right pane along the split axis.
+    end: u16,
+    /// Extent across the split axis.
+    cross: (u16, u16),
+}
+
+impl Splitter {
+    /// Check if the cell at `column`, `row` is on either side of the border.
+    fn hit(&self, column: u16, row: u16) -> bool {
+        let (along, across) = match self.direction {
+            Direction::Horizontal => (column, row),
+            Direction::Vertical => (row, column),
+        };
+        (along + 1 == self.pos || along == self.pos)
+            && across >= self.cross.0
+            && across < self.cross.1
+    }
+}
+
+/// The areas computed for each pane and split id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutAreas {
+    areas: HashMap<String, Rect>,
+}
+
+impl LayoutAreas {
+    /// The area of a pane or split, if it is visible.
+    pub fn get(&self, id: &str) -> Option<Rect> {
+        self.areas.get(id).copied()
+    }
+
+    /// Iterate over the visible ids and their areas.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Rect)> {
+        self.areas.iter().map(|(id, rect)| (id.as_str(), *rect))
+    }
+}
+
+/// Split weights, collapsed panes and the maximized pane of a
+/// `LayoutTree`, for saving between runs.
+///
+/// The text form (via `Display` and `FromStr`) has one entry per line:
+///
+/// ```text
+/// split main 1 2
+/// collapsed events
+/// maximized endpoints
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayoutState {
+    /// Weights of each split, by split id.
+    pub weights: BTreeMap<String, Vec<f32>>,
+    /// Collapsed pane and split ids.
+    pub collapsed: BTreeSet<String>,
+    /// The maximized pane or split id.
+    pub maximized: Option<String>,
+}
+
+impl fmt::Display for LayoutState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (id, weights) in &self.weights {
+            write!(f, "split {}", id)?;
+            for weight in weights {
+                write!(f, " {}", weight)?;
+            }
+            writeln!(f)?;
+        }
+        for id in &self.collapsed {
+            writeln!(f, "collapsed {}", id)?;
+        }
+        if let Some(id) = &self.maximized {
+            writeln!(f, "maximized {}", id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when parsing a `LayoutState` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLayoutStateError {
+    /// 1-based line number of the invalid entry.
+    pub line: usize,
+}
+
+impl fmt::Display for ParseLayoutStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid layout state on line {}", self.line)
+    }
+}
+
+impl std::error::Error for ParseLayoutStateError {}
+
+impl FromStr for LayoutState {
+    type Err = ParseLayoutStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut state = LayoutState::default();
+        for (index, line) in s.lines().enumerate() {
+            let error = ParseLayoutStateError { line: index + 1 };
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (None, _) => continue,
+                (Some("split"), Some(id)) => {
+                    let weights = words
+                        .map(|w| w.parse::<f32>().map_err(|_| error.clone()))
+                        .collect::<Result<Vec<f32>, _>>()?;
+                    state.weights.insert(id.to_string(), weights);
+                }
+                (Some("collapsed"), Some(id)) => {
+                    state.collapsed.insert(id.to_string());
+                }
+                (Some("maximized"), Some(id)) => state.maximized = Some(id.to_string()),
+                _ => return Err(error),
+            }
+        }
+        Ok(state)
+    }
+}
+
+/// A declarative layout of panes and resizable splits.
+#[derive(Debug)]
+pub struct LayoutTree {
+    root: LayoutNode,
+    collapsed: BTreeSet<String>,
+    maximized: Option<String>,
+    /// Splitters of the last computed layout, for mouse hit testing.
+    splitters: RefCell<Vec<Splitter>>,
+    drag: Option<Splitter>,
+}
+
+impl LayoutTree {
+    /// Create a tree from its root node.
+    pub fn new(root: LayoutNode) -> Self {
+        Self {
+            root,
+            collapsed: BTreeSet::new(),
+            maximized: None,
+            splitters: RefCell::new(Vec::new()),
+            drag: None,
+        }
+    }
+
+    /// Compute the area of every visible pane and split.
+    ///
+    /// The splitter positions are remembered for mouse dragging, so call
+    /// this while drawing.
+    pub fn compute(&self, area: Rect) -> LayoutAreas {
+        let mut areas = LayoutAreas::default();
+        let mut splitters = Vec::new();
+        let root = self
+            .maximized
+            .as_deref()
+            .and_then(|id| self.root.find(id))
+            .unwrap_or(&self.root);
+        self.layout(root, area, &mut areas, &mut splitters);
+        *self.splitters.borrow_mut() = splitters;
+        areas
+    }
+
+    fn layout(
+        &self,
+        node: &LayoutNode,
+        area: Rect,
+        areas: &mut LayoutAreas,
+        splitters: &mut Vec<Splitter>,
+    ) {
+        match &node.kind {
+            NodeKind::Pane(id) => {
+                areas.areas.insert(id.clone(), area);
+            }
+            NodeKind::Stack {
+                direction,
+                children,
+            } => {
+                let visible: Vec<&LayoutNode> =
+                    children.iter().filter(|c| !self.is_hidden(c)).collect();
+                let rects = Layout::default()
+                    .direction(*direction)
+                    .constraints(visible.iter().map(|c| c.constraint))
+                    .split(area);
+                for (child, rect) in visible.into_iter().zip(rects.iter()) {
+                    self.layout(child, *rect, areas, splitters);
+                }
+            }
+            NodeKind::Split {
+                id,
+                direction,
+                children,
+                weights,
+            } => {
+                areas.areas.insert(id.clone(), area);
+                let visible: Vec<usize> = (0..children.len())
+                    .filter(|&i| !self.is_hidden(&children[i]))
+                    .collect();
+                let total: f32 = visible.iter().map(|&i| weights[i]).sum();
+                let (start, length, cross) = match direction {
+                    Direction::Horizontal => (area.x, area.width, (area.y, area.bottom())),
+                    Direction::Vertical => (area.y, area.height, (area.x, area.right())),
+                };
+
+                let mut cumulative = 0.0;
+                let mut edge = start;
+                let mut previous: Option<(usize, u16)> = None;
+                let mut last_splitter: Option<usize> = None;
+                for (n, &index) in visible.iter().enumerate() {
+                    cumulative += weights[index];
+                    let next = if n + 1 == visible.len() || total <= 0.0 {
+                        start + length
+                    } else {
+                        start + (length as f32 * cumulative / total).round() as u16
+                    };
+                    let rect = match direction {
+                        Direction::Horizontal => Rect {
+                            x: edge,
+                            width: next - edge,
+                            ..area
+                        },
+                        Direction::Vertical => Rect {
+                            y: edge,
+                            height: next - edge,
+                            ..area
+                        },
+                    };
+                    if let Some((left, left_start)) = previous {
+                        // The previous splitter's right pane ends where this one starts
+                        if let Some(last) = last_splitter {
+                            splitters[last].end = edge;
+                        }
+                        last_splitter = Some(splitters.len());
+                        splitters.push(Splitter {
+                            split: id.clone(),
+                            direction: *direction,
+                            left,
+                            right: index,
+                            start: left_start,
+                            pos: edge,
+                            end: start + length,
+                            cross,
+                        });
+                    }
+                    self.layout(&children[index], rect, areas, splitters);
+                    previous = Some((index, edge));
+                    edge = next;
+                }
+            }
+        }
+    }
+
+    fn is_hidden(&self, node: &LayoutNode) -> bool {
+        node.id().is_some_and(|id| self.collapsed.contains(id))
+    }
+
+    /// The weights of a split's children.
+    pub fn weights(&self, split_id: &str) -> Option<Vec<f32>> {
+        match &self.root.find(split_id)?.kind {
+            NodeKind::Split { weights, .. } => Some(weights.clone()),
+            _ => None,
+        }
+    }
+
+    /// Replace the weights of a split's children.
+    ///
+    /// Returns `false` if there is no such split or the count differs.
+    pub fn set_weights(&mut self, split_id: &str, weights: &[f32]) -> bool {
+        match self.root.weights_mut(split_id) {
+            Some(current) if current.len() == weights.len() => {
+                current.copy_from_slice(weights);
+                current.iter_mut().for_each(|w| *w = w.max(0.0));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move the splitter between children `boundary` and `boundary + 1` of
+    /// a split by `delta`, a fraction of the pair's combined size (positive
+    /// grows the first child).
+    pub fn resize(&mut self, split_id: &str, boundary: usize, delta: f32) -> bool {
+        self.shift(split_id, boundary, boundary + 1, delta)
+    }
+
+    fn shift(&mut self, split_id: &str, left: usize, right: usize, delta: f32) -> bool {
+        let Some(weights) = self.root.weights_mut(split_id) else {
+            return false;
+        };
+        if right >= weights.len() || left >= right {
+            return false;
+        }
+        let combined = weights[left] + weights[right];
+        if combined <= 0.0 {
+            return false;
+        }
+        let share = (weights[left] / combined + delta).clamp(MIN_SHARE, 1.0 - MIN_SHARE);
+        weights[left] = combined * share;
+        weights[right] = combined - weights[left];
+        true
+    }
+
+    /// Hide a pane or split; its space goes to its siblings.
+    pub fn collapse(&mut self, id: &str) {
+        self.collapsed.insert(id.to_string());
+    }
+
+    /// Show a collapsed pane or split again.
+    pub fn expand(&mut self, id: &str) {
+        self.collapsed.remove(id);
+    }
+
+    /// Collapse or expand a pane or split.
+    pub fn toggle_collapsed(&mut self, id: &str) {
+        if !self.collapsed.remove(id) {
+            self.collapsed.insert(id.to_string());
+        }
+    }
+
+    /// Check if a pane or split is collapsed.
+    pub fn is_collapsed(&self, id: &str) -> bool {
+        self.collapsed.contains(id)
+    }
+
+    /// Give the whole area to a pane or split.
+    ///
+    /// Returns `false` if the id is not in the tree.
+    pub fn maximize(&mut self, id: &str) -> bool {
+        if !self.root.contains(id) {
+            return false;
+        }
+        self.maximized = Some(id.to_string());
+        true
+    }
+
+    /// Undo `maximize`.
+    pub fn restore(&mut self) {
+        self.maximized = None;
+    }
+
+    /// Maximize a pane or split, or restore if it is already maximized.
+    pub fn toggle_maximized(&mut self, id: &str) {
+        if self.maximized.as_deref() == Some(id) {
+            self.restore();
+        } else {
+            self.maximize(id);
+        }
+    }
+
+    /// The maximized pane or split.
+    pub fn maximized(&self) -> Option<&str> {
+        self.maximized.as_deref()
+    }
+
+    /// Capture the weights, collapsed panes and maximized pane.
+    pub fn state(&self) -> LayoutState {
+        let mut weights = BTreeMap::new();
+        self.root.for_each_split(&mut |id, w| {
+            weights.insert(id.to_string(), w.to_vec());
+        });
+        LayoutState {
+            weights,
+            collapsed: self.collapsed.clone(),
+            maximized: self.maximized.clone(),
+        }
+    }
+
+    /// Apply a saved state.
+    ///
+    /// Entries for splits or panes that no longer exist, or whose child
+    /// count changed, are ignored.
+    pub fn apply_state(&mut self, state: &LayoutState) {
+        for (id, weights) in &state.weights {
+            self.set_weights(id, weights);
+        }
+        self.collapsed = state
+            .collapsed
+            .iter()
+            .filter(|id| self.root.contains(id))
+            .cloned()
+            .collect();
+        self.maximized = state.maximized.clone().filter(|id| self.root.contains(id));
+    }
+
+    /// Handle splitter keys and mouse drags.
+    ///
+    /// `focused` is the id of the pane the keys apply to (usually the
+    /// focused component's id). Alt+arrow keys move the nearest splitter
+    /// next to it and Alt+M toggles maximizing it.
+    pub fn handle_input(&mut self, event: &Event, focused: Option<&str>) -> EventResult {
+        match event {
+            Event::Key(key) if key.modifiers.contains(KeyModifiers::ALT) => {
+                let Some(focused) = focused else {
+                    return EventResult::Unhandled;
+                };
+                let (direction, delta) = match key.code {
+                    KeyCode::Left => (Direction::Horizontal, -KEY_STEP),
+                    KeyCode::Right => (Direction::Horizontal, KEY_STEP),
+                    KeyCode::Up => (Direction::Vertical, -KEY_STEP),
+                    KeyCode::Down => (Direction::Vertical, KEY_STEP),
+                    KeyCode::Char('m') if self.root.contains(focused) => {
+                        self.toggle_maximized(focused);
+                        return EventResult::Handled;
+                    }
+                    _ => return EventResult::Unhandled,
+                };
+                if self.resize_around(focused, direction, delta) {
+                    EventResult::Handled
+                } else {
+                    EventResult::Unhandled
+                }
+            }
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    let hit = self
+                        .splitters
+                        .borrow()
+                        .iter()
+                        .find(|s| s.hit(mouse.column, mouse.row))
+                        .cloned();
+                    match hit {
+                        Some(splitter) => {
+                            self.drag = Some(splitter);
+                            EventResult::Handled
+                        }
+                        None => EventResult::Unhandled,
+                    }
+                }
+                MouseEventKind::Drag(MouseButton::Left) => {
+                    let Some(drag) = self.drag.clone() else {
+                        return EventResult::Unhandled;
+                    };
+                    let along = match drag.direction {
+                        Direction::Horizontal => mouse.column,
+                        Direction::Vertical => mouse.row,
+                    };
+                    let span = drag.end.saturating_sub(drag.start).max(1) as f32;
+                    let share = (along.saturating_sub(drag.start) as f32 + 1.0) / span;
+                    if let Some(weights) = self.root.weights_mut(&drag.split) {
+                        let combined = weights[drag.left] + weights[drag.right];
+                        let share = share.clamp(MIN_SHARE, 1.0 - MIN_SHARE);
+                        weights[drag.left] = combined * share;
+                        weights[drag.right] = combined - weights[drag.left];
+                    }
+                    EventResult::Handled
+                }
+                MouseEventKind::Up(MouseButton::Left) if self.drag.is_some() => {
+                    self.drag = None;
+                    EventResult::Handled
+                }
+                _ => EventResult::Unhandled,
+            },
+            _ => EventResult::Unhandled,
+        }
+    }
+
+    /// Move the splitter next to `id` in the innermost split along
+    /// `direction` containing it.
+    fn resize_around(&mut self, id: &str, direction: Direction, delta: f32) -> bool {
+        let mut path = Vec::new();
+        if !self.root.split_path(id, &mut path) {
+            return false;
+        }
+        let Some(step) = path.iter().rev().find(|step| step.direction == direction) else {
+            return false;
+        };
+        let visible = |index: &usize| {
+            step.siblings[*index]
+                .as_deref()
+                .is_none_or(|id| !self.collapsed.contains(id))
+        };
+        // Prefer the splitter after the pane, else the one before it
+        let after = (step.child + 1..step.siblings.len()).find(visible);
+        let before = (0..step.child).rev().find(visible);
+        let (left, right) = match (after, before) {
+            (Some(after), _) => (step.child, after),
+            (None, Some(before)) => (before, step.child),
+            (None, None) => return false,
+        };
+        let split = step.split.clone();
+        self.shift(&split, left, right, delta)
+    }
+}
+
+/// A `LayoutTree` with components mounted into its panes.
+///
+/// Events go to the splitters first, then to each mounted component in
+/// mount order until one handles them. Components are expected to check
+/// their own focus, as the built-in widgets do.
+pub struct LayoutView {
+    tree: LayoutTree,
+    panes: Vec<(String, BoxedComponent)>,
+}
+
+impl LayoutView {
+    /// Create a view with no mounted components.
+    pub fn new(tree: LayoutTree) -> Self {
+        Self {
+            tree,
+            panes: Vec::new(),
+        }
+    }
+
+    /// Mount a component into the pane `id`, returning the view (builder
+    /// style).
+    pub fn mount<C: Component + 'static>(mut self, id: &str, component: C) -> Self {
+        self.mount_boxed(id, Box::new(component));
+        self
+    }
+
+    /// Mount a boxed component into the pane `id`, replacing any
+    /// component already mounted there.
+    pub fn mount_boxed(&mut self, id: &str, component: BoxedComponent) {
+        if let Some(pane) = self.panes.iter_mut().find(|(pane, _)| pane == id) {
+            pane.1 = component;
+        } else {
+            self.panes.push((id.to_string(), component));
+        }
+    }
+
+    /// Remove the component mounted into the pane `id`.
+    pub fn unmount(&mut self, id: &str) -> Option<BoxedComponent> {
+        let index = self.panes.iter().position(|(pane, _)| pane == id)?;
+        Some(self.panes.remove(index).1)
+    }
+
+    /// The component mounted into the pane `id`.
+    pub fn get(&self, id: &str) -> Option<&dyn Component> {
+        self.panes
+            .iter()
+            .find(|(pane, _)| pane == id)
+            .map(|(_, component)| component.as_ref())
+    }
+
+    /// The component mounted into the pane `id`, mutably.
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut BoxedComponent> {
+        self.panes
+            .iter_mut()
+            .find(|(pane, _)| pane == id)
+            .map(|(_, component)| component)
+    }
+
+    /// Access the layout tree.
+    pub fn tree(&self) -> &LayoutTree {
+        &self.tree
+    }
+
+    /// Access the layout tree mutably (to collapse, maximize or restore
+    /// saved ratios).
+    pub fn tree_mut(&mut self) -> &mut LayoutTree {
+        &mut self.tree
+    }
+
+    /// The pane containing the component with focus id `focused`.
+    fn focused_pane(&self, focused: Option<&str>) -> Option<String> {
+        let focused = focused?;
+        self.panes
+            .iter()
+            .find(|(pane, component)| pane == focused || component.focus_id() == Some(focused))
+            .map(|(pane, _)| pane.clone())
+    }
+}
+
+/// Saves the `LayoutState` in its text form.
+#[cfg(feature = "persist")]
+impl Persist for LayoutTree {
+    type State = String;
+
+    fn save(&self) -> String {
+        self.state().to_string()
+    }
+
+    fn restore(&mut self, state: String) {
+        if let Ok(state) = state.parse() {
+            self.apply_state(&state);
+        }
+    }
+}
+
+impl Component for LayoutView {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let areas = self.tree.compute(area);
+        for (id, component) in &self.panes {
+            if let Some(rect) = areas.get(id) {
+                component.draw(frame, rect, ctx);
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        let pane = self.focused_pane(ctx.focus().focused_id());
+        let result = self.tree.handle_input(event, pane.as_deref());
+        if !result.should_propagate() {
+            return result;
+        }
+        for (_, component) in &mut self.panes {
+            let result = component.handle_event(event, ctx);
+            if !result.should_propagate() {
+                return result;
+            }
+        }
+        EventResult::Unhandled
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Component> {
+        self.panes
+            .iter_mut()
+            .map(|(_, component)| component.as_mut() as &mut dyn Component)
+            .collect()
+    }
+
+    fn on_locale_change(&mut self, l10n: &Localizer) {
+        for (_, component) in &mut self.panes {
+            component.on_locale_change(l10n);
+        }
+    }
+
+    #[cfg(feature = "persist")]
+    fn save_state(&self, session: &mut Session) {
+        for (_, component) in &self.panes {
+            component.save_state(session);
+        }
+    }
+
+    #[cfg(feature = "persist")]
+    fn restore_state(&mut self, session: &Session) {
+        for (_, component) in &mut self.panes {
+            component.restore_state(session);
+        }
+    }
+
+    fn focus_children(&self) -> Vec<&str> {
+        self.panes
+            .iter()
+            .filter_map(|(_, component)| component.focus_id())
+            .collect()
+    }
+
+    fn commands(&self) -> Vec<Command> {
+        self.panes
+            .iter()
+            .flat_map(|(_, component)| component.commands())
+            .collect()
+    }
+
+    fn on_command(&mut self, id: &str, ctx: &mut AppContext) -> EventResult {
+        for (_, component) in &mut self.panes {
+            let result = component.on_command(id, ctx);
+            if !result.should_propagate() {
+                return result;
+            }
+        }
+        EventResult::Unhandled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    fn tree() -> LayoutTree {
+        LayoutTree::new(LayoutNode::column([
+            LayoutNode::pane("header").length(2),
+            LayoutNode::hsplit(
+                "main",
+                [
+                    LayoutNode::pane("list"),
+                    LayoutNode::vsplit(
+                        "detail",
+                        [LayoutNode::pane("attributes"), LayoutNode::pane("events")],
+                    ),
+                ],
+            )
+            .weights([1.0, 3.0]),
+        ]))
+    }
+
+    #[test]
+    fn test_compute_collapse_and_maximize() {
+        let mut tree = tree();
+        let area = Rect::new(0, 0, 80, 22);
+        let areas = tree.compute(area);
+        assert_eq!(areas.get("header"), Some(Rect::new(0, 0, 80, 2)));
+        assert_eq!(areas.get("list"), Some(Rect::new(0, 2, 20, 20)));
+        assert_eq!(areas.get("attributes"), Some(Rect::new(20, 2, 60, 10)));
+        assert_eq!(areas.get("events"), Some(Rect::new(20, 12, 60, 10)));
+
+        tree.collapse("list");
+        let areas = tree.compute(area);
+        assert_eq!(areas.get("list"), None);
+        assert_eq!(areas.get("attributes"), Some(Rect::new(0, 2, 80, 10)));
+
+        tree.expand("list");
+        assert!(tree.maximize("events"));
+        let areas = tree.compute(area);
+        assert_eq!(areas.get("events"), Some(area));
+        assert_eq!(areas.get("header"), None);
+    }
+
+    #[test]
+    fn test_resize_by_key_and_mouse() {
+        let mut tree = tree();
+        let area = Rect::new(0, 0, 80, 22);
+        tree.compute(area);
+
+        let alt_right = Event::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::ALT));
+        assert!(tree.handle_input(&alt_right, Some("list")).is_handled());
+        assert_eq!(tree.compute(area).get("list").map(|r| r.width), Some(24));
+
+        // Dragging the border between list and detail to column 39
+        let mouse = |kind, column| {
+            Event::Mouse(crossterm::event::MouseEvent {
+                kind,
+                column,
+                row: 10,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        assert!(tree
+            .handle_input(&mouse(MouseEventKind::Down(MouseButton::Left), 23), None)
+            .is_handled());
+        tree.handle_input(&mouse(MouseEventKind::Drag(MouseButton::Left), 39), None);
+        tree.handle_input(&mouse(MouseEventKind::Up(MouseButton::Left), 39), None);
+        assert_eq!(tree.compute(area).get("list").map(|r| r.width), Some(40));
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let mut tree = tree();
+        tree.resize("detail", 0, 0.25);
+        tree.collapse("events");
+        let text = tree.state().to_string();
+        let state: LayoutState = text.parse().unwrap();
+        assert_eq!(state, tree.state());
+
+        let mut restored = self::tree();
+        restored.apply_state(&state);
+        assert_eq!(restored.weights("detail"), Some(vec![1.5, 0.5]));
+        assert!(restored.is_collapsed("events"));
+        assert!("bogus line".parse::<LayoutState>().is_err());
+    }
+}
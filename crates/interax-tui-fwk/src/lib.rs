@@ -9,11 +9,42 @@
 //! ## Features
 //!
 //! - **Event-driven**: No polling, only responds to terminal events and task messages
-//! - **Async tasks**: Background tasks communicate via typed message channels
+//! - **Async tasks**: Background tasks communicate via typed message channels,
+//!   broadcast with shared payloads to the UI and any other subscriber
+//! - **Async effects**: One-shot futures spawned from event handlers, with results
+//!   delivered back to the originating component and automatic cancellation
+//! - **Timers**: Per-component timeouts, intervals, debounce and throttle routed to
+//!   the requesting component, without a global tick
+//! - **Animations**: Eased tweens, spinners, progress bars and tab and focus
+//!   transitions that request frames only while they run
 //! - **Builder pattern**: Clean, composable application setup
 //! - **Tabs support**: Built-in tab management with enable/disable support
+//!   and nested `TabGroup`s for second-level navigation
 //! - **Minimal allocations**: Designed for efficiency in hot paths
 //! - **Runtime control**: Toggle mouse capture, navigate tabs, quit via contexts
+//! - **Layouts**: Declarative rows, columns and resizable splits with mounted components
+//! - **Responsive layouts**: Compact/normal/wide breakpoints and a minimum-size guard
+//! - **Shared state**: Typed store slices with signals and derived selectors
+//! - **Session persistence**: Active tab, focus and component state saved across restarts
+//! - **Recording**: Record events and task messages to a file and replay them deterministically
+//! - **Remote attach**: Serve one app over a Unix socket to several operators with
+//!   independent views and shared task outputs
+//! - **Localization**: Fluent resources with locale negotiation and runtime switching
+//! - **Screen router**: Push/pop drill-down screens with history and named routes
+//! - **Clipboard**: Copy via OSC 52 (works over SSH) with optional local clipboard fallback
+//! - **Notifications**: Toasts with severity and auto-dismiss from handlers and tasks,
+//!   plus a persistent status bar
+//! - **Instrumentation**: Frame, handling and idle times, redraw counts and task queue
+//!   depths via `DrawContext::stats()`, with a toggleable debug overlay
+//! - **Command palette**: Ctrl+P overlay fuzzy-searching commands, tabs and focus targets
+//! - **Widgets**: Focusable, themed table, list and tree components
+//! - **Unicode text**: Grapheme-aware truncation, display width and right-to-left alignment
+//! - **Text editing**: Single-line inputs and multi-line text areas with validation
+//! - **Scroll views**: Scrollable viewports with scrollbars and follow-tail mode
+//! - **Log capture**: A `tracing` layer with a ring buffer and a `LogView` tab
+//! - **Charts**: Time-series buffers fed from tasks with sparkline, line chart,
+//!   histogram and gauge widgets
+//! - **Forms**: Labeled fields with per-field and cross-field validation and typed submission
 //!
 //! ## Quick Start
 //!
@@ -93,29 +124,90 @@
 //!     .build()?;
 //! ```
 
+pub mod animation;
 pub mod app;
 pub mod bus;
+pub mod clipboard;
 pub mod component;
 pub mod context;
+pub mod effect;
 pub mod event;
 pub mod focus;
+pub mod l10n;
+pub mod layout;
+#[cfg(feature = "tracing")]
+pub mod logging;
+pub mod notify;
+pub mod palette;
+#[cfg(feature = "persist")]
+pub mod persist;
+#[cfg(feature = "record")]
+pub mod record;
+pub mod responsive;
+pub mod router;
+pub mod series;
+#[cfg(all(unix, feature = "server"))]
+pub mod server;
+pub mod stats;
+pub mod status;
+pub mod store;
 pub mod tabs;
 pub mod task;
 pub mod terminal;
+pub mod theme;
+pub mod timer;
+pub mod unicode;
+pub mod widgets;
 
 // Re-export main types at crate root for convenience
+pub use animation::{blend, Animator, Easing, Tween};
 pub use app::{App, AppBuilder, AppError, BuildError};
 pub use bus::{MessageBus, SendError, TaskMessage, TaskSender, TrySendError};
+pub use clipboard::{Clipboard, LocalClipboard};
 pub use component::{BoxedComponent, Component, ComponentExt, MainUi};
 pub use context::{
-    AppContext, DrawContext, FocusDrawContext, FocusEventContext, TabEventContext, TabsDrawContext,
+    AppContext, DrawContext, FocusDrawContext, FocusEventContext, NavigateDrawContext,
+    NavigateEventContext, NavigateRequestContext, TabEventContext, TabsDrawContext,
     TabsEventContext,
 };
+pub use effect::{EffectId, EffectOutput};
 pub use event::{Event, KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+pub use fluent_bundle::FluentArgs;
 pub use focus::{EventResult, FocusManager};
-pub use tabs::{BoxedTab, Tab, TabInfo, TabManager};
+pub use l10n::{L10nError, Localizer};
+pub use layout::{
+    LayoutAreas, LayoutNode, LayoutState, LayoutTree, LayoutView, ParseLayoutStateError,
+};
+pub use notify::{Notifier, Severity, Toast, ToastId};
+pub use palette::{fuzzy_match, Command, FuzzyMatch};
+#[cfg(feature = "persist")]
+pub use persist::{session_path, Persist, Session};
+pub use responsive::{Breakpoint, Breakpoints, Responsive};
+pub use router::{BoxedScreen, Navigation, RouteParams, Router, Screen};
+pub use series::{Aggregation, Histogram, Sample, SeriesStats, TimeSeries};
+#[cfg(all(unix, feature = "server"))]
+pub use server::{attach, AppServer};
+pub use stats::FrameStats;
+pub use status::StatusBar;
+pub use store::{Selector, Signal, Store};
+pub use tabs::{BoxedTab, Tab, TabGroup, TabInfo, TabManager};
 pub use task::{Task, TaskContext, TaskHandle};
-pub use terminal::{install_panic_hook, Terminal, TerminalConfig, TerminalError};
+pub use terminal::{install_panic_hook, Terminal, TerminalConfig, TerminalError, TerminalOutput};
+pub use theme::Theme;
+pub use timer::Timer;
+pub use unicode::{display_width, fit_line, text_direction, truncate, TextDirection};
+pub use widgets::{
+    Field, FieldValue, Form, FormError, FormValues, ProgressBar, ScrollContent, ScrollOffset,
+    ScrollView, SelectableList, SelectableTable, SeriesGauge, SeriesHistogram, SeriesLineChart,
+    SeriesSparkline, SortOrder, Spinner, TableColumn, TextArea, TextBuffer, TextInput, TreeNode,
+    TreeView, Validator,
+};
+
+// Log capture (requires the `tracing` feature, enabled by default)
+#[cfg(feature = "tracing")]
+pub use logging::{LogBuffer, LogLayer, LogRecord, LogWatcher, LogsUpdated};
+#[cfg(feature = "tracing")]
+pub use widgets::{LogFilter, LogView};
 
 // Conditionally re-export blocking task helpers
 #[cfg(feature = "blocking-tasks")]
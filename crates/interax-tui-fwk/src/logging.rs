@@ -0,0 +1,359 @@
+//! Log capture for the TUI framework.
+//!
+//! Writing to stdout or stderr while the app runs corrupts the alternate
+//! screen. This module provides a `tracing` layer that captures log records
+//! into a bounded in-memory ring buffer instead, so they can be shown in a
+//! `LogView`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppBuilder, LogBuffer, LogView};
+//!
+//! let logs = LogBuffer::new(10_000);
+//! logs.install()?;
+//! // Or combine with other layers:
+//! // tracing_subscriber::registry().with(logs.layer()).init();
+//!
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .add_tab(LogView::new("logs", "Logs", logs.clone()))
+//!     // Redraw when new records arrive
+//!     .add_task("logs", logs.watcher())
+//!     .build()?;
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::Notify;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::bus::TaskSender;
+use crate::task::{Task, TaskContext};
+
+/// A captured log record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// Sequence number, increasing across the lifetime of the buffer.
+    pub seq: u64,
+    /// When the record was captured.
+    pub timestamp: SystemTime,
+    /// Severity.
+    pub level: Level,
+    /// Target (usually the module path).
+    pub target: String,
+    /// Message followed by any other fields as `key=value`.
+    pub message: String,
+}
+
+impl LogRecord {
+    /// Capture time as `HH:MM:SS.mmm` (UTC).
+    pub fn time(&self) -> String {
+        let since_epoch = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let secs = since_epoch.as_secs() % 86_400;
+        format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            since_epoch.subsec_millis()
+        )
+    }
+}
+
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:>5} {}: {}",
+            self.time(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+pub(crate) struct Records {
+    records: VecDeque<LogRecord>,
+    capacity: usize,
+    next_seq: u64,
+}
+
+impl Records {
+    /// Iterate over the buffered records, oldest first.
+    pub(crate) fn iter(&self) -> impl DoubleEndedIterator<Item = &LogRecord> {
+        self.records.iter()
+    }
+}
+
+struct Shared {
+    records: Mutex<Records>,
+    notify: Notify,
+}
+
+/// A bounded ring buffer of log records.
+///
+/// Cloning is cheap and every clone shares the same records. When the
+/// buffer is full, the oldest record is dropped.
+#[derive(Clone)]
+pub struct LogBuffer {
+    shared: Arc<Shared>,
+}
+
+impl LogBuffer {
+    /// Create a buffer holding at most `capacity` records.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            shared: Arc::new(Shared {
+                records: Mutex::new(Records {
+                    records: VecDeque::with_capacity(capacity.min(1024)),
+                    capacity,
+                    next_seq: 0,
+                }),
+                notify: Notify::new(),
+            }),
+        }
+    }
+
+    /// A `tracing` layer that captures records into this buffer.
+    pub fn layer(&self) -> LogLayer {
+        LogLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    /// Install a global subscriber that captures every record into this
+    /// buffer.
+    ///
+    /// Use `layer()` instead to combine the capture with filters or other
+    /// layers.
+    pub fn install(&self) -> Result<(), tracing::subscriber::SetGlobalDefaultError> {
+        use tracing_subscriber::layer::SubscriberExt;
+        tracing::subscriber::set_global_default(tracing_subscriber::registry().with(self.layer()))
+    }
+
+    /// A task that sends `LogsUpdated` when new records arrive.
+    ///
+    /// Add it with `AppBuilder::add_task` and return `true` from
+    /// `handle_task_message` to redraw. Updates are sent at most every
+    /// 100 ms.
+    pub fn watcher(&self) -> LogWatcher {
+        LogWatcher {
+            buffer: self.clone(),
+            interval: Duration::from_millis(100),
+        }
+    }
+
+    /// Append a record.
+    pub fn push(&self, level: Level, target: impl Into<String>, message: impl Into<String>) {
+        {
+            let mut records = self.lock();
+            let seq = records.next_seq;
+            records.next_seq += 1;
+            if records.records.len() == records.capacity {
+                records.records.pop_front();
+            }
+            records.records.push_back(LogRecord {
+                seq,
+                timestamp: SystemTime::now(),
+                level,
+                target: target.into(),
+                message: message.into(),
+            });
+        }
+        self.shared.notify.notify_one();
+    }
+
+    /// Maximum number of records kept.
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Number of buffered records.
+    pub fn len(&self) -> usize {
+        self.lock().records.len()
+    }
+
+    /// Check if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().records.is_empty()
+    }
+
+    /// Remove all records.
+    pub fn clear(&self) {
+        self.lock().records.clear();
+        self.shared.notify.notify_one();
+    }
+
+    /// Copy the buffered records, oldest first.
+    pub fn snapshot(&self) -> Vec<LogRecord> {
+        self.lock().records.iter().cloned().collect()
+    }
+
+    /// Wait until a record is pushed (or the buffer is cleared).
+    pub async fn changed(&self) {
+        self.shared.notify.notified().await;
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, Records> {
+        self.shared
+            .records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for LogBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.lock();
+        f.debug_struct("LogBuffer")
+            .field("len", &records.records.len())
+            .field("capacity", &records.capacity)
+            .finish()
+    }
+}
+
+/// A `tracing_subscriber` layer that writes events into a `LogBuffer`.
+///
+/// Created with `LogBuffer::layer()`. Combine it with the usual
+/// `tracing_subscriber` filters to control which records are captured.
+pub struct LogLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for LogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.buffer
+            .push(*metadata.level(), metadata.target(), visitor.finish());
+    }
+}
+
+/// Collects the `message` field and appends the others as `key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(mut self) -> String {
+        if !self.fields.is_empty() {
+            if !self.message.is_empty() {
+                self.message.push(' ');
+            }
+            self.message.push_str(&self.fields);
+        }
+        self.message
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            if !self.fields.is_empty() {
+                self.fields.push(' ');
+            }
+            let _ = write!(self.fields, "{}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Message sent by `LogWatcher` when new records are available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogsUpdated;
+
+/// A task that notifies the UI about new log records.
+///
+/// Created with `LogBuffer::watcher()`.
+pub struct LogWatcher {
+    buffer: LogBuffer,
+    interval: Duration,
+}
+
+impl LogWatcher {
+    /// Set the minimum time between two updates.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl Task for LogWatcher {
+    type Message = LogsUpdated;
+
+    async fn run(self, sender: TaskSender<Self::Message>, mut ctx: TaskContext) {
+        loop {
+            tokio::select! {
+                _ = self.buffer.changed() => {
+                    if sender.send(LogsUpdated).await.is_err() {
+                        break;
+                    }
+                    // Coalesce bursts of records into one redraw
+                    tokio::time::sleep(self.interval).await;
+                }
+                _ = ctx.cancelled() => {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_layer_captures_records() {
+        let buffer = LogBuffer::new(10);
+        let subscriber = tracing_subscriber::registry().with(buffer.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "interax::bus", endpoint = 1, "attribute changed");
+            tracing::warn!("no message fields");
+        });
+
+        let records = buffer.snapshot();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, Level::INFO);
+        assert_eq!(records[0].target, "interax::bus");
+        assert_eq!(records[0].message, "attribute changed endpoint=1");
+        assert_eq!(records[1].level, Level::WARN);
+        assert_eq!(records[1].message, "no message fields");
+    }
+
+    #[test]
+    fn test_buffer_is_bounded() {
+        let buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(Level::INFO, "test", format!("record {}", i));
+        }
+        let records = buffer.snapshot();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].seq, 2);
+        assert_eq!(records[2].message, "record 4");
+    }
+}
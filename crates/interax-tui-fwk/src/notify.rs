@@ -0,0 +1,384 @@
+//! Toast notifications for the TUI framework.
+//!
+//! A `Notifier` queues short messages with a severity that the framework
+//! draws as an overlay in the bottom-right corner and dismisses after a
+//! timeout. It is reachable from event handlers via `AppContext::notifier()`
+//! and from background tasks via `TaskContext::notifier()`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use interax_tui_fwk::{Severity, Toast};
+//!
+//! // In an event handler
+//! ctx.notifier().success("Saved");
+//!
+//! // In a task
+//! ctx.notifier().push(
+//!     Toast::new(Severity::Error, "Connection lost")
+//!         .title("Controller")
+//!         .sticky(),
+//! );
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Span,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::Notify;
+
+use crate::l10n::Localizer;
+use crate::theme::Theme;
+
+/// Maximum width of a toast, including borders.
+const TOAST_WIDTH: u16 = 48;
+
+/// Maximum number of queued toasts; the oldest are dropped first.
+const MAX_TOASTS: usize = 32;
+
+/// Severity of a toast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Neutral information.
+    Info,
+    /// A successful operation.
+    Success,
+    /// Something that may need attention.
+    Warning,
+    /// A failure.
+    Error,
+}
+
+impl Severity {
+    /// How long toasts of this severity stay visible by default.
+    pub fn default_timeout(self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(4),
+            Severity::Warning => Duration::from_secs(6),
+            Severity::Error => Duration::from_secs(10),
+        }
+    }
+
+    /// Style of this severity in `theme`.
+    pub fn style(self, theme: &Theme) -> Style {
+        match self {
+            Severity::Info => theme.info,
+            Severity::Success => theme.success,
+            Severity::Warning => theme.warning,
+            Severity::Error => theme.error,
+        }
+    }
+
+    /// Message id of the default toast title.
+    fn label_id(self) -> &'static str {
+        match self {
+            Severity::Info => "fwk-toast-info",
+            Severity::Success => "fwk-toast-success",
+            Severity::Warning => "fwk-toast-warning",
+            Severity::Error => "fwk-toast-error",
+        }
+    }
+}
+
+/// A transient message shown by the framework.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    /// Severity, used for the border color and default title.
+    pub severity: Severity,
+    /// Title shown in the border (defaults to the severity).
+    pub title: Option<String>,
+    /// Message text.
+    pub message: String,
+    /// Time until the toast is dismissed, or `None` to keep it until
+    /// `Notifier::dismiss` is called.
+    pub timeout: Option<Duration>,
+}
+
+impl Toast {
+    /// Create a toast with the severity's default timeout.
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            title: None,
+            message: message.into(),
+            timeout: Some(severity.default_timeout()),
+        }
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the time until the toast is dismissed.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Keep the toast until it is dismissed explicitly.
+    pub fn sticky(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+}
+
+/// Identifies a queued toast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+struct Entry {
+    id: ToastId,
+    toast: Toast,
+    expires: Option<Instant>,
+}
+
+struct Queue {
+    entries: VecDeque<Entry>,
+    next_id: u64,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    notify: Notify,
+}
+
+/// A shared queue of toasts.
+///
+/// Cloning is cheap and every clone shares the same queue. Adding or
+/// dismissing a toast wakes the event loop so the overlay is redrawn.
+#[derive(Clone)]
+pub struct Notifier {
+    shared: Arc<Shared>,
+}
+
+impl Notifier {
+    /// Create an empty queue.
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                queue: Mutex::new(Queue {
+                    entries: VecDeque::new(),
+                    next_id: 0,
+                }),
+                notify: Notify::new(),
+            }),
+        }
+    }
+
+    /// Queue a toast.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let id = {
+            let mut queue = self.lock();
+            let id = ToastId(queue.next_id);
+            queue.next_id += 1;
+            if queue.entries.len() == MAX_TOASTS {
+                queue.entries.pop_front();
+            }
+            let expires = toast.timeout.map(|timeout| Instant::now() + timeout);
+            queue.entries.push_back(Entry { id, toast, expires });
+            id
+        };
+        self.shared.notify.notify_one();
+        id
+    }
+
+    /// Queue a toast with the default timeout.
+    pub fn notify(&self, severity: Severity, message: impl Into<String>) -> ToastId {
+        self.push(Toast::new(severity, message))
+    }
+
+    /// Queue an info toast.
+    pub fn info(&self, message: impl Into<String>) -> ToastId {
+        self.notify(Severity::Info, message)
+    }
+
+    /// Queue a success toast.
+    pub fn success(&self, message: impl Into<String>) -> ToastId {
+        self.notify(Severity::Success, message)
+    }
+
+    /// Queue a warning toast.
+    pub fn warning(&self, message: impl Into<String>) -> ToastId {
+        self.notify(Severity::Warning, message)
+    }
+
+    /// Queue an error toast.
+    pub fn error(&self, message: impl Into<String>) -> ToastId {
+        self.notify(Severity::Error, message)
+    }
+
+    /// Remove a toast.
+    ///
+    /// Returns `false` if it was already dismissed.
+    pub fn dismiss(&self, id: ToastId) -> bool {
+        let removed = {
+            let mut queue = self.lock();
+            let len = queue.entries.len();
+            queue.entries.retain(|entry| entry.id != id);
+            queue.entries.len() != len
+        };
+        if removed {
+            self.shared.notify.notify_one();
+        }
+        removed
+    }
+
+    /// Remove all toasts.
+    pub fn dismiss_all(&self) {
+        self.lock().entries.clear();
+        self.shared.notify.notify_one();
+    }
+
+    /// Copy the queued toasts, oldest first.
+    pub fn toasts(&self) -> Vec<Toast> {
+        self.lock()
+            .entries
+            .iter()
+            .map(|entry| entry.toast.clone())
+            .collect()
+    }
+
+    /// Number of queued toasts.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Check if no toasts are queued.
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Wait until a toast is added or dismissed.
+    pub async fn changed(&self) {
+        self.shared.notify.notified().await;
+    }
+
+    /// When the next toast times out.
+    pub(crate) fn next_expiry(&self) -> Option<Instant> {
+        self.lock()
+            .entries
+            .iter()
+            .filter_map(|entry| entry.expires)
+            .min()
+    }
+
+    /// Drop the toasts that timed out at `now`.
+    ///
+    /// Returns `true` if any toast was removed.
+    pub(crate) fn expire(&self, now: Instant) -> bool {
+        let mut queue = self.lock();
+        let len = queue.entries.len();
+        queue
+            .entries
+            .retain(|entry| entry.expires.is_none_or(|expires| expires > now));
+        queue.entries.len() != len
+    }
+
+    /// Draw the queued toasts stacked upwards from the bottom-right corner
+    /// of `area`, newest at the bottom.
+    pub(crate) fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme, l10n: &Localizer) {
+        let queue = self.lock();
+        let width = TOAST_WIDTH.min(area.width);
+        if width < 4 {
+            return;
+        }
+        let text_width = (width - 2) as usize;
+        let mut bottom = area.bottom();
+
+        for entry in queue.entries.iter().rev() {
+            let toast = &entry.toast;
+            let lines = wrapped_lines(&toast.message, text_width);
+            let height = (lines as u16).saturating_add(2);
+            if bottom < area.y + height {
+                break;
+            }
+            let rect = Rect {
+                x: area.right() - width,
+                y: bottom - height,
+                width,
+                height,
+            };
+            bottom -= height;
+
+            let style = toast.severity.style(theme);
+            let title = match &toast.title {
+                Some(title) => title.clone(),
+                None => l10n.message(toast.severity.label_id()),
+            };
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(style)
+                .title(Span::styled(format!(" {} ", title), style));
+            frame.render_widget(Clear, rect);
+            frame.render_widget(
+                Paragraph::new(toast.message.as_str())
+                    .style(theme.text)
+                    .wrap(Wrap { trim: true })
+                    .block(block),
+                rect,
+            );
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.shared
+            .queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Notifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Notifier")
+            .field("len", &self.lock().entries.len())
+            .finish()
+    }
+}
+
+/// Approximate number of lines `text` takes when wrapped at `width`.
+fn wrapped_lines(text: &str, width: usize) -> usize {
+    text.lines()
+        .map(|line| line.chars().count().div_ceil(width.max(1)).max(1))
+        .sum::<usize>()
+        .max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_and_dismiss() {
+        let notifier = Notifier::new();
+        let info = notifier.push(Toast::new(Severity::Info, "connected").timeout(Duration::ZERO));
+        let error = notifier.push(Toast::new(Severity::Error, "lost").sticky());
+        assert_eq!(notifier.len(), 2);
+        assert!(notifier.next_expiry().is_some());
+
+        assert!(notifier.expire(Instant::now() + Duration::from_millis(1)));
+        assert_eq!(notifier.toasts()[0].message, "lost");
+        assert!(notifier.next_expiry().is_none());
+
+        assert!(!notifier.dismiss(info));
+        assert!(notifier.dismiss(error));
+        assert!(notifier.is_empty());
+    }
+}
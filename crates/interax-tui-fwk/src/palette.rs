@@ -0,0 +1,466 @@
+//! Command palette for the TUI framework.
+//!
+//! The palette is an overlay (opened with Ctrl+P by default) listing the
+//! commands registered by the `MainUi` and the active tab, the tabs to jump
+//! to and the registered focus ids. Typing fuzzy-filters the list and Enter
+//! runs the selected entry.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppContext, Command, Component, EventResult};
+//!
+//! impl Component for MyApp {
+//!     fn commands(&self) -> Vec<Command> {
+//!         vec![
+//!             Command::new("refresh", "Refresh endpoints").shortcut("r"),
+//!             Command::new("mouse", "Toggle mouse capture"),
+//!         ]
+//!     }
+//!
+//!     fn on_command(&mut self, id: &str, ctx: &mut AppContext) -> EventResult {
+//!         match id {
+//!             "refresh" => self.refresh(),
+//!             "mouse" => {
+//!                 let enabled = ctx.mouse_capture_enabled();
+//!                 let _ = ctx.set_mouse_capture(!enabled);
+//!             }
+//!             _ => return EventResult::Unhandled,
+//!         }
+//!         EventResult::Handled
+//!     }
+//! }
+//! ```
+
+use fluent_bundle::FluentArgs;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::event::{Event, KeyCode, KeyModifiers};
+use crate::l10n::Localizer;
+use crate::theme::Theme;
+use crate::widgets::{ScrollCursor, TextInput};
+
+/// A named action shown in the command palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    /// Identifier passed back to `on_command`.
+    pub id: String,
+    /// Title shown in the palette and matched against the query.
+    pub title: String,
+    /// Optional longer description, shown next to the title.
+    pub description: Option<String>,
+    /// Optional key hint, shown next to the title.
+    pub shortcut: Option<String>,
+}
+
+impl Command {
+    /// Create a command.
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+            shortcut: None,
+        }
+    }
+
+    /// Set the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the key hint.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+}
+
+/// Result of matching a query against a candidate string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better.
+    pub score: i32,
+    /// Char indices of the matched characters in the candidate.
+    pub positions: Vec<usize>,
+}
+
+/// Match `query` as a case-insensitive subsequence of `candidate`.
+///
+/// Consecutive matches and matches at the start of a word score higher,
+/// so `"ep"` ranks "Endpoints" above "Sleep". Returns `None` if some query
+/// character does not occur in order. An empty query matches everything
+/// with a score of 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let mut positions = Vec::new();
+    let mut score = 0;
+    let mut query_chars = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut prev: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+
+    for (index, c) in candidate.chars().enumerate() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(wanted.to_lowercase()) {
+            score += 1;
+            if last_match.is_some_and(|last| last + 1 == index) {
+                score += 5;
+            }
+            let word_start = match prev {
+                None => true,
+                Some(p) => !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase()),
+            };
+            if word_start {
+                score += 8;
+            }
+            if last_match.is_none() {
+                // Prefer matches close to the start
+                score -= index.min(10) as i32;
+            }
+            positions.push(index);
+            last_match = Some(index);
+            query_chars.next();
+        }
+        prev = Some(c);
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Who receives a command picked from the palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CommandTarget {
+    /// The `MainUi` (which forwards to its child components).
+    Main,
+    /// The active tab.
+    Tab,
+}
+
+/// What happens when a palette entry is chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PaletteAction {
+    /// Run a registered command.
+    Command { target: CommandTarget, id: String },
+    /// Select a tab by id.
+    SelectTab(String),
+    /// Focus an element by id.
+    Focus(String),
+}
+
+/// A row of the palette.
+#[derive(Debug, Clone)]
+pub(crate) struct PaletteEntry {
+    label: String,
+    detail: String,
+    action: PaletteAction,
+}
+
+impl PaletteEntry {
+    /// An entry running `command`.
+    pub(crate) fn command(command: Command, target: CommandTarget) -> Self {
+        let detail = command.shortcut.or(command.description).unwrap_or_default();
+        Self {
+            label: command.title,
+            detail,
+            action: PaletteAction::Command {
+                target,
+                id: command.id,
+            },
+        }
+    }
+
+    /// An entry selecting a tab.
+    pub(crate) fn tab(id: &str, title: &str, l10n: &Localizer) -> Self {
+        let mut args = FluentArgs::new();
+        args.set("title", title);
+        Self {
+            label: l10n.format("fwk-palette-go-to-tab", &args),
+            detail: "tab".to_string(),
+            action: PaletteAction::SelectTab(id.to_string()),
+        }
+    }
+
+    /// An entry focusing an element.
+    pub(crate) fn focus(id: &str, l10n: &Localizer) -> Self {
+        let mut args = FluentArgs::new();
+        args.set("id", id);
+        Self {
+            label: l10n.format("fwk-palette-focus", &args),
+            detail: "focus".to_string(),
+            action: PaletteAction::Focus(id.to_string()),
+        }
+    }
+}
+
+/// The command palette overlay owned by `App`.
+pub(crate) struct CommandPalette {
+    key: (KeyCode, KeyModifiers),
+    open: bool,
+    input: TextInput,
+    entries: Vec<PaletteEntry>,
+    /// Indices into `entries` with their matched positions, best first.
+    matches: Vec<(usize, Vec<usize>)>,
+    cursor: ScrollCursor,
+    /// Localized strings, refreshed on every `open`.
+    title: String,
+    no_matches: String,
+}
+
+impl CommandPalette {
+    /// Create a closed palette opened by `code` + `modifiers`.
+    pub(crate) fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            key: (code, modifiers),
+            open: false,
+            input: TextInput::new(":palette"),
+            entries: Vec::new(),
+            matches: Vec::new(),
+            cursor: ScrollCursor::default(),
+            title: String::new(),
+            no_matches: String::new(),
+        }
+    }
+
+    /// Check if the palette is shown.
+    pub(crate) fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Check if `event` is the key that opens the palette.
+    pub(crate) fn is_trigger(&self, event: &Event) -> bool {
+        event.is_key_with_modifiers(self.key.0, self.key.1)
+    }
+
+    /// Show the palette with a fresh query over `entries`.
+    pub(crate) fn open(&mut self, entries: Vec<PaletteEntry>, l10n: &Localizer) {
+        self.open = true;
+        self.title = format!(" {} ", l10n.message("fwk-palette-title"));
+        self.no_matches = l10n.message("fwk-palette-no-matches");
+        self.input
+            .set_placeholder(l10n.message("fwk-palette-placeholder"));
+        self.entries = entries;
+        self.input.set_text("");
+        self.input.buffer_mut().clear_history();
+        self.refilter();
+    }
+
+    /// Hide the palette.
+    pub(crate) fn close(&mut self) {
+        self.open = false;
+        self.entries.clear();
+        self.matches.clear();
+    }
+
+    /// Handle a key while the palette is open.
+    ///
+    /// Returns the chosen action when Enter is pressed. Every event is
+    /// consumed so nothing leaks to the UI underneath.
+    pub(crate) fn handle_input(&mut self, event: &Event) -> Option<PaletteAction> {
+        let Event::Key(key) = event else {
+            if let Event::Paste(_) = event {
+                self.input_changed(event);
+            }
+            return None;
+        };
+        match key.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Enter => {
+                let action = self
+                    .matches
+                    .get(self.cursor.cursor())
+                    .map(|(index, _)| self.entries[*index].action.clone());
+                self.close();
+                return action;
+            }
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown => {
+                self.cursor.handle_key(key.code, self.matches.len());
+            }
+            _ if self.is_trigger(event) => self.close(),
+            _ => self.input_changed(event),
+        }
+        None
+    }
+
+    fn input_changed(&mut self, event: &Event) {
+        let version = self.input.buffer().version();
+        self.input.handle_input(event);
+        if self.input.buffer().version() != version {
+            self.refilter();
+        }
+    }
+
+    /// Recompute the matches for the current query.
+    fn refilter(&mut self) {
+        let query = self.input.text();
+        let mut scored: Vec<(i32, usize, Vec<usize>)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                fuzzy_match(query, &entry.label).map(|m| (m.score, index, m.positions))
+            })
+            .collect();
+        // Best score first; registration order breaks ties
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        self.matches = scored
+            .into_iter()
+            .map(|(_, index, positions)| (index, positions))
+            .collect();
+        self.cursor.set(0, self.matches.len());
+    }
+
+    /// Render the palette centered near the top of `area`.
+    pub(crate) fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = area.width.saturating_sub(4).min(72);
+        let rows = self.matches.len().clamp(1, 12) as u16;
+        let height = (rows + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 4,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border_focused)
+            .title(Span::styled(self.title.as_str(), theme.accent));
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+        if inner.height == 0 {
+            return;
+        }
+
+        let input_area = Rect { height: 1, ..inner };
+        self.input.render_text(frame, input_area, true, theme);
+        let separator = Rect {
+            y: inner.y + 1,
+            height: 1.min(inner.height - 1),
+            ..inner
+        };
+        frame.render_widget(
+            Paragraph::new("─".repeat(inner.width as usize)).style(theme.muted),
+            separator,
+        );
+
+        let list = Rect {
+            y: inner.y + 2.min(inner.height),
+            height: inner.height.saturating_sub(2),
+            ..inner
+        };
+        if self.matches.is_empty() {
+            frame.render_widget(
+                Paragraph::new(self.no_matches.as_str()).style(theme.muted),
+                list,
+            );
+            return;
+        }
+
+        let visible = self
+            .cursor
+            .visible_range(self.matches.len(), list.height as usize);
+        let lines: Vec<Line> = visible
+            .map(|row| {
+                let (index, positions) = &self.matches[row];
+                let entry = &self.entries[*index];
+                let base = if row == self.cursor.cursor() {
+                    theme.cursor_focused
+                } else {
+                    theme.text
+                };
+                let mut spans: Vec<Span> = entry
+                    .label
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        let style = if positions.contains(&i) {
+                            base.patch(theme.accent)
+                        } else {
+                            base
+                        };
+                        Span::styled(c.to_string(), style)
+                    })
+                    .collect();
+                // Right-align the detail when it fits
+                let label_width = entry.label.chars().count();
+                let detail_width = entry.detail.chars().count();
+                let total = list.width as usize;
+                if label_width + detail_width + 1 < total {
+                    spans.push(Span::styled(
+                        " ".repeat(total - label_width - detail_width),
+                        base,
+                    ));
+                    spans.push(Span::styled(entry.detail.clone(), base.patch(theme.muted)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), list);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_fuzzy_match_ranking() {
+        assert!(fuzzy_match("xyz", "Endpoints").is_none());
+        assert_eq!(fuzzy_match("", "Endpoints").unwrap().score, 0);
+
+        let m = fuzzy_match("ep", "Endpoints").unwrap();
+        assert_eq!(m.positions, vec![0, 3]);
+
+        // Word starts and consecutive runs outrank scattered matches
+        let word = fuzzy_match("rl", "Refresh list").unwrap().score;
+        let scattered = fuzzy_match("rl", "Interval").unwrap().score;
+        assert!(word > scattered);
+        let run = fuzzy_match("log", "Logs").unwrap().score;
+        let gaps = fuzzy_match("log", "Long loop").unwrap().score;
+        assert!(run > gaps);
+    }
+
+    #[test]
+    fn test_palette_filters_and_dispatches() {
+        let mut palette = CommandPalette::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+        let l10n = Localizer::new();
+        palette.open(
+            vec![
+                PaletteEntry::command(Command::new("refresh", "Refresh"), CommandTarget::Main),
+                PaletteEntry::tab("logs", "Logs", &l10n),
+                PaletteEntry::focus("endpoints", &l10n),
+            ],
+            &l10n,
+        );
+        assert_eq!(palette.matches.len(), 3);
+
+        palette.handle_input(&key(KeyCode::Char('l')));
+        palette.handle_input(&key(KeyCode::Char('o')));
+        palette.handle_input(&key(KeyCode::Char('g')));
+        assert_eq!(palette.matches.len(), 1);
+        assert_eq!(
+            palette.handle_input(&key(KeyCode::Enter)),
+            Some(PaletteAction::SelectTab("logs".to_string()))
+        );
+        assert!(!palette.is_open());
+
+        palette.open(vec![PaletteEntry::focus("endpoints", &l10n)], &l10n);
+        assert_eq!(palette.handle_input(&key(KeyCode::Esc)), None);
+        assert!(!palette.is_open());
+    }
+}
@@ -0,0 +1,281 @@
+//! Session state persistence for the TUI framework.
+//!
+//! When enabled with `AppBuilder::persist("my-app")`, `App::run` loads a
+//! `Session` from `$XDG_STATE_HOME/my-app/session.json` (falling back to
+//! `~/.local/state`) before the first draw and writes it back on shutdown.
+//!
+//! The framework records the active tab and the focused element itself.
+//! Components and tabs add their own state in `save_state` and read it
+//! back in `restore_state`, usually through a type implementing `Persist`.
+//!
+//! Every entry is stored with the `Persist::VERSION` of its type. Entries
+//! with another version, or that fail to deserialize, are skipped, so a
+//! schema change only loses the old state instead of breaking startup.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{Component, Session};
+//!
+//! impl Component for MyApp {
+//!     fn save_state(&self, session: &mut Session) {
+//!         session.save("my-app.filter", &self.filter);
+//!         session.save("my-app.layout", &self.layout);
+//!     }
+//!
+//!     fn restore_state(&mut self, session: &Session) {
+//!         session.restore("my-app.filter", &mut self.filter);
+//!         session.restore("my-app.layout", &mut self.layout);
+//!     }
+//! }
+//!
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .persist("my-app")
+//!     .build()?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Version of the session file layout itself.
+const FORMAT_VERSION: u32 = 1;
+
+/// Name of the session file in the application's state directory.
+const FILE_NAME: &str = "session.json";
+
+/// State that can be saved to and restored from a `Session`.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::Persist;
+///
+/// impl Persist for NodeFilter {
+///     type State = (String, bool);
+///     const VERSION: u32 = 2;
+///
+///     fn save(&self) -> Self::State {
+///         (self.query.clone(), self.only_online)
+///     }
+///
+///     fn restore(&mut self, (query, only_online): Self::State) {
+///         self.query = query;
+///         self.only_online = only_online;
+///     }
+/// }
+/// ```
+pub trait Persist {
+    /// Serialized form of the state.
+    type State: Serialize + DeserializeOwned;
+
+    /// Schema version of `State`.
+    ///
+    /// Bump this when `State` changes incompatibly; saved state with
+    /// another version is discarded.
+    const VERSION: u32 = 1;
+
+    /// Capture the current state.
+    fn save(&self) -> Self::State;
+
+    /// Apply previously saved state.
+    fn restore(&mut self, state: Self::State);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Entry {
+    version: u32,
+    state: Value,
+}
+
+#[derive(Serialize, Deserialize)]
+struct File {
+    version: u32,
+    entries: BTreeMap<String, Entry>,
+}
+
+/// Saved state of an application, keyed by name.
+///
+/// Keys are shared by every component and tab, so prefix them, e.g. with
+/// the component's focus id. The `app.` prefix is used by the framework.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Session {
+    entries: BTreeMap<String, Entry>,
+}
+
+impl Session {
+    /// Create an empty session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Save `value` under `key`.
+    pub fn save<P: Persist>(&mut self, key: &str, value: &P) {
+        self.set(key, P::VERSION, &value.save());
+    }
+
+    /// Restore `value` from `key`.
+    ///
+    /// Returns `false`, leaving `value` alone, if nothing usable is saved.
+    pub fn restore<P: Persist>(&self, key: &str, value: &mut P) -> bool {
+        match self.get(key, P::VERSION) {
+            Some(state) => {
+                value.restore(state);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Save a serializable value under `key` with a schema version.
+    pub fn set<T: Serialize>(&mut self, key: &str, version: u32, value: &T) {
+        // Serializing plain data into a `Value` only fails for maps with
+        // non-string keys; such state is not saved
+        if let Ok(state) = serde_json::to_value(value) {
+            self.entries
+                .insert(key.to_string(), Entry { version, state });
+        }
+    }
+
+    /// Get the value saved under `key` if it has `version` and
+    /// deserializes as `T`.
+    pub fn get<T: DeserializeOwned>(&self, key: &str, version: u32) -> Option<T> {
+        let entry = self
+            .entries
+            .get(key)
+            .filter(|entry| entry.version == version)?;
+        T::deserialize(&entry.state).ok()
+    }
+
+    /// Remove the value saved under `key`.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.entries.remove(key).is_some()
+    }
+
+    /// Check if a value is saved under `key`.
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Number of saved values.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no values are saved.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Parse a session from JSON.
+    ///
+    /// Returns an empty session if the text is not a session file of the
+    /// current format.
+    pub fn from_json(json: &str) -> Self {
+        match serde_json::from_str::<File>(json) {
+            Ok(file) if file.version == FORMAT_VERSION => Self {
+                entries: file.entries,
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// Serialize the session as JSON.
+    pub fn to_json(&self) -> String {
+        let file = File {
+            version: FORMAT_VERSION,
+            entries: self.entries.clone(),
+        };
+        serde_json::to_string_pretty(&file).expect("session serializes")
+    }
+
+    /// Load a session from `path`.
+    ///
+    /// A missing or unreadable file gives an empty session.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .map(|json| Self::from_json(&json))
+            .unwrap_or_default()
+    }
+
+    /// Write the session to `path`, creating its directory.
+    ///
+    /// The file is replaced atomically so a crash never leaves a
+    /// truncated session behind.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, self.to_json())?;
+        fs::rename(&tmp, path)
+    }
+}
+
+/// Path of the session file of `app_name` in the XDG state directory.
+///
+/// Returns `None` if neither `XDG_STATE_HOME` nor `HOME` is set.
+pub fn session_path(app_name: &str) -> Option<PathBuf> {
+    let state_home = env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/state")))?;
+    Some(state_home.join(app_name).join(FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Filter {
+        query: String,
+    }
+
+    impl Persist for Filter {
+        type State = String;
+        const VERSION: u32 = 2;
+
+        fn save(&self) -> String {
+            self.query.clone()
+        }
+
+        fn restore(&mut self, state: String) {
+            self.query = state;
+        }
+    }
+
+    #[test]
+    fn test_round_trip_and_version_mismatch() {
+        let mut session = Session::new();
+        session.save(
+            "filter",
+            &Filter {
+                query: "node-1".into(),
+            },
+        );
+        session.set("filter.v1", 1, &"old");
+
+        let loaded = Session::from_json(&session.to_json());
+        assert_eq!(loaded, session);
+
+        let mut filter = Filter {
+            query: String::new(),
+        };
+        assert!(loaded.restore("filter", &mut filter));
+        assert_eq!(filter.query, "node-1");
+
+        // Saved with another schema version
+        assert!(!loaded.restore("filter.v1", &mut filter));
+        assert_eq!(filter.query, "node-1");
+
+        assert!(Session::from_json("{\"version\": 0, \"entries\": {}}").is_empty());
+        assert!(Session::from_json("not json").is_empty());
+    }
+}
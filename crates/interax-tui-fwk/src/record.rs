@@ -0,0 +1,343 @@
+//! Event recording and deterministic replay.
+//!
+//! With `AppBuilder::record(path)`, the app writes every terminal event and
+//! every task message of a type registered with
+//! `AppBuilder::record_messages` to `path`, one JSON line per entry,
+//! timestamped from startup.
+//!
+//! With `AppBuilder::replay(path)`, the app does not spawn its tasks.
+//! Instead the recorded events and messages are fed back through the event
+//! loop at their original pace (or faster with `AppBuilder::replay_speed`).
+//! While the replay runs, live input other than Ctrl+C/Ctrl+Q is ignored;
+//! once it ends, the app takes live input again so the result can be
+//! inspected.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Teammate reproducing a bug
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .add_task("controller", ControllerTask::new())
+//!     .record_messages::<ControllerMessage>("controller")
+//!     .record("bug-1234.jsonl")
+//!     .build()?;
+//!
+//! // Replaying it twice as fast
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .add_task("controller", ControllerTask::new())
+//!     .record_messages::<ControllerMessage>("controller")
+//!     .replay("bug-1234.jsonl")
+//!     .replay_speed(2.0)
+//!     .build()?;
+//! ```
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::bus::{TaskMessage, DEFAULT_CHANNEL_SIZE};
+use crate::event::Event;
+
+/// One line of a recording.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    /// Milliseconds since the recording started.
+    at_ms: u64,
+    #[serde(flatten)]
+    kind: EntryKind,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum EntryKind {
+    Event(Event),
+    Message { task: String, payload: Value },
+}
+
+/// Converts the messages of one task to and from JSON.
+#[derive(Clone, Copy)]
+pub(crate) struct MessageCodec {
+    task_name: &'static str,
+    encode: fn(&(dyn Any + Send + Sync)) -> Option<Value>,
+    decode: fn(Value) -> Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl MessageCodec {
+    /// Codec for messages of type `T` sent by `task_name`.
+    pub(crate) fn new<T>(task_name: &'static str) -> Self
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        Self {
+            task_name,
+            encode: |payload| {
+                let message = payload.downcast_ref::<T>()?;
+                serde_json::to_value(message).ok()
+            },
+            decode: |value| {
+                let message: T = serde_json::from_value(value).ok()?;
+                Some(Arc::new(message))
+            },
+        }
+    }
+}
+
+/// Registered message codecs, by task name.
+#[derive(Clone, Default)]
+pub(crate) struct MessageCodecs {
+    codecs: HashMap<&'static str, MessageCodec>,
+}
+
+impl MessageCodecs {
+    /// Register a codec, replacing any previous one for the same task.
+    pub(crate) fn insert(&mut self, codec: MessageCodec) {
+        self.codecs.insert(codec.task_name, codec);
+    }
+
+    fn encode(&self, message: &TaskMessage) -> Option<Value> {
+        let codec = self.codecs.get(message.task_name)?;
+        (codec.encode)(message.payload.as_ref())
+    }
+
+    fn decode(&self, task: &str, payload: Value) -> Option<TaskMessage> {
+        let codec = self.codecs.get(task)?;
+        Some(TaskMessage {
+            task_name: codec.task_name,
+            payload: (codec.decode)(payload)?,
+        })
+    }
+}
+
+/// Writes events and messages to a recording file.
+pub(crate) struct Recorder {
+    writer: BufWriter<File>,
+    codecs: MessageCodecs,
+    start: Instant,
+}
+
+impl Recorder {
+    /// Create (or truncate) the recording at `path`.
+    pub(crate) fn create(path: &Path, codecs: MessageCodecs) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            codecs,
+            start: Instant::now(),
+        })
+    }
+
+    /// Record a terminal event.
+    pub(crate) fn event(&mut self, event: &Event) {
+        self.write(EntryKind::Event(event.clone()));
+    }
+
+    /// Record a task message, if its task has a codec.
+    pub(crate) fn message(&mut self, message: &TaskMessage) {
+        if let Some(payload) = self.codecs.encode(message) {
+            self.write(EntryKind::Message {
+                task: message.task_name.to_string(),
+                payload,
+            });
+        }
+    }
+
+    fn write(&mut self, kind: EntryKind) {
+        let entry = Entry {
+            at_ms: self.start.elapsed().as_millis() as u64,
+            kind,
+        };
+        // A failing disk should not take the UI down; the recording just
+        // ends early. Flushing per entry keeps everything up to a crash.
+        if let Ok(line) = serde_json::to_string(&entry) {
+            let _ = writeln!(self.writer, "{}", line).and_then(|()| self.writer.flush());
+        }
+    }
+}
+
+/// Recording and replay settings of an app.
+pub(crate) struct Recording {
+    codecs: MessageCodecs,
+    record_path: Option<PathBuf>,
+    replay_path: Option<PathBuf>,
+    replay_speed: f64,
+    recorder: Option<Recorder>,
+}
+
+impl Recording {
+    pub(crate) fn new() -> Self {
+        Self {
+            codecs: MessageCodecs::default(),
+            record_path: None,
+            replay_path: None,
+            replay_speed: 1.0,
+            recorder: None,
+        }
+    }
+
+    pub(crate) fn add_codec(&mut self, codec: MessageCodec) {
+        self.codecs.insert(codec);
+    }
+
+    pub(crate) fn set_record_path(&mut self, path: PathBuf) {
+        self.record_path = Some(path);
+    }
+
+    pub(crate) fn set_replay_path(&mut self, path: PathBuf) {
+        self.replay_path = Some(path);
+    }
+
+    pub(crate) fn set_replay_speed(&mut self, speed: f64) {
+        assert!(speed > 0.0, "replay speed must be positive");
+        self.replay_speed = speed;
+    }
+
+    /// Open the recording file and start the replay, if configured.
+    ///
+    /// Returns the channel of replayed terminal events; replayed messages
+    /// are sent to `messages`.
+    pub(crate) fn start(
+        &mut self,
+        messages: mpsc::Sender<TaskMessage>,
+    ) -> io::Result<Option<mpsc::Receiver<Event>>> {
+        if let Some(path) = &self.record_path {
+            self.recorder = Some(Recorder::create(path, self.codecs.clone())?);
+        }
+        let Some(path) = &self.replay_path else {
+            return Ok(None);
+        };
+        let (events_tx, events_rx) = mpsc::channel(DEFAULT_CHANNEL_SIZE);
+        spawn_replay(path, self.replay_speed, &self.codecs, events_tx, messages)?;
+        Ok(Some(events_rx))
+    }
+
+    /// Record a live terminal event.
+    pub(crate) fn event(&mut self, event: &Event) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.event(event);
+        }
+    }
+
+    /// Record a task message.
+    pub(crate) fn message(&mut self, message: &TaskMessage) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.message(message);
+        }
+    }
+}
+
+/// An entry read back from a recording.
+enum Replayed {
+    Event(Event),
+    Message(TaskMessage),
+}
+
+/// Parse a recording, skipping lines that are malformed or belong to a
+/// task without a codec.
+fn parse(text: &str, codecs: &MessageCodecs) -> Vec<(Duration, Replayed)> {
+    text.lines()
+        .filter_map(|line| serde_json::from_str::<Entry>(line).ok())
+        .filter_map(|entry| {
+            let replayed = match entry.kind {
+                EntryKind::Event(event) => Replayed::Event(event),
+                EntryKind::Message { task, payload } => {
+                    Replayed::Message(codecs.decode(&task, payload)?)
+                }
+            };
+            Some((Duration::from_millis(entry.at_ms), replayed))
+        })
+        .collect()
+}
+
+/// Read the recording at `path` and spawn a task feeding it back.
+///
+/// Events go to `events` and messages to `messages` (the message bus).
+/// `events` is closed when the replay ends. Entries are delayed by their
+/// timestamp divided by `speed`.
+pub(crate) fn spawn_replay(
+    path: &Path,
+    speed: f64,
+    codecs: &MessageCodecs,
+    events: mpsc::Sender<Event>,
+    messages: mpsc::Sender<TaskMessage>,
+) -> io::Result<()> {
+    let entries = parse(&fs::read_to_string(path)?, codecs);
+    tokio::spawn(async move {
+        let start = tokio::time::Instant::now();
+        for (at, replayed) in entries {
+            tokio::time::sleep_until(start + at.div_f64(speed)).await;
+            let sent = match replayed {
+                Replayed::Event(event) => events.send(event).await.is_ok(),
+                Replayed::Message(message) => messages.send(message).await.is_ok(),
+            };
+            if !sent {
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NodeAdded(u64);
+
+    #[test]
+    fn test_entries_round_trip() {
+        let mut codecs = MessageCodecs::default();
+        codecs.insert(MessageCodec::new::<NodeAdded>("controller"));
+
+        let key = Event::Key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        let lines = [
+            Entry {
+                at_ms: 5,
+                kind: EntryKind::Event(key.clone()),
+            },
+            Entry {
+                at_ms: 12,
+                kind: EntryKind::Message {
+                    task: "controller".into(),
+                    payload: codecs
+                        .encode(&TaskMessage::new("controller", NodeAdded(7)))
+                        .unwrap(),
+                },
+            },
+            Entry {
+                at_ms: 20,
+                kind: EntryKind::Message {
+                    task: "unknown".into(),
+                    payload: Value::Null,
+                },
+            },
+        ]
+        .iter()
+        .map(|entry| serde_json::to_string(entry).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        let entries = parse(&format!("{}\nnot json", lines), &codecs);
+        assert_eq!(entries.len(), 2);
+        assert!(
+            matches!(&entries[0], (at, Replayed::Event(event)) if at.as_millis() == 5 && *event == key)
+        );
+        let (at, Replayed::Message(message)) = &entries[1] else {
+            panic!("expected a message");
+        };
+        assert_eq!(at.as_millis(), 12);
+        assert_eq!(message.task_name, "controller");
+        assert_eq!(message.downcast_ref::<NodeAdded>(), Some(&NodeAdded(7)));
+    }
+}
@@ -0,0 +1,205 @@
+//! Responsive layouts driven by the terminal size.
+//!
+//! The framework classifies the terminal width into a `Breakpoint` and
+//! tells the `MainUi` and every tab when it changes, via
+//! `MainUi::on_breakpoint` and `Tab::on_breakpoint`. The current breakpoint
+//! is also available while drawing via `DrawContext::breakpoint()`.
+//!
+//! `Responsive` holds one value per breakpoint, such as alternative
+//! `LayoutTree`s, and falls back to the normal value.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppBuilder, Breakpoint, Breakpoints, Responsive};
+//!
+//! struct MyApp {
+//!     layouts: Responsive<LayoutTree>,
+//! }
+//!
+//! impl Component for MyApp {
+//!     fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+//!         let areas = self.layouts.get(ctx.breakpoint()).compute(area);
+//!         // ...
+//!     }
+//! }
+//!
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .breakpoints(Breakpoints::new(100, 160))
+//!     .min_size(60, 15)
+//!     .build()?;
+//! ```
+
+use fluent_bundle::FluentArgs;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::l10n::Localizer;
+use crate::theme::Theme;
+
+/// A terminal size class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Breakpoint {
+    /// Narrow terminals, e.g. a split or a phone.
+    Compact,
+    /// The usual 80+ column terminal.
+    #[default]
+    Normal,
+    /// Wide terminals with room for extra panes.
+    Wide,
+}
+
+/// Widths at which the breakpoints start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakpoints {
+    /// Minimum width of `Breakpoint::Normal`.
+    pub normal: u16,
+    /// Minimum width of `Breakpoint::Wide`.
+    pub wide: u16,
+}
+
+impl Breakpoints {
+    /// Create breakpoints starting `Normal` at `normal` columns and `Wide`
+    /// at `wide` columns.
+    pub fn new(normal: u16, wide: u16) -> Self {
+        Self {
+            normal,
+            wide: wide.max(normal),
+        }
+    }
+
+    /// Classify a terminal width.
+    pub fn classify(&self, width: u16) -> Breakpoint {
+        if width >= self.wide {
+            Breakpoint::Wide
+        } else if width >= self.normal {
+            Breakpoint::Normal
+        } else {
+            Breakpoint::Compact
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    /// `Normal` from 80 columns, `Wide` from 140.
+    fn default() -> Self {
+        Self::new(80, 140)
+    }
+}
+
+/// A value with optional variants for the compact and wide breakpoints.
+///
+/// `get` falls back to the normal value when no variant is set.
+#[derive(Debug, Clone, Default)]
+pub struct Responsive<T> {
+    compact: Option<T>,
+    normal: T,
+    wide: Option<T>,
+}
+
+impl<T> Responsive<T> {
+    /// Use `normal` for every breakpoint.
+    pub fn new(normal: T) -> Self {
+        Self {
+            compact: None,
+            normal,
+            wide: None,
+        }
+    }
+
+    /// Set the value used for compact terminals.
+    pub fn compact(mut self, value: T) -> Self {
+        self.compact = Some(value);
+        self
+    }
+
+    /// Set the value used for wide terminals.
+    pub fn wide(mut self, value: T) -> Self {
+        self.wide = Some(value);
+        self
+    }
+
+    /// The value for `breakpoint`.
+    pub fn get(&self, breakpoint: Breakpoint) -> &T {
+        match breakpoint {
+            Breakpoint::Compact => self.compact.as_ref(),
+            Breakpoint::Normal => None,
+            Breakpoint::Wide => self.wide.as_ref(),
+        }
+        .unwrap_or(&self.normal)
+    }
+
+    /// The value for `breakpoint`, mutably.
+    pub fn get_mut(&mut self, breakpoint: Breakpoint) -> &mut T {
+        match breakpoint {
+            Breakpoint::Compact => self.compact.as_mut(),
+            Breakpoint::Normal => None,
+            Breakpoint::Wide => self.wide.as_mut(),
+        }
+        .unwrap_or(&mut self.normal)
+    }
+}
+
+/// Check if `area` is smaller than the `(width, height)` minimum.
+pub(crate) fn is_too_small(area: Rect, (width, height): (u16, u16)) -> bool {
+    area.width < width || area.height < height
+}
+
+/// Draw the screen shown instead of the UI when the terminal is too small.
+pub(crate) fn draw_size_guard(
+    frame: &mut Frame,
+    area: Rect,
+    min: (u16, u16),
+    theme: &Theme,
+    l10n: &Localizer,
+) {
+    let size = |width: u16, height: u16| {
+        let mut args = FluentArgs::new();
+        args.set("width", width);
+        args.set("height", height);
+        args
+    };
+    let lines = vec![
+        Line::from(Span::styled(l10n.message("fwk-too-small"), theme.error)),
+        Line::from(Span::styled(
+            l10n.format("fwk-too-small-size", &size(area.width, area.height)),
+            theme.text,
+        )),
+        Line::from(Span::styled(
+            l10n.format("fwk-too-small-need", &size(min.0, min.1)),
+            theme.muted,
+        )),
+    ];
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let rect = Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
+    };
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), rect);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_and_fallback() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.classify(60), Breakpoint::Compact);
+        assert_eq!(breakpoints.classify(80), Breakpoint::Normal);
+        assert_eq!(breakpoints.classify(200), Breakpoint::Wide);
+
+        let columns = Responsive::new(2).wide(3);
+        assert_eq!(*columns.get(Breakpoint::Compact), 2);
+        assert_eq!(*columns.get(Breakpoint::Wide), 3);
+
+        assert!(is_too_small(Rect::new(0, 0, 30, 20), (40, 10)));
+        assert!(!is_too_small(Rect::new(0, 0, 30, 20), (30, 20)));
+    }
+}
@@ -0,0 +1,594 @@
+//! Screen navigation for the TUI framework.
+//!
+//! This module provides a router with a history stack for drill-down flows
+//! (list → detail → sub-detail) that sit on top of the tabbed interface.
+
+use ratatui::{layout::Rect, Frame};
+
+use crate::context::TabEventContext;
+use crate::effect::EffectOutput;
+use crate::event::Event;
+use crate::focus::EventResult;
+use crate::timer::Timer;
+
+/// A full-screen view that can be pushed onto the navigation stack.
+///
+/// Screens mirror the `Tab` trait: they draw themselves, handle events
+/// while they are the current screen, and receive lifecycle callbacks
+/// when they become visible or hidden.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::{Screen, Event, EventResult, KeyCode, TabEventContext};
+/// use ratatui::{Frame, layout::Rect, widgets::Paragraph};
+///
+/// struct EndpointDetail {
+///     id: u32,
+/// }
+///
+/// impl Screen for EndpointDetail {
+///     fn title(&self) -> &str {
+///         "Endpoint"
+///     }
+///
+///     fn draw(&self, frame: &mut Frame, area: Rect) {
+///         frame.render_widget(Paragraph::new(format!("Endpoint {}", self.id)), area);
+///     }
+///
+///     fn handle_event(&mut self, event: &Event, ctx: &mut TabEventContext) -> EventResult {
+///         if event.is_key(KeyCode::Esc) {
+///             ctx.navigate().back();
+///             return EventResult::Handled;
+///         }
+///         EventResult::Unhandled
+///     }
+/// }
+/// ```
+pub trait Screen: Send {
+    /// Display title for this screen (used for breadcrumbs).
+    fn title(&self) -> &str;
+
+    /// Draw the screen to the given frame area.
+    fn draw(&self, frame: &mut Frame, area: Rect);
+
+    /// Handle an input event while this screen is the current screen.
+    ///
+    /// Navigation requested through `ctx.navigate()` is applied after
+    /// this method returns.
+    #[allow(unused_variables)]
+    fn handle_event(&mut self, event: &Event, ctx: &mut TabEventContext) -> EventResult {
+        EventResult::Unhandled
+    }
+
+    /// Handle the result of an effect spawned while this screen was the
+    /// current screen.
+    ///
+    /// See `Component::handle_effect`.
+    #[allow(unused_variables)]
+    fn handle_effect(
+        &mut self,
+        output: &mut EffectOutput,
+        ctx: &mut TabEventContext,
+    ) -> EventResult {
+        EventResult::Unhandled
+    }
+
+    /// Handle a timer set while this screen was the current screen.
+    ///
+    /// See `Component::handle_timer`.
+    #[allow(unused_variables)]
+    fn handle_timer(&mut self, timer: &Timer, ctx: &mut TabEventContext) -> EventResult {
+        EventResult::Unhandled
+    }
+
+    /// Called when this screen becomes the current screen.
+    fn on_activate(&mut self) {}
+
+    /// Called when this screen stops being the current screen
+    /// (another screen was pushed over it, or it was removed).
+    fn on_deactivate(&mut self) {}
+}
+
+/// A boxed screen for type-erased storage.
+pub type BoxedScreen = Box<dyn Screen>;
+
+/// A factory that creates a screen from route parameters.
+pub type RouteFactory = Box<dyn Fn(&RouteParams) -> BoxedScreen + Send>;
+
+/// Parameters extracted from a route path.
+///
+/// For the pattern `endpoint/:id` and the path `endpoint/42`,
+/// `params.get("id")` returns `Some("42")`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteParams {
+    params: Vec<(String, String)>,
+}
+
+impl RouteParams {
+    /// Get a parameter by name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get a parameter by name and parse it.
+    ///
+    /// Returns `None` if the parameter is missing or fails to parse.
+    pub fn parse<T: std::str::FromStr>(&self, name: &str) -> Option<T> {
+        self.get(name).and_then(|value| value.parse().ok())
+    }
+
+    /// Iterate over all parameters in pattern order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// A single segment of a route pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// Must match exactly.
+    Literal(String),
+    /// Matches any single segment and captures it under the given name.
+    Param(String),
+}
+
+/// A registered named route.
+struct Route {
+    segments: Vec<Segment>,
+    factory: RouteFactory,
+}
+
+impl Route {
+    fn new(pattern: &str, factory: RouteFactory) -> Self {
+        let segments = split_path(pattern)
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(name) => Segment::Param(name.to_string()),
+                None => Segment::Literal(segment.to_string()),
+            })
+            .collect();
+        Self { segments, factory }
+    }
+
+    /// Match a path against this route, returning the captured parameters.
+    fn matches(&self, path: &str) -> Option<RouteParams> {
+        let mut params = RouteParams::default();
+        let mut parts = split_path(path);
+
+        for segment in &self.segments {
+            let part = parts.next()?;
+            match segment {
+                Segment::Literal(literal) if literal == part => {}
+                Segment::Literal(_) => return None,
+                Segment::Param(name) => params.params.push((name.clone(), part.to_string())),
+            }
+        }
+
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(params)
+    }
+}
+
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|s| !s.is_empty())
+}
+
+/// An entry in the navigation history.
+struct HistoryEntry {
+    screen: BoxedScreen,
+    /// The route path this screen was created from, if any.
+    path: Option<String>,
+}
+
+/// A navigation request.
+///
+/// Requests issued from a `TabEventContext` are queued and applied by the
+/// framework once the handler returns.
+pub enum Navigation {
+    /// Push a screen on top of the stack.
+    Push(BoxedScreen),
+    /// Push a screen created from a named route.
+    PushRoute(String),
+    /// Replace the current screen.
+    Replace(BoxedScreen),
+    /// Replace the current screen with one created from a named route.
+    ReplaceRoute(String),
+    /// Remove the current screen, discarding it.
+    Pop,
+    /// Go back, keeping the current screen in the forward history.
+    Back,
+    /// Go forward to the most recent screen left with `Back`.
+    Forward,
+    /// Remove all screens and clear the history.
+    Clear,
+}
+
+/// Router managing the screen stack and history.
+///
+/// When the stack is empty, the application shows its tabs as usual.
+/// When a screen is active, it receives events instead of the active tab
+/// and the main UI can draw it via `DrawContext::navigate()`.
+///
+/// This is used internally by the framework to manage screens.
+pub struct Router {
+    routes: Vec<Route>,
+    stack: Vec<HistoryEntry>,
+    forward: Vec<HistoryEntry>,
+}
+
+impl Router {
+    /// Create a new router with no routes and no screens.
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            stack: Vec::new(),
+            forward: Vec::new(),
+        }
+    }
+
+    /// Register a named route.
+    ///
+    /// Pattern segments starting with `:` capture parameters,
+    /// e.g. `endpoint/:id`. Routes are matched in registration order.
+    pub fn route<F>(&mut self, pattern: &str, factory: F)
+    where
+        F: Fn(&RouteParams) -> BoxedScreen + Send + 'static,
+    {
+        self.routes.push(Route::new(pattern, Box::new(factory)));
+    }
+
+    /// Create a screen for the given path from the registered routes.
+    fn resolve(&self, path: &str) -> Option<BoxedScreen> {
+        self.routes
+            .iter()
+            .find_map(|route| route.matches(path).map(|params| (route.factory)(&params)))
+    }
+
+    /// Check if the given path matches a registered route.
+    pub fn has_route(&self, path: &str) -> bool {
+        self.routes
+            .iter()
+            .any(|route| route.matches(path).is_some())
+    }
+
+    /// Get the current screen, if any.
+    pub fn current(&self) -> Option<&dyn Screen> {
+        self.stack.last().map(|e| e.screen.as_ref())
+    }
+
+    /// Get a mutable reference to the current screen.
+    pub fn current_mut(&mut self) -> Option<&mut BoxedScreen> {
+        self.stack.last_mut().map(|e| &mut e.screen)
+    }
+
+    /// Get the route path of the current screen, if it was created from a route.
+    pub fn current_path(&self) -> Option<&str> {
+        self.stack.last().and_then(|e| e.path.as_deref())
+    }
+
+    /// Check if there is no active screen.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Get the number of screens on the stack.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Check if `back()` would change the current screen.
+    pub fn can_go_back(&self) -> bool {
+        !self.stack.is_empty()
+    }
+
+    /// Check if `forward()` would change the current screen.
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Titles of all screens on the stack, from the bottom up.
+    pub fn breadcrumbs(&self) -> Vec<&str> {
+        self.stack.iter().map(|e| e.screen.title()).collect()
+    }
+
+    fn deactivate_current(&mut self) {
+        if let Some(entry) = self.stack.last_mut() {
+            entry.screen.on_deactivate();
+        }
+    }
+
+    fn activate_current(&mut self) {
+        if let Some(entry) = self.stack.last_mut() {
+            entry.screen.on_activate();
+        }
+    }
+
+    fn push_entry(&mut self, entry: HistoryEntry) {
+        self.deactivate_current();
+        self.forward.clear();
+        self.stack.push(entry);
+        self.activate_current();
+    }
+
+    fn replace_entry(&mut self, entry: HistoryEntry) {
+        self.deactivate_current();
+        self.stack.pop();
+        self.stack.push(entry);
+        self.activate_current();
+    }
+
+    /// Push a screen on top of the stack.
+    ///
+    /// This clears the forward history.
+    pub fn push(&mut self, screen: BoxedScreen) {
+        self.push_entry(HistoryEntry { screen, path: None });
+    }
+
+    /// Push a screen created from a named route.
+    ///
+    /// Returns `false` if no route matches the path.
+    pub fn push_route(&mut self, path: &str) -> bool {
+        match self.resolve(path) {
+            Some(screen) => {
+                self.push_entry(HistoryEntry {
+                    screen,
+                    path: Some(path.to_string()),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the current screen (or push if the stack is empty).
+    pub fn replace(&mut self, screen: BoxedScreen) {
+        self.replace_entry(HistoryEntry { screen, path: None });
+    }
+
+    /// Replace the current screen with one created from a named route.
+    ///
+    /// Returns `false` if no route matches the path.
+    pub fn replace_route(&mut self, path: &str) -> bool {
+        match self.resolve(path) {
+            Some(screen) => {
+                self.replace_entry(HistoryEntry {
+                    screen,
+                    path: Some(path.to_string()),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove and discard the current screen.
+    ///
+    /// Returns `true` if a screen was removed.
+    pub fn pop(&mut self) -> bool {
+        if self.stack.is_empty() {
+            return false;
+        }
+        self.deactivate_current();
+        self.stack.pop();
+        self.activate_current();
+        true
+    }
+
+    /// Go back one screen, keeping the current one in the forward history.
+    ///
+    /// Returns `true` if the current screen changed.
+    pub fn back(&mut self) -> bool {
+        if self.stack.is_empty() {
+            return false;
+        }
+        self.deactivate_current();
+        if let Some(entry) = self.stack.pop() {
+            self.forward.push(entry);
+        }
+        self.activate_current();
+        true
+    }
+
+    /// Go forward to the screen most recently left with `back()`.
+    ///
+    /// Returns `true` if the current screen changed.
+    pub fn forward(&mut self) -> bool {
+        match self.forward.pop() {
+            Some(entry) => {
+                self.deactivate_current();
+                self.stack.push(entry);
+                self.activate_current();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove all screens and clear the history.
+    pub fn clear(&mut self) {
+        self.deactivate_current();
+        self.stack.clear();
+        self.forward.clear();
+    }
+
+    /// Apply a navigation request.
+    ///
+    /// Returns `false` if the request had no effect (e.g. unknown route).
+    pub fn apply(&mut self, navigation: Navigation) -> bool {
+        match navigation {
+            Navigation::Push(screen) => {
+                self.push(screen);
+                true
+            }
+            Navigation::PushRoute(path) => self.push_route(&path),
+            Navigation::Replace(screen) => {
+                self.replace(screen);
+                true
+            }
+            Navigation::ReplaceRoute(path) => self.replace_route(&path),
+            Navigation::Pop => self.pop(),
+            Navigation::Back => self.back(),
+            Navigation::Forward => self.forward(),
+            Navigation::Clear => {
+                self.clear();
+                true
+            }
+        }
+    }
+
+    /// Draw the current screen.
+    pub fn draw(&self, frame: &mut Frame, area: Rect) {
+        if let Some(screen) = self.current() {
+            screen.draw(frame, area);
+        }
+    }
+
+    /// Handle an event for the current screen.
+    pub fn handle_event(&mut self, event: &Event, ctx: &mut TabEventContext) -> EventResult {
+        if let Some(screen) = self.current_mut() {
+            screen.handle_event(event, ctx)
+        } else {
+            EventResult::Unhandled
+        }
+    }
+
+    /// Hand a timer to the current screen.
+    pub fn handle_timer(&mut self, timer: &Timer, ctx: &mut TabEventContext) -> EventResult {
+        if let Some(screen) = self.current_mut() {
+            screen.handle_timer(timer, ctx)
+        } else {
+            EventResult::Unhandled
+        }
+    }
+
+    /// Hand an effect result to the current screen.
+    pub fn handle_effect(
+        &mut self,
+        output: &mut EffectOutput,
+        ctx: &mut TabEventContext,
+    ) -> EventResult {
+        if let Some(screen) = self.current_mut() {
+            screen.handle_effect(output, ctx)
+        } else {
+            EventResult::Unhandled
+        }
+    }
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    struct TestScreen {
+        title: String,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl TestScreen {
+        fn boxed(title: &str, log: &Arc<Mutex<Vec<String>>>) -> BoxedScreen {
+            Box::new(Self {
+                title: title.to_string(),
+                log: log.clone(),
+            })
+        }
+    }
+
+    impl Screen for TestScreen {
+        fn title(&self) -> &str {
+            &self.title
+        }
+
+        fn draw(&self, _frame: &mut Frame, _area: Rect) {}
+
+        fn on_activate(&mut self) {
+            self.log.lock().unwrap().push(format!("+{}", self.title));
+        }
+
+        fn on_deactivate(&mut self) {
+            self.log.lock().unwrap().push(format!("-{}", self.title));
+        }
+    }
+
+    #[test]
+    fn test_route_params() {
+        let route = Route::new(
+            "endpoint/:id/cluster/:cluster",
+            Box::new(|_| unreachable!()),
+        );
+
+        let params = route.matches("endpoint/42/cluster/onoff").unwrap();
+        assert_eq!(params.get("id"), Some("42"));
+        assert_eq!(params.parse::<u32>("id"), Some(42));
+        assert_eq!(params.get("cluster"), Some("onoff"));
+
+        assert!(route.matches("endpoint/42").is_none());
+        assert!(route.matches("node/42/cluster/onoff").is_none());
+        assert!(route.matches("endpoint/42/cluster/onoff/extra").is_none());
+    }
+
+    #[test]
+    fn test_router_push_route() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut router = Router::new();
+        let route_log = log.clone();
+        router.route("endpoint/:id", move |params| {
+            TestScreen::boxed(params.get("id").unwrap(), &route_log)
+        });
+
+        assert!(router.push_route("endpoint/7"));
+        assert_eq!(router.current().map(|s| s.title()), Some("7"));
+        assert_eq!(router.current_path(), Some("endpoint/7"));
+        assert!(!router.push_route("unknown/7"));
+        assert_eq!(router.depth(), 1);
+    }
+
+    #[test]
+    fn test_router_history() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut router = Router::new();
+
+        router.push(TestScreen::boxed("list", &log));
+        router.push(TestScreen::boxed("detail", &log));
+        assert_eq!(router.breadcrumbs(), vec!["list", "detail"]);
+
+        assert!(router.back());
+        assert_eq!(router.current().map(|s| s.title()), Some("list"));
+        assert!(router.can_go_forward());
+
+        assert!(router.forward());
+        assert_eq!(router.current().map(|s| s.title()), Some("detail"));
+
+        router.replace(TestScreen::boxed("other", &log));
+        assert_eq!(router.breadcrumbs(), vec!["list", "other"]);
+
+        router.back();
+        router.push(TestScreen::boxed("sub", &log));
+        assert!(!router.can_go_forward());
+
+        assert!(router.pop());
+        assert!(router.pop());
+        assert!(router.is_empty());
+        assert!(!router.pop());
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "+list", "-list", "+detail", "-detail", "+list", "-list", "+detail", "-detail",
+                "+other", "-other", "+list", "-list", "+sub", "-sub", "+list", "-list",
+            ]
+        );
+    }
+}
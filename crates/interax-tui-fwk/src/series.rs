@@ -0,0 +1,392 @@
+//! Time-series buffers for charting streamed values.
+//!
+//! A `TimeSeries` is a shared, bounded buffer of timestamped samples.
+//! Background tasks push samples into a clone while the UI renders another
+//! clone with the chart widgets in `widgets::chart`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use interax_tui_fwk::{Aggregation, TimeSeries};
+//!
+//! let latency = TimeSeries::new(4096).retention(Duration::from_secs(300));
+//!
+//! // In a task:
+//! let series = latency.clone();
+//! series.push(12.5);
+//!
+//! // While drawing: mean latency per bucket over the last minute
+//! let buckets = latency.downsample(Duration::from_secs(60), 60, Aggregation::Mean);
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// A single timestamped value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// When the sample was taken.
+    pub time: Instant,
+    /// The sampled value.
+    pub value: f64,
+}
+
+/// How samples falling into the same bucket are combined when downsampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aggregation {
+    /// Average of the samples.
+    #[default]
+    Mean,
+    /// Smallest sample.
+    Min,
+    /// Largest sample.
+    Max,
+    /// Most recent sample.
+    Last,
+    /// Sum of the samples (e.g. bytes per bucket).
+    Sum,
+    /// Number of samples (e.g. messages per bucket).
+    Count,
+}
+
+impl Aggregation {
+    fn apply(self, values: &[f64]) -> Option<f64> {
+        if values.is_empty() {
+            return match self {
+                Aggregation::Count | Aggregation::Sum => Some(0.0),
+                _ => None,
+            };
+        }
+        Some(match self {
+            Aggregation::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            Aggregation::Last => values[values.len() - 1],
+            Aggregation::Sum => values.iter().sum(),
+            Aggregation::Count => values.len() as f64,
+        })
+    }
+}
+
+/// Summary of the samples in a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesStats {
+    /// Number of samples.
+    pub count: usize,
+    /// Smallest value.
+    pub min: f64,
+    /// Largest value.
+    pub max: f64,
+    /// Average value.
+    pub mean: f64,
+}
+
+/// Distribution of the samples in a window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Lower bound of the first bin.
+    pub min: f64,
+    /// Upper bound of the last bin.
+    pub max: f64,
+    /// Number of samples per bin.
+    pub counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Width of each bin.
+    pub fn bin_width(&self) -> f64 {
+        if self.counts.is_empty() {
+            0.0
+        } else {
+            (self.max - self.min) / self.counts.len() as f64
+        }
+    }
+}
+
+struct SeriesData {
+    samples: VecDeque<Sample>,
+    capacity: usize,
+    retention: Option<Duration>,
+}
+
+impl SeriesData {
+    /// Drop samples older than the retention window ending at `now`.
+    fn prune(&mut self, now: Instant) {
+        let Some(retention) = self.retention else {
+            return;
+        };
+        let Some(cutoff) = now.checked_sub(retention) else {
+            return;
+        };
+        while self.samples.front().is_some_and(|s| s.time < cutoff) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Samples taken in `window` before `now`, oldest first.
+    fn window(&self, now: Instant, window: Duration) -> impl Iterator<Item = &Sample> {
+        let cutoff = now.checked_sub(window);
+        self.samples
+            .iter()
+            .filter(move |s| cutoff.is_none_or(|cutoff| s.time >= cutoff) && s.time <= now)
+    }
+}
+
+/// A shared, bounded buffer of timestamped samples.
+///
+/// Cloning is cheap and every clone shares the same samples, so a task can
+/// push into one clone while a chart renders another. The buffer keeps at
+/// most `capacity` samples and, with a retention window, drops samples
+/// older than that window.
+#[derive(Clone)]
+pub struct TimeSeries {
+    data: Arc<Mutex<SeriesData>>,
+}
+
+impl TimeSeries {
+    /// Create a series holding at most `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            data: Arc::new(Mutex::new(SeriesData {
+                samples: VecDeque::with_capacity(capacity.min(1024)),
+                capacity,
+                retention: None,
+            })),
+        }
+    }
+
+    /// Drop samples older than `retention`.
+    pub fn retention(self, retention: Duration) -> Self {
+        self.lock().retention = Some(retention);
+        self
+    }
+
+    /// Push a sample taken now.
+    pub fn push(&self, value: f64) {
+        self.push_at(Instant::now(), value);
+    }
+
+    /// Push a sample taken at `time`.
+    ///
+    /// Samples are expected in time order.
+    pub fn push_at(&self, time: Instant, value: f64) {
+        let mut data = self.lock();
+        if data.samples.len() == data.capacity {
+            data.samples.pop_front();
+        }
+        data.samples.push_back(Sample { time, value });
+        data.prune(time);
+    }
+
+    /// Number of buffered samples.
+    pub fn len(&self) -> usize {
+        self.lock().samples.len()
+    }
+
+    /// Check if the series has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.lock().samples.is_empty()
+    }
+
+    /// Remove all samples.
+    pub fn clear(&self) {
+        self.lock().samples.clear();
+    }
+
+    /// The most recent sample.
+    pub fn latest(&self) -> Option<Sample> {
+        self.lock().samples.back().copied()
+    }
+
+    /// Copy the buffered samples, oldest first.
+    pub fn samples(&self) -> Vec<Sample> {
+        self.lock().samples.iter().copied().collect()
+    }
+
+    /// Summary of the samples in the last `window`.
+    pub fn stats(&self, window: Duration) -> Option<SeriesStats> {
+        self.stats_at(Instant::now(), window)
+    }
+
+    /// Summary of the samples in the `window` ending at `now`.
+    pub fn stats_at(&self, now: Instant, window: Duration) -> Option<SeriesStats> {
+        let data = self.lock();
+        let mut stats: Option<SeriesStats> = None;
+        let mut sum = 0.0;
+        for sample in data.window(now, window) {
+            sum += sample.value;
+            let stats = stats.get_or_insert(SeriesStats {
+                count: 0,
+                min: sample.value,
+                max: sample.value,
+                mean: 0.0,
+            });
+            stats.count += 1;
+            stats.min = stats.min.min(sample.value);
+            stats.max = stats.max.max(sample.value);
+        }
+        stats.map(|stats| SeriesStats {
+            mean: sum / stats.count as f64,
+            ..stats
+        })
+    }
+
+    /// Combine the samples of the last `window` into `buckets` equal
+    /// time slices, oldest first.
+    ///
+    /// Empty buckets are `None`, except for `Sum` and `Count` where they
+    /// are zero.
+    pub fn downsample(
+        &self,
+        window: Duration,
+        buckets: usize,
+        aggregation: Aggregation,
+    ) -> Vec<Option<f64>> {
+        self.downsample_at(Instant::now(), window, buckets, aggregation)
+    }
+
+    /// Like `downsample`, for the `window` ending at `now`.
+    pub fn downsample_at(
+        &self,
+        now: Instant,
+        window: Duration,
+        buckets: usize,
+        aggregation: Aggregation,
+    ) -> Vec<Option<f64>> {
+        if buckets == 0 {
+            return Vec::new();
+        }
+        let mut values: Vec<Vec<f64>> = vec![Vec::new(); buckets];
+        let window_secs = window.as_secs_f64();
+        let data = self.lock();
+        for sample in data.window(now, window) {
+            let age = now.duration_since(sample.time).as_secs_f64();
+            // Age 0 is the newest bucket, `window` the oldest
+            let from_end = if window_secs > 0.0 {
+                ((age / window_secs) * buckets as f64) as usize
+            } else {
+                0
+            };
+            let index = buckets - 1 - from_end.min(buckets - 1);
+            values[index].push(sample.value);
+        }
+        values.iter().map(|v| aggregation.apply(v)).collect()
+    }
+
+    /// Distribution of the samples of the last `window` over `bins`
+    /// equal-width bins spanning their range.
+    pub fn histogram(&self, window: Duration, bins: usize) -> Histogram {
+        self.histogram_at(Instant::now(), window, bins)
+    }
+
+    /// Like `histogram`, for the `window` ending at `now`.
+    pub fn histogram_at(&self, now: Instant, window: Duration, bins: usize) -> Histogram {
+        let Some(stats) = self.stats_at(now, window) else {
+            return Histogram {
+                min: 0.0,
+                max: 0.0,
+                counts: vec![0; bins],
+            };
+        };
+        let mut counts = vec![0; bins];
+        if bins > 0 {
+            let span = stats.max - stats.min;
+            let data = self.lock();
+            for sample in data.window(now, window) {
+                let index = if span > 0.0 {
+                    (((sample.value - stats.min) / span) * bins as f64) as usize
+                } else {
+                    0
+                };
+                counts[index.min(bins - 1)] += 1;
+            }
+        }
+        Histogram {
+            min: stats.min,
+            max: stats.max,
+            counts,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SeriesData> {
+        self.data.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for TimeSeries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.lock();
+        f.debug_struct("TimeSeries")
+            .field("len", &data.samples.len())
+            .field("capacity", &data.capacity)
+            .field("retention", &data.retention)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    #[test]
+    fn test_retention_and_capacity() {
+        let start = Instant::now();
+        let series = TimeSeries::new(5).retention(secs(10));
+        for i in 0..8 {
+            series.push_at(start + secs(i), i as f64);
+        }
+        // Capacity keeps the last five
+        assert_eq!(series.len(), 5);
+        assert_eq!(series.samples()[0].value, 3.0);
+
+        // Retention drops everything older than 10 seconds
+        series.push_at(start + secs(15), 15.0);
+        let values: Vec<f64> = series.samples().iter().map(|s| s.value).collect();
+        assert_eq!(values, vec![5.0, 6.0, 7.0, 15.0]);
+    }
+
+    #[test]
+    fn test_downsample() {
+        let start = Instant::now();
+        let series = TimeSeries::new(100);
+        for i in 0..10 {
+            series.push_at(start + secs(i), i as f64);
+        }
+        let now = start + secs(9);
+
+        let means = series.downsample_at(now, secs(10), 5, Aggregation::Mean);
+        assert_eq!(
+            means,
+            vec![Some(0.5), Some(2.5), Some(4.5), Some(6.5), Some(8.5)]
+        );
+
+        let counts = series.downsample_at(now + secs(20), secs(10), 2, Aggregation::Count);
+        assert_eq!(counts, vec![Some(0.0), Some(0.0)]);
+
+        let stats = series.stats_at(now, secs(100)).unwrap();
+        assert_eq!((stats.count, stats.min, stats.max), (10, 0.0, 9.0));
+        assert_eq!(stats.mean, 4.5);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let start = Instant::now();
+        let series = TimeSeries::new(100);
+        for value in [1.0, 2.0, 2.5, 9.0, 10.0] {
+            series.push_at(start, value);
+        }
+        let histogram = series.histogram_at(start, secs(1), 3);
+        assert_eq!(histogram.min, 1.0);
+        assert_eq!(histogram.max, 10.0);
+        assert_eq!(histogram.counts, vec![3, 0, 2]);
+        assert_eq!(histogram.bin_width(), 3.0);
+    }
+}
@@ -0,0 +1,278 @@
+//! Serving one application to several remote terminals.
+//!
+//! An `AppServer` listens on a Unix socket and builds a fresh `App` for
+//! every client that attaches, so each operator gets independent tabs,
+//! focus, screens and toasts. Tasks added to the server run once and their
+//! messages are delivered to every attached app; tasks added to the
+//! per-connection `AppBuilder` run once per client.
+//!
+//! Clients attach with `attach`, which puts the local terminal in raw mode,
+//! forwards its events to the server and writes the rendered output back.
+//!
+//! # Protocol
+//!
+//! The client sends one JSON-encoded `Event` per line, starting with a
+//! `Resize` carrying its size. The server sends raw terminal output. The
+//! connection closes when the client's app quits.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{attach, AppBuilder, AppServer};
+//!
+//! // The monitor
+//! let server = AppServer::new(|| AppBuilder::new().main_ui(Monitor::new()))
+//!     .add_task("controller", ControllerTask::new());
+//! server.serve_unix("/run/monitor.sock").await?;
+//!
+//! // An operator
+//! attach("/run/monitor.sock").await?;
+//! ```
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use crossterm::event::EventStream;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use futures::{stream, StreamExt};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::unix::OwnedReadHalf;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{mpsc, watch};
+
+use crate::app::{App, AppBuilder, AppError, PendingTask};
+use crate::bus::{MessageBus, TaskMessage, TaskSender};
+use crate::component::MainUi;
+use crate::context::Services;
+use crate::event::Event;
+use crate::task::{BoxedTaskFuture, Task, TaskContext, TaskFactory};
+
+/// Size assumed for a client that does not report its size first.
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
+/// Serves an application to remote terminals over a Unix socket.
+pub struct AppServer<M: MainUi> {
+    factory: Box<dyn Fn() -> AppBuilder<M> + Send>,
+    tasks: Vec<PendingTask>,
+    bus: MessageBus,
+}
+
+impl<M: MainUi + 'static> AppServer<M> {
+    /// Create a server building the app of every client with `factory`.
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn() -> AppBuilder<M> + Send + 'static,
+    {
+        Self {
+            factory: Box::new(factory),
+            tasks: Vec::new(),
+            bus: MessageBus::new(),
+        }
+    }
+
+    /// Add a task shared by all clients.
+    ///
+    /// The task runs while the server runs, and every attached app gets
+    /// each of its messages in `MainUi::handle_task_message`. Toasts
+    /// and status bar updates from its context are not shown to anyone;
+    /// report through messages instead.
+    pub fn add_task<T: Task>(mut self, name: &'static str, task: T) -> Self {
+        let sender: TaskSender<T::Message> = self.bus.register(name);
+        let factory: TaskFactory = Box::new(move |ctx: TaskContext| {
+            Box::pin(async move {
+                task.run(sender, ctx).await;
+            }) as BoxedTaskFuture
+        });
+        self.tasks.push(PendingTask { name, factory });
+        self
+    }
+
+    /// Listen on a Unix socket at `path` and serve clients until an error
+    /// occurs.
+    ///
+    /// A stale socket file left by a previous server is replaced.
+    pub async fn serve_unix(self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if path.exists() {
+            if UnixStream::connect(path).await.is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    "another server is listening on the socket",
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        self.serve(UnixListener::bind(path)?).await
+    }
+
+    /// Serve clients connecting to `listener` until an error occurs.
+    ///
+    /// Dropping the returned future cancels the shared tasks.
+    pub async fn serve(mut self, listener: UnixListener) -> io::Result<()> {
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+        for pending in self.tasks.drain(..) {
+            let ctx = TaskContext::new(cancel_rx.clone(), Services::default());
+            tokio::spawn((pending.factory)(ctx));
+        }
+
+        // Keep the tasks' senders open while no client is attached
+        let mut unattached = self.bus.take_receiver().expect("receiver already taken");
+        tokio::spawn(async move { while unattached.recv().await.is_some() {} });
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let app = (self.factory)().build().map_err(io::Error::other)?;
+            tokio::spawn(forward_messages(self.bus.subscribe(), app.message_sender()));
+            tokio::spawn(async move {
+                // A failing client only ends its own connection
+                let _ = serve_connection(app, stream).await;
+            });
+        }
+    }
+}
+
+/// Forward the shared task messages to one attached app until it quits.
+async fn forward_messages(mut shared: mpsc::Receiver<TaskMessage>, app: mpsc::Sender<TaskMessage>) {
+    while let Some(message) = shared.recv().await {
+        if app.send(message).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Run `app` for the client on `stream`.
+async fn serve_connection<M: MainUi + 'static>(
+    app: App<M>,
+    stream: UnixStream,
+) -> Result<(), AppError> {
+    let (read, mut write) = stream.into_split();
+    let mut lines = BufReader::new(read).lines();
+    let size = match read_event(&mut lines).await? {
+        Some(Event::Resize { width, height }) => (width, height),
+        _ => DEFAULT_SIZE,
+    };
+
+    let (output_tx, mut output_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+    tokio::spawn(async move {
+        while let Some(bytes) = output_rx.recv().await {
+            if write.write_all(&bytes).await.is_err() {
+                break;
+            }
+        }
+    });
+    let output = RemoteOutput {
+        buffer: Vec::new(),
+        tx: output_tx,
+    };
+
+    let events = Box::pin(stream::unfold(lines, |mut lines| async move {
+        let event = read_event(&mut lines).await.transpose()?;
+        Some((event, lines))
+    }));
+    app.run_remote(Box::new(output), size, events).await
+}
+
+/// Read the next event sent by a client, skipping malformed lines.
+///
+/// Returns `None` when the client disconnects.
+async fn read_event(lines: &mut Lines<BufReader<OwnedReadHalf>>) -> io::Result<Option<Event>> {
+    while let Some(line) = lines.next_line().await? {
+        if let Ok(event) = serde_json::from_str(&line) {
+            return Ok(Some(event));
+        }
+    }
+    Ok(None)
+}
+
+/// Terminal output buffered until flushed, then handed to the task
+/// writing to the client's socket.
+struct RemoteOutput {
+    buffer: Vec<u8>,
+    tx: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl Write for RemoteOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.tx
+            .send(std::mem::take(&mut self.buffer))
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+}
+
+/// Attach the local terminal to the server listening at `path`.
+///
+/// Returns when the remote app quits or the connection drops.
+pub async fn attach(path: impl AsRef<Path>) -> io::Result<()> {
+    let stream = UnixStream::connect(path).await?;
+    enable_raw_mode()?;
+    let result = forward(stream).await;
+    disable_raw_mode()?;
+    result
+}
+
+/// Forward local events to the server and its output to stdout.
+async fn forward(stream: UnixStream) -> io::Result<()> {
+    let (mut read, mut write) = stream.into_split();
+    let (width, height) = crossterm::terminal::size()?;
+    send_event(&mut write, &Event::Resize { width, height }).await?;
+
+    let mut events = EventStream::new();
+    let mut buffer = vec![0; 8192];
+    let mut stdout = io::stdout();
+    loop {
+        tokio::select! {
+            read = read.read(&mut buffer) => {
+                let len = read?;
+                if len == 0 {
+                    return Ok(());
+                }
+                stdout.write_all(&buffer[..len])?;
+                stdout.flush()?;
+            }
+            event = events.next() => match event {
+                Some(event) => send_event(&mut write, &Event::from(event?)).await?,
+                None => return Ok(()),
+            },
+        }
+    }
+}
+
+/// Send one event to the server.
+async fn send_event(write: &mut (impl AsyncWriteExt + Unpin), event: &Event) -> io::Result<()> {
+    let mut line = serde_json::to_string(event).map_err(io::Error::other)?;
+    line.push('\n');
+    write.write_all(line.as_bytes()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_output_sends_on_flush() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut output = RemoteOutput {
+            buffer: Vec::new(),
+            tx,
+        };
+        output.write_all(b"\x1b[1;1H").unwrap();
+        output.write_all(b"ok").unwrap();
+        assert!(rx.try_recv().is_err());
+        output.flush().unwrap();
+        output.flush().unwrap();
+        assert_eq!(rx.try_recv().unwrap(), b"\x1b[1;1Hok");
+        assert!(rx.try_recv().is_err());
+
+        drop(rx);
+        output.write_all(b"x").unwrap();
+        assert!(output.flush().is_err());
+    }
+}
@@ -0,0 +1,236 @@
+//! Event loop instrumentation.
+//!
+//! The app measures how long it takes to draw frames and handle events,
+//! how long it sleeps between wake-ups, how often it redraws and how many
+//! messages each task has queued. The numbers are available while drawing
+//! via `DrawContext::stats()`, and a debug overlay showing them live can be
+//! toggled with a key set by `AppBuilder::debug_overlay_key`.
+//!
+//! An idle app should stay close to 100% idle and redraw only when
+//! something changes.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppBuilder, KeyCode, KeyModifiers};
+//!
+//! let app = AppBuilder::new()
+//!     .main_ui(MyApp::new())
+//!     .debug_overlay_key(KeyCode::F(12), KeyModifiers::NONE)
+//!     .build()?;
+//!
+//! // In a draw method
+//! let stats = ctx.stats();
+//! status.set_right("fps", format!("{:.1} redraws/s", stats.redraw_rate()));
+//! ```
+
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::theme::Theme;
+
+/// Width of the debug overlay, borders included.
+const OVERLAY_WIDTH: u16 = 34;
+
+/// Measurements of the event loop.
+///
+/// Durations of the frame being drawn are not known yet, so while drawing
+/// `frame_time` is the one of the previous frame.
+#[derive(Debug, Clone)]
+pub struct FrameStats {
+    /// Time taken to draw the last frame.
+    pub frame_time: Duration,
+    /// Longest time taken to draw a frame.
+    pub max_frame_time: Duration,
+    /// Time taken to handle the last event, task message or tick, from
+    /// wake-up until the redraw.
+    pub event_latency: Duration,
+    /// Longest time taken to handle an event, task message or tick.
+    pub max_event_latency: Duration,
+    /// Number of frames drawn.
+    pub redraws: u64,
+    /// Number of times the event loop woke up.
+    pub wakeups: u64,
+    /// Total time spent waiting for something to happen.
+    pub idle_time: Duration,
+    /// Messages each task has queued and the UI has not handled yet,
+    /// sorted by task name.
+    pub queue_depths: Vec<(&'static str, usize)>,
+    /// Number of effects spawned by handlers and still running.
+    pub running_effects: usize,
+    /// Number of timers set by handlers and not fired or cancelled yet.
+    pub scheduled_timers: usize,
+    started: Instant,
+}
+
+impl FrameStats {
+    /// Start measuring now.
+    pub(crate) fn new() -> Self {
+        Self {
+            frame_time: Duration::ZERO,
+            max_frame_time: Duration::ZERO,
+            event_latency: Duration::ZERO,
+            max_event_latency: Duration::ZERO,
+            redraws: 0,
+            wakeups: 0,
+            idle_time: Duration::ZERO,
+            queue_depths: Vec::new(),
+            running_effects: 0,
+            scheduled_timers: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Time since the event loop started.
+    pub fn uptime(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Fraction of the uptime spent waiting, from 0 to 1.
+    pub fn idle_ratio(&self) -> f64 {
+        let uptime = self.uptime().as_secs_f64();
+        if uptime == 0.0 {
+            return 1.0;
+        }
+        (self.idle_time.as_secs_f64() / uptime).min(1.0)
+    }
+
+    /// Average number of frames drawn per second.
+    pub fn redraw_rate(&self) -> f64 {
+        let uptime = self.uptime().as_secs_f64();
+        if uptime == 0.0 {
+            return 0.0;
+        }
+        self.redraws as f64 / uptime
+    }
+
+    /// Total number of queued task messages.
+    pub fn queued_messages(&self) -> usize {
+        self.queue_depths.iter().map(|(_, depth)| depth).sum()
+    }
+
+    /// Record a wake-up after waiting for `idle`.
+    pub(crate) fn record_wakeup(&mut self, idle: Duration) {
+        self.wakeups += 1;
+        self.idle_time += idle;
+    }
+
+    /// Record handling a wake-up in `latency`.
+    pub(crate) fn record_event(&mut self, latency: Duration) {
+        self.event_latency = latency;
+        self.max_event_latency = self.max_event_latency.max(latency);
+    }
+
+    /// Record drawing a frame in `time`.
+    pub(crate) fn record_frame(&mut self, time: Duration) {
+        self.redraws += 1;
+        self.frame_time = time;
+        self.max_frame_time = self.max_frame_time.max(time);
+    }
+
+    /// Draw the debug overlay in the top right corner of `area`.
+    pub(crate) fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let row = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{:<10}", label), theme.muted),
+                Span::styled(value, theme.text),
+            ])
+        };
+        let mut lines = vec![
+            row(
+                "frame",
+                format!(
+                    "{} (max {})",
+                    format_duration(self.frame_time),
+                    format_duration(self.max_frame_time)
+                ),
+            ),
+            row(
+                "handling",
+                format!(
+                    "{} (max {})",
+                    format_duration(self.event_latency),
+                    format_duration(self.max_event_latency)
+                ),
+            ),
+            row(
+                "redraws",
+                format!("{} ({:.1}/s)", self.redraws, self.redraw_rate()),
+            ),
+            row("wake-ups", self.wakeups.to_string()),
+            row("idle", format!("{:.1}%", self.idle_ratio() * 100.0)),
+            row("effects", self.running_effects.to_string()),
+            row("timers", self.scheduled_timers.to_string()),
+        ];
+        for (task, depth) in &self.queue_depths {
+            lines.push(row(task, format!("{} queued", depth)));
+        }
+
+        let width = OVERLAY_WIDTH.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        if width < 4 || height < 3 {
+            return;
+        }
+        let rect = Rect {
+            x: area.right() - width,
+            y: area.y,
+            width,
+            height,
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.accent)
+            .title(Span::styled(" Frame stats ", theme.accent));
+        frame.render_widget(Clear, rect);
+        frame.render_widget(Paragraph::new(lines).block(block), rect);
+    }
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Format `duration` with a unit suited to its size.
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1000 {
+        format!("{}µs", micros)
+    } else {
+        format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_loop_activity() {
+        let mut stats = FrameStats::new();
+        stats.record_wakeup(Duration::from_millis(5));
+        stats.record_event(Duration::from_micros(300));
+        stats.record_frame(Duration::from_millis(2));
+        stats.record_event(Duration::from_micros(100));
+        stats.record_frame(Duration::from_millis(1));
+        stats.queue_depths = vec![("controller", 3), ("poller", 1)];
+
+        assert_eq!(stats.redraws, 2);
+        assert_eq!(stats.wakeups, 1);
+        assert_eq!(stats.frame_time, Duration::from_millis(1));
+        assert_eq!(stats.max_frame_time, Duration::from_millis(2));
+        assert_eq!(stats.max_event_latency, Duration::from_micros(300));
+        assert_eq!(stats.queued_messages(), 4);
+        assert!(stats.idle_ratio() <= 1.0);
+
+        assert_eq!(format_duration(Duration::from_micros(300)), "300µs");
+        assert_eq!(format_duration(Duration::from_micros(2500)), "2.5ms");
+    }
+}
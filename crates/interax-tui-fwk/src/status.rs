@@ -0,0 +1,168 @@
+//! Persistent status bar for the TUI framework.
+//!
+//! When enabled with `AppBuilder::status_bar(true)`, the framework reserves
+//! the bottom row of the terminal for a `StatusBar`. Components update its
+//! left and right segments by key through `AppContext::status_bar()`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! ctx.status_bar().set_left("conn", "● connected");
+//! ctx.status_bar().set_right("nodes", format!("{} nodes", nodes.len()));
+//! ctx.status_bar().remove("conn");
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::theme::Theme;
+
+/// Separator drawn between segments.
+const SEPARATOR: &str = " │ ";
+
+/// Which side of the bar a segment is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+struct Segment {
+    key: String,
+    side: Side,
+    text: String,
+    style: Option<Style>,
+}
+
+/// A one-row bar of keyed text segments.
+///
+/// Cloning is cheap and every clone shares the same segments, so a clone
+/// can be handed to a background task. Segments keep the order in which
+/// they were first set.
+#[derive(Clone, Default)]
+pub struct StatusBar {
+    segments: Arc<Mutex<Vec<Segment>>>,
+}
+
+impl StatusBar {
+    /// Create an empty status bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text of a left-aligned segment.
+    pub fn set_left(&self, key: &str, text: impl Into<String>) {
+        self.set(key, Side::Left, text.into(), None);
+    }
+
+    /// Set the text of a right-aligned segment.
+    pub fn set_right(&self, key: &str, text: impl Into<String>) {
+        self.set(key, Side::Right, text.into(), None);
+    }
+
+    /// Set the text and style of a left-aligned segment.
+    pub fn set_left_styled(&self, key: &str, text: impl Into<String>, style: Style) {
+        self.set(key, Side::Left, text.into(), Some(style));
+    }
+
+    /// Set the text and style of a right-aligned segment.
+    pub fn set_right_styled(&self, key: &str, text: impl Into<String>, style: Style) {
+        self.set(key, Side::Right, text.into(), Some(style));
+    }
+
+    /// Get the text of a segment.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.lock()
+            .iter()
+            .find(|segment| segment.key == key)
+            .map(|segment| segment.text.clone())
+    }
+
+    /// Remove a segment.
+    ///
+    /// Returns `true` if the segment existed.
+    pub fn remove(&self, key: &str) -> bool {
+        let mut segments = self.lock();
+        let len = segments.len();
+        segments.retain(|segment| segment.key != key);
+        segments.len() != len
+    }
+
+    /// Remove all segments.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn set(&self, key: &str, side: Side, text: String, style: Option<Style>) {
+        let mut segments = self.lock();
+        if let Some(segment) = segments.iter_mut().find(|segment| segment.key == key) {
+            segment.side = side;
+            segment.text = text;
+            segment.style = style;
+        } else {
+            segments.push(Segment {
+                key: key.to_string(),
+                side,
+                text,
+                style,
+            });
+        }
+    }
+
+    /// Render the bar into `area` (one row).
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let segments = self.lock();
+        let side = |side: Side| -> (Vec<Span<'static>>, usize) {
+            let mut spans = Vec::new();
+            let mut width = 0;
+            for segment in segments.iter().filter(|segment| segment.side == side) {
+                if !spans.is_empty() {
+                    spans.push(Span::styled(SEPARATOR, theme.muted));
+                    width += SEPARATOR.chars().count();
+                }
+                width += segment.text.chars().count();
+                let style = segment.style.unwrap_or_default();
+                spans.push(Span::styled(segment.text.clone(), style));
+            }
+            (spans, width)
+        };
+        let (mut left, left_width) = side(Side::Left);
+        let (right, right_width) = side(Side::Right);
+
+        // Pad between the sides; the right side is dropped if it doesn't fit
+        let total = area.width as usize;
+        if left_width + right_width + 2 <= total {
+            left.insert(0, Span::raw(" "));
+            left.push(Span::raw(" ".repeat(total - left_width - right_width - 2)));
+            left.extend(right);
+            left.push(Span::raw(" "));
+        } else {
+            left.insert(0, Span::raw(" "));
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(left)).style(theme.status_bar),
+            area,
+        );
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Segment>> {
+        self.segments.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for StatusBar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segments = self.lock();
+        f.debug_map()
+            .entries(segments.iter().map(|segment| (&segment.key, &segment.text)))
+            .finish()
+    }
+}
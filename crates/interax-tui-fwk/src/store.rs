@@ -0,0 +1,376 @@
+//! Reactive shared state for the TUI framework.
+//!
+//! A `Store` holds one value ("slice") per type. Tasks and event handlers
+//! write slices, and components read them through a `Signal` (the whole
+//! slice) or a `Selector` (a value derived from a slice). Both remember
+//! what they last saw, so a component only does work when its own inputs
+//! changed rather than on every message.
+//!
+//! Writing a slice wakes the event loop and redraws the UI, so tasks no
+//! longer need to send messages that `MainUi::handle_task_message` pushes
+//! into children.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::{AppBuilder, Selector, Signal, Store};
+//!
+//! #[derive(Default)]
+//! struct Nodes(Vec<Node>);
+//!
+//! // In a task
+//! ctx.store().update(|nodes: &mut Nodes| nodes.0.push(node));
+//!
+//! // In a component
+//! struct NodeCount {
+//!     count: Selector<Nodes, usize>,
+//! }
+//!
+//! impl NodeCount {
+//!     fn new(store: &Store) -> Self {
+//!         Self { count: store.select(|nodes: &Nodes| nodes.0.len()) }
+//!     }
+//! }
+//!
+//! impl Component for NodeCount {
+//!     fn draw(&self, frame: &mut Frame, area: Rect, _ctx: &DrawContext) {
+//!         let count = self.count.get().unwrap_or(0);
+//!         // ...
+//!     }
+//! }
+//!
+//! let store = Store::new();
+//! let app = AppBuilder::new()
+//!     .main_ui(NodeCount::new(&store))
+//!     .store(store)
+//!     .build()?;
+//! ```
+
+use std::any::{type_name, Any, TypeId};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tokio::sync::Notify;
+
+struct Slice {
+    value: Box<dyn Any + Send>,
+    version: u64,
+}
+
+struct Slices {
+    slices: HashMap<TypeId, Slice>,
+    next_version: u64,
+}
+
+struct Shared {
+    slices: Mutex<Slices>,
+    notify: Notify,
+}
+
+/// A shared map of typed state slices.
+///
+/// Cloning is cheap and every clone shares the same slices. Every write
+/// gives the slice a new version and wakes the event loop.
+#[derive(Clone)]
+pub struct Store {
+    shared: Arc<Shared>,
+}
+
+impl Store {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                slices: Mutex::new(Slices {
+                    slices: HashMap::new(),
+                    next_version: 1,
+                }),
+                notify: Notify::new(),
+            }),
+        }
+    }
+
+    /// Replace the slice of type `T`.
+    pub fn set<T: Any + Send>(&self, value: T) {
+        {
+            let mut slices = self.lock();
+            let version = slices.bump();
+            slices.slices.insert(
+                TypeId::of::<T>(),
+                Slice {
+                    value: Box::new(value),
+                    version,
+                },
+            );
+        }
+        self.shared.notify.notify_one();
+    }
+
+    /// Modify the slice of type `T`, starting from `T::default()` if it is
+    /// not set yet.
+    pub fn update<T: Any + Send + Default, R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = {
+            let mut slices = self.lock();
+            let version = slices.bump();
+            let slice = slices
+                .slices
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Slice {
+                    value: Box::new(T::default()),
+                    version,
+                });
+            slice.version = version;
+            f(slice
+                .value
+                .downcast_mut()
+                .expect("slice has the wrong type"))
+        };
+        self.shared.notify.notify_one();
+        result
+    }
+
+    /// Remove the slice of type `T`.
+    ///
+    /// Returns the removed value.
+    pub fn remove<T: Any + Send>(&self) -> Option<T> {
+        let removed = self.lock().slices.remove(&TypeId::of::<T>())?;
+        self.shared.notify.notify_one();
+        removed.value.downcast().ok().map(|value| *value)
+    }
+
+    /// Copy the slice of type `T`.
+    pub fn get<T: Any + Send + Clone>(&self) -> Option<T> {
+        self.with(T::clone)
+    }
+
+    /// Read the slice of type `T` without copying it.
+    pub fn with<T: Any + Send, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let slices = self.lock();
+        let slice = slices.slices.get(&TypeId::of::<T>())?;
+        slice.value.downcast_ref().map(f)
+    }
+
+    /// Check if the slice of type `T` is set.
+    pub fn contains<T: Any + Send>(&self) -> bool {
+        self.lock().slices.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Version of the slice of type `T`, or 0 if it is not set.
+    ///
+    /// The version changes on every write.
+    pub fn version<T: Any + Send>(&self) -> u64 {
+        self.lock()
+            .slices
+            .get(&TypeId::of::<T>())
+            .map_or(0, |slice| slice.version)
+    }
+
+    /// Subscribe to the slice of type `T`.
+    pub fn signal<T: Any + Send>(&self) -> Signal<T> {
+        Signal {
+            store: self.clone(),
+            seen: Cell::new(0),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Subscribe to a value derived from the slice of type `T`.
+    ///
+    /// `select` runs only when the slice changes, and the selector is
+    /// only reported as changed when its result differs from the last one.
+    pub fn select<T, R, F>(&self, select: F) -> Selector<T, R>
+    where
+        T: Any + Send,
+        R: Clone + PartialEq,
+        F: Fn(&T) -> R + Send + 'static,
+    {
+        Selector {
+            store: self.clone(),
+            select: Box::new(select),
+            seen: Cell::new(0),
+            value: RefCell::new(None),
+            dirty: Cell::new(false),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wait until a slice is written or removed.
+    pub async fn changed(&self) {
+        self.shared.notify.notified().await;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Slices> {
+        self.shared
+            .slices
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Slices {
+    fn bump(&mut self) -> u64 {
+        let version = self.next_version;
+        self.next_version += 1;
+        version
+    }
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Store {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store")
+            .field("slices", &self.lock().slices.len())
+            .finish()
+    }
+}
+
+/// A subscription to one slice of a `Store`.
+///
+/// A signal remembers the version it last read, so `is_dirty` tells a
+/// component whether it needs to refresh.
+pub struct Signal<T> {
+    store: Store,
+    seen: Cell<u64>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Any + Send> Signal<T> {
+    /// Check if the slice changed since it was last read.
+    pub fn is_dirty(&self) -> bool {
+        self.store.version::<T>() != self.seen.get()
+    }
+
+    /// Read the slice without copying it and mark it as seen.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let slices = self.store.lock();
+        let slice = slices.slices.get(&TypeId::of::<T>());
+        self.seen.set(slice.map_or(0, |slice| slice.version));
+        slice?.value.downcast_ref().map(f)
+    }
+
+    /// Write the slice.
+    pub fn set(&self, value: T) {
+        self.store.set(value);
+    }
+
+    /// The store this signal reads from.
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+}
+
+impl<T: Any + Send + Clone> Signal<T> {
+    /// Copy the slice and mark it as seen.
+    pub fn get(&self) -> Option<T> {
+        self.with(T::clone)
+    }
+}
+
+impl<T> fmt::Debug for Signal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signal")
+            .field("slice", &type_name::<T>())
+            .field("seen", &self.seen.get())
+            .finish()
+    }
+}
+
+/// A cached value derived from one slice of a `Store`.
+pub struct Selector<T, R> {
+    store: Store,
+    select: Box<dyn Fn(&T) -> R + Send>,
+    seen: Cell<u64>,
+    value: RefCell<Option<R>>,
+    dirty: Cell<bool>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Any + Send, R: Clone + PartialEq> Selector<T, R> {
+    /// Copy the derived value, recomputing it if the slice changed.
+    ///
+    /// Returns `None` while the slice is not set.
+    pub fn get(&self) -> Option<R> {
+        self.refresh();
+        self.dirty.set(false);
+        self.value.borrow().clone()
+    }
+
+    /// Check if the derived value changed since it was last read, and
+    /// mark it as seen.
+    ///
+    /// Use this in `tick` or `handle_task_message` to react to changes.
+    pub fn changed(&self) -> bool {
+        self.refresh();
+        self.dirty.replace(false)
+    }
+
+    fn refresh(&self) {
+        let slices = self.store.lock();
+        let slice = slices.slices.get(&TypeId::of::<T>());
+        let version = slice.map_or(0, |slice| slice.version);
+        if version == self.seen.get() {
+            return;
+        }
+        self.seen.set(version);
+        let value = slice
+            .and_then(|slice| slice.value.downcast_ref())
+            .map(|value| (self.select)(value));
+        drop(slices);
+
+        let mut current = self.value.borrow_mut();
+        if *current != value {
+            *current = value;
+            self.dirty.set(true);
+        }
+    }
+}
+
+impl<T, R: fmt::Debug> fmt::Debug for Selector<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Selector")
+            .field("slice", &type_name::<T>())
+            .field("value", &self.value.borrow())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Nodes(Vec<&'static str>);
+
+    #[test]
+    fn test_signal_and_selector_dirty_tracking() {
+        let store = Store::new();
+        let nodes = store.signal::<Nodes>();
+        let count = store.select(|nodes: &Nodes| nodes.0.len());
+        assert!(!nodes.is_dirty());
+        assert_eq!(count.get(), None);
+
+        store.update(|nodes: &mut Nodes| nodes.0.push("a"));
+        assert!(nodes.is_dirty());
+        assert_eq!(nodes.get(), Some(Nodes(vec!["a"])));
+        assert!(!nodes.is_dirty());
+        assert!(count.changed());
+        assert_eq!(count.get(), Some(1));
+
+        // A write that leaves the derived value alone does not mark it
+        store.set(Nodes(vec!["b"]));
+        assert!(nodes.is_dirty());
+        assert!(!count.changed());
+
+        assert_eq!(store.remove::<Nodes>(), Some(Nodes(vec!["b"])));
+        assert!(count.changed());
+        assert_eq!(count.get(), None);
+    }
+}
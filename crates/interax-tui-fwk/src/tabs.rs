@@ -2,19 +2,28 @@
 //!
 //! This module provides traits and types for building tabbed interfaces.
 
+use std::borrow::Cow;
 use std::collections::HashSet;
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Tabs as RatatuiTabs},
     Frame,
 };
 
 use crate::context::TabEventContext;
-use crate::event::Event;
+use crate::effect::EffectOutput;
+use crate::event::{Event, KeyCode};
 use crate::focus::EventResult;
+use crate::l10n::Localizer;
+use crate::palette::Command;
+#[cfg(feature = "persist")]
+use crate::persist::Session;
+use crate::responsive::Breakpoint;
+use crate::theme::Theme;
+use crate::timer::Timer;
+use crate::unicode::{display_width, truncate};
 
 /// A tab that can be displayed in the application.
 ///
@@ -68,6 +77,56 @@ pub trait Tab: Send {
         EventResult::Unhandled
     }
 
+    /// Handle the result of an effect spawned with
+    /// `TabEventContext::spawn_effect` while this tab is active.
+    ///
+    /// See `Component::handle_effect`.
+    #[allow(unused_variables)]
+    fn handle_effect(
+        &mut self,
+        output: &mut EffectOutput,
+        ctx: &mut TabEventContext,
+    ) -> EventResult {
+        EventResult::Unhandled
+    }
+
+    /// Handle a timer set with `TabEventContext::set_timeout` or
+    /// `TabEventContext::set_interval` while this tab is active.
+    ///
+    /// See `Component::handle_timer`.
+    #[allow(unused_variables)]
+    fn handle_timer(&mut self, timer: &Timer, ctx: &mut TabEventContext) -> EventResult {
+        EventResult::Unhandled
+    }
+
+    /// Called on each tick cycle if the app has a tick rate configured,
+    /// while this tab is active or if it ticks when inactive.
+    ///
+    /// Use this for animations, spinners or polling. The default
+    /// implementation does nothing.
+    #[allow(unused_variables)]
+    fn tick(&mut self, ctx: &mut TabEventContext) {}
+
+    /// Check if this tab keeps getting ticks and its timers while another
+    /// tab is active.
+    ///
+    /// Its timers are not cancelled when it is deactivated either. The
+    /// default is `false`.
+    fn ticks_when_inactive(&self) -> bool {
+        false
+    }
+
+    /// Commands this tab offers in the command palette while it is active.
+    fn commands(&self) -> Vec<Command> {
+        vec![]
+    }
+
+    /// Run a command chosen in the command palette.
+    #[allow(unused_variables)]
+    fn on_command(&mut self, id: &str, ctx: &mut TabEventContext) -> EventResult {
+        EventResult::Unhandled
+    }
+
     /// Check if this tab is enabled by default.
     ///
     /// This can be overridden at runtime via `TabsEventContext::set_enabled()`.
@@ -76,6 +135,30 @@ pub trait Tab: Send {
         true
     }
 
+    /// Refresh cached localized strings, such as the title.
+    ///
+    /// Called for every tab, active or not, at startup and whenever the
+    /// locale changes.
+    #[allow(unused_variables)]
+    fn on_locale_change(&mut self, l10n: &Localizer) {}
+
+    /// Save state to restore on the next start.
+    ///
+    /// Called for every tab, active or not, when `AppBuilder::persist` is set.
+    #[cfg(feature = "persist")]
+    #[allow(unused_variables)]
+    fn save_state(&self, session: &mut Session) {}
+
+    /// Restore state saved by `save_state`, before the first draw.
+    #[cfg(feature = "persist")]
+    #[allow(unused_variables)]
+    fn restore_state(&mut self, session: &Session) {}
+
+    /// Called at startup and whenever a resize crosses a breakpoint, for
+    /// active and inactive tabs alike.
+    #[allow(unused_variables)]
+    fn on_breakpoint(&mut self, breakpoint: Breakpoint) {}
+
     /// Called when this tab becomes the active tab.
     fn on_activate(&mut self) {}
 
@@ -291,35 +374,43 @@ impl TabManager {
         false
     }
 
-    /// Draw the tab bar.
+    /// Draw the tab bar using the default theme.
     pub fn draw_tabbar(&self, frame: &mut Frame, area: Rect) {
+        self.draw_tabbar_themed(frame, area, &Theme::default());
+    }
+
+    /// Draw the tab bar using the given theme.
+    pub fn draw_tabbar_themed(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         if self.tabs.is_empty() {
             return;
         }
 
-        let titles: Vec<Line> = self
-            .tabs
-            .iter()
+        // Translated titles can be much longer than the source, so shrink
+        // the longest ones until the bar fits
+        let titles = fit_titles(
+            self.tabs.iter().map(|tab| tab.title()).collect(),
+            area.width as usize,
+        );
+        let titles: Vec<Line> = titles
+            .into_iter()
             .enumerate()
-            .map(|(i, tab)| {
+            .map(|(i, title)| {
                 let enabled = self.is_tab_enabled(i);
                 let style = if !enabled {
-                    Style::default().fg(Color::DarkGray)
+                    theme.muted
                 } else if i == self.active_index {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
+                    theme.accent
                 } else {
-                    Style::default().fg(Color::White)
+                    theme.text
                 };
-                Line::from(Span::styled(tab.title(), style))
+                Line::from(Span::styled(title, style))
             })
             .collect();
 
         let tabs_widget = RatatuiTabs::new(titles)
             .block(Block::default().borders(Borders::BOTTOM))
             .select(self.active_index)
-            .highlight_style(Style::default().fg(Color::Yellow));
+            .highlight_style(theme.accent);
 
         frame.render_widget(tabs_widget, area);
     }
@@ -339,6 +430,118 @@ impl TabManager {
             EventResult::Unhandled
         }
     }
+
+    /// Hand an effect result to the active tab.
+    pub fn handle_effect(
+        &mut self,
+        output: &mut EffectOutput,
+        ctx: &mut TabEventContext,
+    ) -> EventResult {
+        if let Some(tab) = self.active_tab_mut() {
+            tab.handle_effect(output, ctx)
+        } else {
+            EventResult::Unhandled
+        }
+    }
+
+    /// Hand a timer to the active tab.
+    pub fn handle_timer(&mut self, timer: &Timer, ctx: &mut TabEventContext) -> EventResult {
+        if let Some(tab) = self.active_tab_mut() {
+            tab.handle_timer(timer, ctx)
+        } else {
+            EventResult::Unhandled
+        }
+    }
+
+    /// Tick the active tab and the inactive tabs that tick when inactive.
+    pub fn tick(&mut self, ctx: &mut TabEventContext) {
+        for tab in self.ticking_mut() {
+            tab.tick(ctx);
+        }
+    }
+
+    /// Tick like `tick`, scoping the effects and timers started by each
+    /// tab to that tab.
+    pub(crate) fn tick_scoped(&mut self, ctx: &mut TabEventContext) {
+        for tab in self.ticking_mut() {
+            ctx.tab = Some(tab.id().to_string());
+            tab.tick(ctx);
+        }
+        ctx.tab = None;
+    }
+
+    /// The tabs to tick: the active one and those ticking when inactive.
+    fn ticking_mut(&mut self) -> impl Iterator<Item = &mut BoxedTab> {
+        let active = self.active_index;
+        self.tabs
+            .iter_mut()
+            .enumerate()
+            .filter(move |(index, tab)| *index == active || tab.ticks_when_inactive())
+            .map(|(_, tab)| tab)
+    }
+
+    /// Check if any tab ticks when inactive.
+    pub fn ticks_when_inactive(&self) -> bool {
+        self.tabs.iter().any(|tab| tab.ticks_when_inactive())
+    }
+
+    /// The tab with id `id`.
+    pub(crate) fn get_mut(&mut self, id: &str) -> Option<&mut BoxedTab> {
+        self.tabs.iter_mut().find(|tab| tab.id() == id)
+    }
+
+    /// Check if the tab with id `id` ticks when inactive.
+    pub(crate) fn is_ticking_when_inactive(&self, id: &str) -> bool {
+        self.tabs
+            .iter()
+            .any(|tab| tab.id() == id && tab.ticks_when_inactive())
+    }
+
+    /// Let every tab refresh its localized strings.
+    pub fn set_locale(&mut self, l10n: &Localizer) {
+        for tab in &mut self.tabs {
+            tab.on_locale_change(l10n);
+        }
+    }
+
+    /// Tell every tab about a new breakpoint.
+    pub fn set_breakpoint(&mut self, breakpoint: Breakpoint) {
+        for tab in &mut self.tabs {
+            tab.on_breakpoint(breakpoint);
+        }
+    }
+
+    /// Save the state of every tab.
+    #[cfg(feature = "persist")]
+    pub fn save_state(&self, session: &mut Session) {
+        for tab in &self.tabs {
+            tab.save_state(session);
+        }
+    }
+
+    /// Restore the state of every tab.
+    #[cfg(feature = "persist")]
+    pub fn restore_state(&mut self, session: &Session) {
+        for tab in &mut self.tabs {
+            tab.restore_state(session);
+        }
+    }
+
+    /// Commands offered by the active tab.
+    pub fn commands(&self) -> Vec<Command> {
+        self.active_tab()
+            .map(|tab| tab.commands())
+            .unwrap_or_default()
+    }
+
+    /// Run a palette command on the active tab.
+    pub fn handle_command(&mut self, id: &str, ctx: &mut TabEventContext) -> EventResult {
+        if let Some(tab) = self.active_tab_mut() {
+            tab.on_command(id, ctx)
+        } else {
+            EventResult::Unhandled
+        }
+    }
 }
 
 impl Default for TabManager {
@@ -346,3 +549,346 @@ impl Default for TabManager {
         Self::new()
     }
 }
+
+/// Shrink the widest `titles` until the tab bar fits in `width` columns.
+///
+/// Each title takes its width plus one column of padding on each side, and
+/// titles are separated by a one-column divider. Titles are cut to a
+/// common maximum width so short titles stay intact.
+fn fit_titles(titles: Vec<&str>, width: usize) -> Vec<Cow<'_, str>> {
+    let chrome = titles.len() * 3 - 1;
+    let budget = width.saturating_sub(chrome);
+    let widths: Vec<usize> = titles.iter().map(|title| display_width(title)).collect();
+    if widths.iter().sum::<usize>() <= budget {
+        return titles.into_iter().map(Cow::Borrowed).collect();
+    }
+
+    // Largest cap whose capped widths fit the budget
+    let widest = widths.iter().copied().max().unwrap_or(0);
+    let cap = (1..=widest)
+        .rev()
+        .find(|&cap| widths.iter().map(|&w| w.min(cap)).sum::<usize>() <= budget)
+        .unwrap_or(1);
+    titles
+        .into_iter()
+        .map(|title| truncate(title, cap))
+        .collect()
+}
+
+/// Height of the tab bar drawn by `TabGroup` when used as a `Tab`.
+const TAB_GROUP_BAR_HEIGHT: u16 = 2;
+
+/// A group of second-level tabs that can be embedded in any `Tab` or `Component`.
+///
+/// `TabGroup` owns its own `TabManager`, so it has an independent active index,
+/// enable/disable overrides and keyboard navigation. Child tabs implement the
+/// same `Tab` trait as top-level tabs.
+///
+/// `TabGroup` itself implements `Tab`, so it can be registered directly with
+/// `AppBuilder::add_tab` to get a tab with sub-pages.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::{AppBuilder, TabGroup};
+///
+/// let endpoints = TabGroup::new("endpoints", "Endpoints")
+///     .with_tab(AttributesTab::new())
+///     .with_tab(CommandsTab::new())
+///     .with_tab(EventsTab::new());
+///
+/// let app = AppBuilder::new()
+///     .main_ui(MyApp::new())
+///     .add_tab(endpoints)
+///     .build()?;
+/// ```
+pub struct TabGroup {
+    id: String,
+    title: String,
+    tabs: TabManager,
+    prev_key: KeyCode,
+    next_key: KeyCode,
+}
+
+impl TabGroup {
+    /// Create a new empty tab group.
+    ///
+    /// By default `[` and `]` select the previous and next sub-tab.
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            tabs: TabManager::new(),
+            prev_key: KeyCode::Char('['),
+            next_key: KeyCode::Char(']'),
+        }
+    }
+
+    /// Add a sub-tab, returning the group (builder style).
+    pub fn with_tab<T: Tab + 'static>(mut self, tab: T) -> Self {
+        self.tabs.add(tab);
+        self
+    }
+
+    /// Add a sub-tab.
+    pub fn add_tab<T: Tab + 'static>(&mut self, tab: T) {
+        self.tabs.add(tab);
+    }
+
+    /// Set the keys used to select the previous and next sub-tab.
+    pub fn nav_keys(mut self, prev: KeyCode, next: KeyCode) -> Self {
+        self.prev_key = prev;
+        self.next_key = next;
+        self
+    }
+
+    /// Access the sub-tabs of this group.
+    pub fn tabs(&self) -> &TabManager {
+        &self.tabs
+    }
+
+    /// Access the sub-tabs of this group mutably.
+    ///
+    /// Use this to select sub-tabs or toggle their enabled state.
+    pub fn tabs_mut(&mut self) -> &mut TabManager {
+        &mut self.tabs
+    }
+
+    /// Draw the sub-tab bar.
+    pub fn draw_tabbar(&self, frame: &mut Frame, area: Rect) {
+        self.tabs.draw_tabbar(frame, area);
+    }
+
+    /// Draw the content of the active sub-tab.
+    pub fn draw_content(&self, frame: &mut Frame, area: Rect) {
+        self.tabs.draw_content(frame, area);
+    }
+
+    /// Handle the group's navigation keys only.
+    ///
+    /// Returns `EventResult::Handled` if the event selected another sub-tab.
+    pub fn handle_navigation(&mut self, event: &Event) -> EventResult {
+        if event.is_key(self.prev_key) {
+            self.tabs.select_prev();
+            EventResult::Handled
+        } else if event.is_key(self.next_key) {
+            self.tabs.select_next();
+            EventResult::Handled
+        } else {
+            EventResult::Unhandled
+        }
+    }
+}
+
+impl Tab for TabGroup {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let bar_height = TAB_GROUP_BAR_HEIGHT.min(area.height);
+        let bar = Rect {
+            height: bar_height,
+            ..area
+        };
+        let content = Rect {
+            y: area.y + bar_height,
+            height: area.height - bar_height,
+            ..area
+        };
+        self.draw_tabbar(frame, bar);
+        self.draw_content(frame, content);
+    }
+
+    /// Navigation keys are handled first, everything else goes to the
+    /// active sub-tab (mirroring how `App` dispatches to `MainUi` then tabs).
+    fn handle_event(&mut self, event: &Event, ctx: &mut TabEventContext) -> EventResult {
+        let result = self.handle_navigation(event);
+        if result.should_propagate() {
+            self.tabs.handle_event(event, ctx)
+        } else {
+            result
+        }
+    }
+
+    fn handle_effect(
+        &mut self,
+        output: &mut EffectOutput,
+        ctx: &mut TabEventContext,
+    ) -> EventResult {
+        self.tabs.handle_effect(output, ctx)
+    }
+
+    fn handle_timer(&mut self, timer: &Timer, ctx: &mut TabEventContext) -> EventResult {
+        self.tabs.handle_timer(timer, ctx)
+    }
+
+    fn tick(&mut self, ctx: &mut TabEventContext) {
+        self.tabs.tick(ctx);
+    }
+
+    /// The group ticks when inactive if any of its sub-tabs does. Its
+    /// active sub-tab is then ticked as well.
+    fn ticks_when_inactive(&self) -> bool {
+        self.tabs.ticks_when_inactive()
+    }
+
+    fn commands(&self) -> Vec<Command> {
+        self.tabs.commands()
+    }
+
+    fn on_command(&mut self, id: &str, ctx: &mut TabEventContext) -> EventResult {
+        self.tabs.handle_command(id, ctx)
+    }
+
+    fn on_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.tabs.set_breakpoint(breakpoint);
+    }
+
+    fn on_locale_change(&mut self, l10n: &Localizer) {
+        self.tabs.set_locale(l10n);
+    }
+
+    #[cfg(feature = "persist")]
+    fn save_state(&self, session: &mut Session) {
+        if let Some(id) = self.tabs.active_tab().map(|tab| tab.id()) {
+            session.set(&format!("{}.tab", self.id), 1, &id);
+        }
+        self.tabs.save_state(session);
+    }
+
+    #[cfg(feature = "persist")]
+    fn restore_state(&mut self, session: &Session) {
+        self.tabs.restore_state(session);
+        if let Some(id) = session.get::<String>(&format!("{}.tab", self.id), 1) {
+            self.tabs.select_by_id(&id);
+        }
+    }
+
+    fn on_activate(&mut self) {
+        if let Some(tab) = self.tabs.active_tab_mut() {
+            tab.on_activate();
+        }
+    }
+
+    fn on_deactivate(&mut self) {
+        if let Some(tab) = self.tabs.active_tab_mut() {
+            tab.on_deactivate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestTab(&'static str);
+
+    impl Tab for TestTab {
+        fn id(&self) -> &str {
+            self.0
+        }
+
+        fn title(&self) -> &str {
+            self.0
+        }
+
+        fn draw(&self, _frame: &mut Frame, _area: Rect) {}
+    }
+
+    struct BackgroundTab(&'static str);
+
+    impl Tab for BackgroundTab {
+        fn id(&self) -> &str {
+            self.0
+        }
+
+        fn title(&self) -> &str {
+            self.0
+        }
+
+        fn draw(&self, _frame: &mut Frame, _area: Rect) {}
+
+        fn ticks_when_inactive(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_ticks_reach_active_and_background_tabs() {
+        let mut group = TabGroup::new("endpoints", "Endpoints")
+            .with_tab(TestTab("attributes"))
+            .with_tab(BackgroundTab("events"))
+            .with_tab(TestTab("commands"));
+        let ticking = |tabs: &mut TabManager| {
+            tabs.ticking_mut()
+                .map(|tab| tab.id().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ticking(group.tabs_mut()), ["attributes", "events"]);
+        group.tabs_mut().select(2);
+        assert_eq!(ticking(group.tabs_mut()), ["events", "commands"]);
+        assert!(group.ticks_when_inactive());
+        assert!(group.tabs().is_ticking_when_inactive("events"));
+        assert!(!group.tabs().is_ticking_when_inactive("commands"));
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(crossterm::event::KeyEvent::new(
+            code,
+            crate::event::KeyModifiers::NONE,
+        ))
+    }
+
+    #[test]
+    fn test_tab_group_navigation() {
+        let mut group = TabGroup::new("endpoints", "Endpoints")
+            .with_tab(TestTab("attributes"))
+            .with_tab(TestTab("commands"))
+            .with_tab(TestTab("events"));
+
+        assert_eq!(group.tabs().active_index(), 0);
+        assert!(group
+            .handle_navigation(&key(KeyCode::Char(']')))
+            .is_handled());
+        assert_eq!(group.tabs().active_tab().map(|t| t.id()), Some("commands"));
+
+        assert!(group
+            .handle_navigation(&key(KeyCode::Char('[')))
+            .is_handled());
+        assert!(group
+            .handle_navigation(&key(KeyCode::Char('[')))
+            .is_handled());
+        assert_eq!(group.tabs().active_tab().map(|t| t.id()), Some("events"));
+
+        assert!(!group.handle_navigation(&key(KeyCode::Up)).is_handled());
+    }
+
+    #[test]
+    fn test_tab_group_disabled_override() {
+        let mut group = TabGroup::new("endpoints", "Endpoints")
+            .with_tab(TestTab("attributes"))
+            .with_tab(TestTab("commands"))
+            .with_tab(TestTab("events"))
+            .nav_keys(KeyCode::Left, KeyCode::Right);
+
+        assert!(group.tabs_mut().set_enabled("commands", false));
+        group.handle_navigation(&key(KeyCode::Right));
+        assert_eq!(group.tabs().active_tab().map(|t| t.id()), Some("events"));
+        assert!(!group.tabs_mut().select_by_id("commands"));
+    }
+
+    #[test]
+    fn test_fit_titles_shrinks_longest() {
+        // 3 tabs take 8 columns of padding and dividers
+        let titles = fit_titles(vec!["Home", "Einstellungen", "Protokolle"], 28);
+        assert_eq!(titles, ["Home", "Einstel…", "Protoko…"]);
+
+        let titles = fit_titles(vec!["Home", "Logs"], 40);
+        assert_eq!(titles, ["Home", "Logs"]);
+    }
+}
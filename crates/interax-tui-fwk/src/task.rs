@@ -9,6 +9,10 @@ use std::pin::Pin;
 use tokio::task::JoinHandle;
 
 use crate::bus::TaskSender;
+use crate::context::Services;
+use crate::l10n::Localizer;
+use crate::notify::Notifier;
+use crate::store::Store;
 
 /// Context provided to running tasks.
 ///
@@ -16,12 +20,39 @@ use crate::bus::TaskSender;
 pub struct TaskContext {
     /// Cancellation token for cooperative shutdown.
     cancel_rx: tokio::sync::watch::Receiver<bool>,
+    /// Toast queue and store shared with the UI.
+    services: Services,
 }
 
 impl TaskContext {
     /// Create a new task context.
-    pub(crate) fn new(cancel_rx: tokio::sync::watch::Receiver<bool>) -> Self {
-        Self { cancel_rx }
+    pub(crate) fn new(cancel_rx: tokio::sync::watch::Receiver<bool>, services: Services) -> Self {
+        Self {
+            cancel_rx,
+            services,
+        }
+    }
+
+    /// Access the toast notification queue.
+    ///
+    /// Toasts pushed from a task are shown without sending a message.
+    #[inline]
+    pub fn notifier(&self) -> &Notifier {
+        &self.services.notifier
+    }
+
+    /// Access the shared state store.
+    ///
+    /// Writing a slice redraws the UI without sending a message.
+    #[inline]
+    pub fn store(&self) -> &Store {
+        &self.services.store
+    }
+
+    /// Access the localizer, e.g. to localize toasts.
+    #[inline]
+    pub fn l10n(&self) -> &Localizer {
+        &self.services.l10n
     }
 
     /// Check if the task should stop.
@@ -50,6 +81,7 @@ impl TaskContext {
     pub fn clone_context(&self) -> Self {
         Self {
             cancel_rx: self.cancel_rx.clone(),
+            services: self.services.clone(),
         }
     }
 }
@@ -104,7 +136,7 @@ impl Clone for TaskContext {
 /// ```
 pub trait Task: Send + 'static {
     /// The message type this task sends to the UI.
-    type Message: Send + 'static;
+    type Message: Send + Sync + 'static;
 
     /// Run the task.
     ///
@@ -3,14 +3,16 @@
 //! This module handles raw mode setup/teardown and provides a safe wrapper
 //! around the ratatui terminal.
 
-use std::io::{self, Stdout};
+use std::io::{self, Stdout, Write};
 
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal as RatatuiTerminal};
+use ratatui::{
+    backend::CrosstermBackend, layout::Rect, Terminal as RatatuiTerminal, TerminalOptions, Viewport,
+};
 
 /// Error type for terminal operations
 #[derive(Debug)]
@@ -56,13 +58,40 @@ impl Default for TerminalConfig {
     }
 }
 
+/// Where a `Terminal` writes its output.
+pub enum TerminalOutput {
+    /// The process's own terminal.
+    Stdout(Stdout),
+    /// A remote terminal, e.g. a client attached to an `AppServer`.
+    Remote(Box<dyn Write + Send>),
+}
+
+impl Write for TerminalOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            TerminalOutput::Stdout(stdout) => stdout.write(buf),
+            TerminalOutput::Remote(output) => output.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            TerminalOutput::Stdout(stdout) => stdout.flush(),
+            TerminalOutput::Remote(output) => output.flush(),
+        }
+    }
+}
+
 /// Terminal wrapper that manages raw mode and alternate screen.
 ///
 /// This struct ensures proper cleanup on drop, restoring the terminal
 /// to its original state even if the application panics.
 pub struct Terminal {
-    terminal: RatatuiTerminal<CrosstermBackend<Stdout>>,
+    terminal: RatatuiTerminal<CrosstermBackend<TerminalOutput>>,
     mouse_capture_enabled: bool,
+    /// Size of a remote terminal, which cannot be queried and is only
+    /// known from resize events.
+    remote_size: Option<Rect>,
 }
 
 impl Terminal {
@@ -77,13 +106,8 @@ impl Terminal {
     pub fn with_config(config: TerminalConfig) -> Result<Self, TerminalError> {
         enable_raw_mode()?;
 
-        let mut stdout = io::stdout();
-
-        if config.mouse_capture {
-            execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-        } else {
-            execute!(stdout, EnterAlternateScreen)?;
-        }
+        let mut stdout = TerminalOutput::Stdout(io::stdout());
+        enter(&mut stdout, &config)?;
 
         let backend = CrosstermBackend::new(stdout);
         let terminal = RatatuiTerminal::new(backend)?;
@@ -91,20 +115,74 @@ impl Terminal {
         Ok(Self {
             terminal,
             mouse_capture_enabled: config.mouse_capture,
+            remote_size: None,
+        })
+    }
+
+    /// Create a terminal that writes to a remote client of `width` by
+    /// `height` cells.
+    ///
+    /// The client is responsible for its own raw mode; this only enters
+    /// the alternate screen. Call `resize` when the client reports a new
+    /// size.
+    pub fn remote(
+        output: Box<dyn Write + Send>,
+        width: u16,
+        height: u16,
+        config: TerminalConfig,
+    ) -> Result<Self, TerminalError> {
+        let mut output = TerminalOutput::Remote(output);
+        enter(&mut output, &config)?;
+
+        let area = Rect::new(0, 0, width, height);
+        let backend = CrosstermBackend::new(output);
+        let terminal = RatatuiTerminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: Viewport::Fixed(area),
+            },
+        )?;
+
+        Ok(Self {
+            terminal,
+            mouse_capture_enabled: config.mouse_capture,
+            remote_size: Some(area),
         })
     }
 
+    /// Check if this terminal belongs to a remote client.
+    #[inline]
+    pub fn is_remote(&self) -> bool {
+        self.remote_size.is_some()
+    }
+
+    /// Apply a resize event.
+    ///
+    /// The local terminal picks up its new size by itself; a remote one
+    /// only learns it from the client.
+    pub fn resize(&mut self, width: u16, height: u16) -> Result<(), TerminalError> {
+        if self.remote_size.is_some() {
+            let area = Rect::new(0, 0, width, height);
+            self.terminal.resize(area)?;
+            self.remote_size = Some(area);
+        }
+        Ok(())
+    }
+
     /// Get a mutable reference to the underlying ratatui terminal.
     #[inline]
-    pub fn inner_mut(&mut self) -> &mut RatatuiTerminal<CrosstermBackend<Stdout>> {
+    pub fn inner_mut(&mut self) -> &mut RatatuiTerminal<CrosstermBackend<TerminalOutput>> {
         &mut self.terminal
     }
 
     /// Get the terminal size as a Rect
     #[inline]
-    pub fn size(&self) -> Result<ratatui::layout::Rect, TerminalError> {
+    pub fn size(&self) -> Result<Rect, TerminalError> {
+        if let Some(area) = self.remote_size {
+            return Ok(area);
+        }
         let size = self.terminal.size().map_err(TerminalError::from)?;
-        Ok(ratatui::layout::Rect::new(0, 0, size.width, size.height))
+        Ok(Rect::new(0, 0, size.width, size.height))
     }
 
     /// Draw to the terminal using the provided closure.
@@ -117,6 +195,14 @@ impl Terminal {
         Ok(())
     }
 
+    /// Write raw bytes, such as an escape sequence, to the terminal.
+    pub fn write_raw(&mut self, bytes: &[u8]) -> Result<(), TerminalError> {
+        let backend = self.terminal.backend_mut();
+        backend.write_all(bytes)?;
+        backend.flush()?;
+        Ok(())
+    }
+
     /// Clear the terminal screen.
     #[inline]
     pub fn clear(&mut self) -> Result<(), TerminalError> {
@@ -150,7 +236,9 @@ impl Terminal {
     /// This is called automatically on drop, but can be called manually
     /// if you need to restore the terminal before the struct is dropped.
     pub fn restore(&mut self) -> Result<(), TerminalError> {
-        disable_raw_mode()?;
+        if !self.is_remote() {
+            disable_raw_mode()?;
+        }
         execute!(
             self.terminal.backend_mut(),
             LeaveAlternateScreen,
@@ -168,6 +256,15 @@ impl Drop for Terminal {
     }
 }
 
+/// Enter the alternate screen and enable mouse capture if configured.
+fn enter(output: &mut TerminalOutput, config: &TerminalConfig) -> io::Result<()> {
+    if config.mouse_capture {
+        execute!(output, EnterAlternateScreen, EnableMouseCapture)
+    } else {
+        execute!(output, EnterAlternateScreen)
+    }
+}
+
 /// Install a panic hook that restores the terminal before printing the panic message.
 ///
 /// Call this early in your application to ensure the terminal is restored
@@ -0,0 +1,128 @@
+//! Theming for the TUI framework.
+//!
+//! This module provides the `Theme` struct used by the tab bar and the
+//! built-in widgets to style focused and unfocused states consistently.
+
+use ratatui::style::{Color, Modifier, Style};
+
+/// Styles shared by the framework's built-in rendering.
+///
+/// Set a theme with `AppBuilder::theme()` and read it while drawing via
+/// `DrawContext::theme()`. The default theme matches the framework's
+/// original colors.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::{AppBuilder, Theme};
+/// use ratatui::style::{Color, Style};
+///
+/// let theme = Theme {
+///     accent: Style::default().fg(Color::Cyan),
+///     ..Theme::default()
+/// };
+///
+/// let app = AppBuilder::new()
+///     .main_ui(MyApp::new())
+///     .theme(theme)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Regular text.
+    pub text: Style,
+    /// De-emphasized text (hints, footers, disabled items).
+    pub muted: Style,
+    /// Accent used for active tabs and focused titles.
+    pub accent: Style,
+    /// Border of a focused widget.
+    pub border_focused: Style,
+    /// Border of an unfocused widget.
+    pub border_unfocused: Style,
+    /// Header row of a focused widget.
+    pub header_focused: Style,
+    /// Header row of an unfocused widget.
+    pub header_unfocused: Style,
+    /// Cursor row of a focused widget.
+    pub cursor_focused: Style,
+    /// Cursor row of an unfocused widget.
+    pub cursor_unfocused: Style,
+    /// Rows marked in a multi-selection.
+    pub selected: Style,
+    /// Selected text in text inputs.
+    pub text_selection: Style,
+    /// Validation errors and error toasts.
+    pub error: Style,
+    /// Info toasts.
+    pub info: Style,
+    /// Success toasts.
+    pub success: Style,
+    /// Warning toasts.
+    pub warning: Style,
+    /// The status bar row.
+    pub status_bar: Style,
+}
+
+impl Theme {
+    /// Border style for the given focus state.
+    #[inline]
+    pub fn border(&self, focused: bool) -> Style {
+        if focused {
+            self.border_focused
+        } else {
+            self.border_unfocused
+        }
+    }
+
+    /// Header style for the given focus state.
+    #[inline]
+    pub fn header(&self, focused: bool) -> Style {
+        if focused {
+            self.header_focused
+        } else {
+            self.header_unfocused
+        }
+    }
+
+    /// Cursor row style for the given focus state.
+    #[inline]
+    pub fn cursor(&self, focused: bool) -> Style {
+        if focused {
+            self.cursor_focused
+        } else {
+            self.cursor_unfocused
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            text: Style::default().fg(Color::White),
+            muted: Style::default().fg(Color::DarkGray),
+            accent: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            border_focused: Style::default().fg(Color::Yellow),
+            border_unfocused: Style::default().fg(Color::DarkGray),
+            header_focused: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            header_unfocused: Style::default().fg(Color::DarkGray),
+            cursor_focused: Style::default()
+                .bg(Color::Yellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+            cursor_unfocused: Style::default().bg(Color::DarkGray).fg(Color::Black),
+            selected: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            text_selection: Style::default().add_modifier(Modifier::REVERSED),
+            error: Style::default().fg(Color::Red),
+            info: Style::default().fg(Color::Cyan),
+            success: Style::default().fg(Color::Green),
+            warning: Style::default().fg(Color::Yellow),
+            status_bar: Style::default().bg(Color::DarkGray).fg(Color::White),
+        }
+    }
+}
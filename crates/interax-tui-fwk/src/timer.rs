@@ -0,0 +1,250 @@
+//! Timers scheduled from event handlers.
+//!
+//! `AppContext::set_timeout` and `AppContext::set_interval` start a named
+//! timer owned by the focused component. When it fires, the app wakes up
+//! and hands a `Timer` to `Component::handle_timer` of the main UI first,
+//! then to the current screen or the active tab, the same way events are
+//! dispatched. Nothing runs between timers, so an app without a tick rate
+//! stays idle until a timer is due.
+//!
+//! Timers are identified by name: setting a timer again replaces it, which
+//! is how `debounce` restarts its delay on every call. `throttle` tells if
+//! an action may run now, at most once per period.
+//!
+//! Like effects, a timer is cancelled when its owner is unregistered from
+//! focus or when the tab that set it is deactivated, unless that tab
+//! opted in to `Tab::ticks_when_inactive`. Such a tab keeps getting its
+//! timers while inactive.
+//!
+//! # Example
+//!
+//! ```ignore
+//! fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+//!     if self.filter.handle_event(event, ctx).is_handled() {
+//!         // Refilter once typing pauses
+//!         ctx.debounce("endpoints.filter", Duration::from_millis(250));
+//!         return EventResult::Handled;
+//!     }
+//!     EventResult::Unhandled
+//! }
+//!
+//! fn handle_timer(&mut self, timer: &Timer, ctx: &mut AppContext) -> EventResult {
+//!     if timer.id() == "endpoints.filter" {
+//!         self.apply_filter();
+//!         return EventResult::Handled;
+//!     }
+//!     EventResult::Unhandled
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::effect::Scope;
+
+/// Shortest interval, so a zero period cannot spin the event loop.
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A timer that fired.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timer {
+    id: String,
+    owner: Option<String>,
+    tab: Option<String>,
+    interval: Option<Duration>,
+}
+
+impl Timer {
+    /// The name the timer was set with.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The focus id of the component that set the timer.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// The id of the top-level tab that set the timer.
+    pub fn tab(&self) -> Option<&str> {
+        self.tab.as_deref()
+    }
+
+    /// The period of an interval, or `None` for a timeout.
+    pub fn interval(&self) -> Option<Duration> {
+        self.interval
+    }
+}
+
+/// A scheduled timer.
+struct Entry {
+    timer: Timer,
+    scope: Scope,
+    deadline: Instant,
+}
+
+#[derive(Default)]
+struct State {
+    entries: Vec<Entry>,
+    /// When each throttled action last ran.
+    throttled: HashMap<String, Instant>,
+}
+
+/// The timers set by the UI, shared by every context.
+#[derive(Clone, Default)]
+pub(crate) struct Timers {
+    state: Arc<Mutex<State>>,
+}
+
+impl Timers {
+    /// Schedule a timer firing after `delay`, and then every `interval` if
+    /// set, replacing any timer with the same id.
+    pub(crate) fn set(
+        &self,
+        id: &str,
+        owner: Option<String>,
+        scope: Scope,
+        delay: Duration,
+        interval: Option<Duration>,
+    ) {
+        let entry = Entry {
+            timer: Timer {
+                id: id.to_string(),
+                owner,
+                tab: scope.tab.clone(),
+                interval: interval.map(|interval| interval.max(MIN_INTERVAL)),
+            },
+            scope,
+            deadline: Instant::now() + delay,
+        };
+        let mut state = self.lock();
+        state.entries.retain(|entry| entry.timer.id != id);
+        state.entries.push(entry);
+    }
+
+    /// Cancel the timer `id`.
+    ///
+    /// Returns `false` if no such timer is scheduled.
+    pub(crate) fn cancel(&self, id: &str) -> bool {
+        let mut state = self.lock();
+        state.throttled.remove(id);
+        let len = state.entries.len();
+        state.entries.retain(|entry| entry.timer.id != id);
+        state.entries.len() != len
+    }
+
+    /// Check if the throttled action `id` may run at `now`, and if so
+    /// start a new period.
+    pub(crate) fn throttle(&self, id: &str, period: Duration, now: Instant) -> bool {
+        let mut state = self.lock();
+        match state.throttled.get(id) {
+            Some(last) if now.duration_since(*last) < period => false,
+            _ => {
+                state.throttled.insert(id.to_string(), now);
+                true
+            }
+        }
+    }
+
+    /// Cancel the timers set by the tab `tab`.
+    pub(crate) fn cancel_tab(&self, tab: &str) {
+        self.lock().entries.retain(|entry| !entry.scope.in_tab(tab));
+    }
+
+    /// Cancel the timers whose owner is no longer registered.
+    pub(crate) fn cancel_orphans(&self, registered: &[String]) {
+        self.lock()
+            .entries
+            .retain(|entry| !entry.scope.is_orphan(registered));
+    }
+
+    /// When the next timer fires.
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.lock().entries.iter().map(|entry| entry.deadline).min()
+    }
+
+    /// Take the timers due at `now`, in deadline order.
+    ///
+    /// Timeouts are removed and intervals rescheduled. An interval that
+    /// fell behind fires once and skips the missed periods.
+    pub(crate) fn take_due(&self, now: Instant) -> Vec<Timer> {
+        let mut state = self.lock();
+        let mut due: Vec<(Instant, Timer)> = Vec::new();
+        state.entries.retain_mut(|entry| {
+            if entry.deadline > now {
+                return true;
+            }
+            due.push((entry.deadline, entry.timer.clone()));
+            match entry.timer.interval {
+                Some(interval) => {
+                    entry.deadline += interval;
+                    if entry.deadline <= now {
+                        entry.deadline = now + interval;
+                    }
+                    true
+                }
+                None => false,
+            }
+        });
+        due.sort_by_key(|(deadline, _)| *deadline);
+        due.into_iter().map(|(_, timer)| timer).collect()
+    }
+
+    /// Number of scheduled timers.
+    pub(crate) fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for Timers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timers")
+            .field("scheduled", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeouts_intervals_and_throttle() {
+        let timers = Timers::default();
+        let now = Instant::now();
+        let ms = Duration::from_millis;
+        timers.set(
+            "poll",
+            Some("table".into()),
+            Scope::default(),
+            ms(10),
+            Some(ms(10)),
+        );
+        timers.set("save", None, Scope::default(), ms(50), None);
+        // Setting it again restarts the delay, as `debounce` does
+        timers.set("save", None, Scope::default(), ms(30), None);
+        assert_eq!(timers.len(), 2);
+        assert!(timers.next_deadline().unwrap() <= now + ms(20));
+
+        let ids = |timers: Vec<Timer>| timers.into_iter().map(|t| t.id).collect::<Vec<_>>();
+        assert!(timers.take_due(now).is_empty());
+        assert_eq!(ids(timers.take_due(now + ms(40))), ["poll", "save"]);
+        // The late interval fires once and is rescheduled from now
+        assert_eq!(timers.len(), 1);
+        assert!(timers.next_deadline().unwrap() > now + ms(40));
+
+        assert!(timers.cancel("poll"));
+        assert!(!timers.cancel("poll"));
+        assert!(timers.next_deadline().is_none());
+
+        assert!(timers.throttle("refresh", ms(100), now));
+        assert!(!timers.throttle("refresh", ms(100), now + ms(50)));
+        assert!(timers.throttle("refresh", ms(100), now + ms(100)));
+    }
+}
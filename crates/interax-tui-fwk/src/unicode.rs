@@ -0,0 +1,198 @@
+//! Width- and direction-aware text helpers.
+//!
+//! Terminal cells do not map one-to-one to `char`s: East Asian wide
+//! characters and most emoji take two columns, combining marks take none,
+//! and a user-perceived character (a grapheme cluster) can span several
+//! `char`s. The helpers here measure and cut text by grapheme cluster and
+//! display width, so truncation never splits a character or leaves half of
+//! a wide one behind.
+//!
+//! Terminals differ in how they handle bidirectional text, so the framework
+//! does not reorder it. Text whose first strong character is right-to-left
+//! (Hebrew, Arabic, ...) is aligned to the right edge of its cell instead,
+//! which is where readers of those scripts expect it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use interax_tui_fwk::unicode::{display_width, fit_line, truncate};
+//!
+//! assert_eq!(display_width("日本語"), 6);
+//! assert_eq!(truncate("日本語のタイトル", 7), "日本語…");
+//!
+//! // Truncated and right-aligned
+//! let line = fit_line("שלום עולם", 6);
+//! ```
+
+use std::borrow::Cow;
+
+use ratatui::{layout::Alignment, text::Line};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Marker appended to truncated text.
+pub const ELLIPSIS: &str = "…";
+
+/// Base direction of a piece of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Latin, CJK and most other scripts.
+    LeftToRight,
+    /// Hebrew, Arabic and other right-to-left scripts.
+    RightToLeft,
+}
+
+impl TextDirection {
+    /// Alignment that puts text of this direction at its reading start.
+    pub fn alignment(self) -> Alignment {
+        match self {
+            TextDirection::LeftToRight => Alignment::Left,
+            TextDirection::RightToLeft => Alignment::Right,
+        }
+    }
+}
+
+/// Number of terminal columns `text` takes.
+pub fn display_width(text: &str) -> usize {
+    text.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// Cut `text` to at most `width` columns, ending with an ellipsis.
+///
+/// Text that already fits is returned unchanged.
+pub fn truncate(text: &str, width: usize) -> Cow<'_, str> {
+    if display_width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+    let budget = width.saturating_sub(ELLIPSIS.width());
+    let mut used = 0;
+    let mut end = 0;
+    for (index, grapheme) in text.grapheme_indices(true) {
+        let w = grapheme.width();
+        if used + w > budget {
+            break;
+        }
+        used += w;
+        end = index + grapheme.len();
+    }
+    finish(&text[..end], width, |kept| format!("{}{}", kept, ELLIPSIS))
+}
+
+/// Cut `text` to at most `width` columns, keeping its end and starting
+/// with an ellipsis.
+///
+/// Use this for paths and identifiers whose tail matters most.
+pub fn truncate_start(text: &str, width: usize) -> Cow<'_, str> {
+    if display_width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+    let budget = width.saturating_sub(ELLIPSIS.width());
+    let mut used = 0;
+    let mut start = text.len();
+    for (index, grapheme) in text.grapheme_indices(true).rev() {
+        let w = grapheme.width();
+        if used + w > budget {
+            break;
+        }
+        used += w;
+        start = index;
+    }
+    finish(&text[start..], width, |kept| {
+        format!("{}{}", ELLIPSIS, kept)
+    })
+}
+
+/// Add the ellipsis to the kept part of a truncated text, unless there is
+/// no room for it.
+fn finish(kept: &str, width: usize, join: impl Fn(&str) -> String) -> Cow<'static, str> {
+    if width < ELLIPSIS.width() {
+        Cow::Borrowed("")
+    } else {
+        Cow::Owned(join(kept))
+    }
+}
+
+/// Base direction of `text`, from its first strong character.
+///
+/// Text without letters (numbers, punctuation) is left-to-right.
+pub fn text_direction(text: &str) -> TextDirection {
+    text.chars()
+        .find_map(|c| {
+            if is_rtl(c) {
+                Some(TextDirection::RightToLeft)
+            } else if c.is_alphabetic() {
+                Some(TextDirection::LeftToRight)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(TextDirection::LeftToRight)
+}
+
+/// Check if `c` is a letter of a right-to-left script.
+fn is_rtl(c: char) -> bool {
+    matches!(
+        c as u32,
+        // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic
+        0x0590..=0x08FF
+            // Hebrew and Arabic presentation forms
+            | 0xFB1D..=0xFDFF
+            | 0xFE70..=0xFEFF
+            // Historic scripts and Arabic mathematical symbols
+            | 0x10800..=0x10FFF
+            | 0x1E800..=0x1EFFF
+    ) && c.is_alphabetic()
+}
+
+/// Truncate `text` to `width` columns and align it by its direction.
+///
+/// This is how the built-in widgets render cells, items and titles.
+pub fn fit_line(text: &str, width: usize) -> Line<'_> {
+    let alignment = text_direction(text).alignment();
+    Line::from(truncate(text, width)).alignment(alignment)
+}
+
+/// Pad `text` with spaces to exactly `width` columns, truncating it if it
+/// is wider.
+pub fn pad(text: &str, width: usize, alignment: Alignment) -> String {
+    let text = truncate(text, width);
+    let gap = width.saturating_sub(display_width(&text));
+    let (left, right) = match alignment {
+        Alignment::Left => (0, gap),
+        Alignment::Center => (gap / 2, gap - gap / 2),
+        Alignment::Right => (gap, 0),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_keeps_graphemes_whole() {
+        assert_eq!(truncate("Logs", 10), "Logs");
+        assert_eq!(truncate("Einstellungen", 6), "Einst…");
+        // Wide characters are not split; the freed column stays empty
+        assert_eq!(truncate("日本語のタイトル", 6), "日本…");
+        assert_eq!(display_width(&truncate("日本語のタイトル", 6)), 5);
+        // "é" written as e + combining acute accent
+        assert_eq!(display_width("cafe\u{301}s"), 5);
+        assert_eq!(truncate("cafe\u{301}s", 5), "cafe\u{301}s");
+        assert_eq!(truncate("cafe\u{301}s!", 5), "cafe\u{301}…");
+        assert_eq!(truncate_start("/var/log/syslog", 8), "…/syslog");
+        assert_eq!(truncate("abc", 0), "");
+    }
+
+    #[test]
+    fn test_direction_and_padding() {
+        assert_eq!(text_direction("שלום"), TextDirection::RightToLeft);
+        assert_eq!(text_direction("42 مرحبا"), TextDirection::RightToLeft);
+        assert_eq!(text_direction("node-1 שלום"), TextDirection::LeftToRight);
+        assert_eq!(text_direction("123"), TextDirection::LeftToRight);
+        assert_eq!(fit_line("שלום", 10).alignment, Some(Alignment::Right));
+
+        assert_eq!(pad("ab", 5, Alignment::Right), "   ab");
+        assert_eq!(pad("日本", 5, Alignment::Center), "日本 ");
+    }
+}
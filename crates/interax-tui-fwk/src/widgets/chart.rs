@@ -0,0 +1,533 @@
+//! Chart widgets rendering `TimeSeries` buffers.
+//!
+//! All charts are display-only components: they are not focusable and read
+//! the latest samples every time they are drawn.
+
+use std::time::Duration;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    symbols::Marker,
+    text::Span,
+    widgets::{
+        Axis, Bar, BarChart, BarGroup, Block, Borders, Chart, Dataset, Gauge, GraphType, Sparkline,
+    },
+    Frame,
+};
+
+use crate::component::Component;
+use crate::context::DrawContext;
+use crate::series::{Aggregation, TimeSeries};
+use crate::theme::Theme;
+
+/// Resolution used to scale values into sparkline bar heights.
+const SPARKLINE_SCALE: f64 = 1000.0;
+
+/// Styles used for line chart series without an explicit style.
+const LINE_COLORS: [Color; 5] = [
+    Color::Yellow,
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::Blue,
+];
+
+/// Format a value with its unit, dropping decimals for large values.
+fn format_value(value: f64, unit: &str) -> String {
+    if value.abs() >= 100.0 {
+        format!("{:.0}{}", value, unit)
+    } else {
+        format!("{:.1}{}", value, unit)
+    }
+}
+
+/// Build the bordered block shared by the charts.
+fn chart_block<'a>(title: Option<&str>, suffix: Option<String>, theme: &Theme) -> Block<'a> {
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme.border_unfocused);
+    let title = match (title, suffix) {
+        (Some(title), Some(suffix)) => Some(format!(" {} {} ", title, suffix)),
+        (Some(title), None) => Some(format!(" {} ", title)),
+        (None, Some(suffix)) => Some(format!(" {} ", suffix)),
+        (None, None) => None,
+    };
+    if let Some(title) = title {
+        block = block.title(Span::styled(title, theme.text));
+    }
+    block
+}
+
+/// A sparkline of a `TimeSeries` over a sliding window.
+///
+/// The window is split into one bucket per column; the title shows the
+/// latest value.
+///
+/// # Example
+///
+/// ```ignore
+/// use std::time::Duration;
+/// use interax_tui_fwk::{Aggregation, SeriesSparkline, TimeSeries};
+///
+/// let rate = TimeSeries::new(10_000).retention(Duration::from_secs(60));
+/// let sparkline = SeriesSparkline::new(rate.clone())
+///     .title("Messages/s")
+///     .aggregation(Aggregation::Count);
+/// ```
+pub struct SeriesSparkline {
+    series: TimeSeries,
+    title: Option<String>,
+    window: Duration,
+    aggregation: Aggregation,
+    unit: String,
+    style: Option<Style>,
+}
+
+impl SeriesSparkline {
+    /// Create a sparkline over the last minute of `series`.
+    pub fn new(series: TimeSeries) -> Self {
+        Self {
+            series,
+            title: None,
+            window: Duration::from_secs(60),
+            aggregation: Aggregation::Mean,
+            unit: String::new(),
+            style: None,
+        }
+    }
+
+    /// Set the title shown in the border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the time window shown.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set how samples in the same column are combined.
+    pub fn aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Set the unit appended to the latest value.
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Set the bar style (defaults to the theme accent).
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Render the sparkline with an explicit theme.
+    ///
+    /// Use this when the chart is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let latest = self
+            .series
+            .latest()
+            .map(|sample| format_value(sample.value, &self.unit));
+        let block = chart_block(self.title.as_deref(), latest, theme);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let values = self
+            .series
+            .downsample(self.window, inner.width as usize, self.aggregation);
+        let max = values.iter().flatten().copied().fold(0.0, f64::max);
+        // Sparklines take integer heights; negative values render empty
+        let bars: Vec<Option<u64>> = values
+            .iter()
+            .map(|value| {
+                value.map(|v| {
+                    if max > 0.0 {
+                        (v.max(0.0) / max * SPARKLINE_SCALE) as u64
+                    } else {
+                        0
+                    }
+                })
+            })
+            .collect();
+        let sparkline = Sparkline::default()
+            .data(bars)
+            .max(SPARKLINE_SCALE as u64)
+            .style(self.style.unwrap_or(theme.accent));
+        frame.render_widget(sparkline, inner);
+    }
+}
+
+impl Component for SeriesSparkline {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        self.render(frame, area, ctx.theme());
+    }
+}
+
+/// A named series in a `SeriesLineChart`.
+struct ChartLine {
+    name: String,
+    series: TimeSeries,
+    style: Option<Style>,
+}
+
+/// A line chart of one or more `TimeSeries` over a sliding window.
+///
+/// The x axis shows seconds before now. The y axis fits the visible data
+/// unless fixed bounds are set.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::SeriesLineChart;
+///
+/// let chart = SeriesLineChart::new()
+///     .title("Latency")
+///     .unit("ms")
+///     .line("p50", p50.clone())
+///     .line("p99", p99.clone());
+/// ```
+pub struct SeriesLineChart {
+    lines: Vec<ChartLine>,
+    title: Option<String>,
+    window: Duration,
+    aggregation: Aggregation,
+    unit: String,
+    y_bounds: Option<[f64; 2]>,
+}
+
+impl SeriesLineChart {
+    /// Create an empty chart over the last minute.
+    pub fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            title: None,
+            window: Duration::from_secs(60),
+            aggregation: Aggregation::Mean,
+            unit: String::new(),
+            y_bounds: None,
+        }
+    }
+
+    /// Add a series drawn in the next default color.
+    pub fn line(mut self, name: impl Into<String>, series: TimeSeries) -> Self {
+        self.lines.push(ChartLine {
+            name: name.into(),
+            series,
+            style: None,
+        });
+        self
+    }
+
+    /// Add a series drawn with `style`.
+    pub fn line_styled(
+        mut self,
+        name: impl Into<String>,
+        series: TimeSeries,
+        style: Style,
+    ) -> Self {
+        self.lines.push(ChartLine {
+            name: name.into(),
+            series,
+            style: Some(style),
+        });
+        self
+    }
+
+    /// Set the title shown in the border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the time window shown.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set how samples in the same column are combined.
+    pub fn aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Set the unit shown on the y axis.
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Fix the y axis bounds instead of fitting the data.
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = Some([min, max]);
+        self
+    }
+
+    /// Render the chart with an explicit theme.
+    ///
+    /// Use this when the chart is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = chart_block(self.title.as_deref(), None, theme);
+        let buckets = block.inner(area).width.saturating_sub(8).max(1) as usize;
+        let window = self.window.as_secs_f64();
+        let bucket_width = window / buckets as f64;
+
+        let points: Vec<Vec<(f64, f64)>> = self
+            .lines
+            .iter()
+            .map(|line| {
+                line.series
+                    .downsample(self.window, buckets, self.aggregation)
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, value)| {
+                        value.map(|v| (-window + (i as f64 + 0.5) * bucket_width, v))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let [min, max] = self.y_bounds.unwrap_or_else(|| {
+            let (min, max) = points
+                .iter()
+                .flatten()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), (_, v)| {
+                    (min.min(*v), max.max(*v))
+                });
+            if min > max {
+                [0.0, 1.0]
+            } else if min == max {
+                [min - 1.0, max + 1.0]
+            } else {
+                [min, max]
+            }
+        });
+
+        let datasets = self
+            .lines
+            .iter()
+            .zip(&points)
+            .enumerate()
+            .map(|(i, (line, points))| {
+                let style = line
+                    .style
+                    .unwrap_or_else(|| Style::default().fg(LINE_COLORS[i % LINE_COLORS.len()]));
+                Dataset::default()
+                    .name(line.name.clone())
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(style)
+                    .data(points)
+            })
+            .collect();
+
+        let x_axis = Axis::default()
+            .style(theme.muted)
+            .bounds([-window, 0.0])
+            .labels([format!("-{}s", self.window.as_secs()), "now".to_string()]);
+        let y_axis = Axis::default()
+            .style(theme.muted)
+            .bounds([min, max])
+            .labels([format_value(min, &self.unit), format_value(max, &self.unit)]);
+
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(x_axis)
+            .y_axis(y_axis);
+        frame.render_widget(chart, area);
+    }
+}
+
+impl Default for SeriesLineChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for SeriesLineChart {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        self.render(frame, area, ctx.theme());
+    }
+}
+
+/// A histogram of the values of a `TimeSeries` over a sliding window.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::SeriesHistogram;
+///
+/// let histogram = SeriesHistogram::new(latency.clone())
+///     .title("Latency distribution")
+///     .bins(8)
+///     .unit("ms");
+/// ```
+pub struct SeriesHistogram {
+    series: TimeSeries,
+    title: Option<String>,
+    window: Duration,
+    bins: usize,
+    unit: String,
+}
+
+impl SeriesHistogram {
+    /// Create a 10-bin histogram over the last minute of `series`.
+    pub fn new(series: TimeSeries) -> Self {
+        Self {
+            series,
+            title: None,
+            window: Duration::from_secs(60),
+            bins: 10,
+            unit: String::new(),
+        }
+    }
+
+    /// Set the title shown in the border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the time window shown.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set the number of bins.
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = bins.max(1);
+        self
+    }
+
+    /// Set the unit of the bin labels.
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Render the histogram with an explicit theme.
+    ///
+    /// Use this when the chart is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = chart_block(self.title.as_deref(), None, theme);
+        let inner = block.inner(area);
+        let histogram = self.series.histogram(self.window, self.bins);
+        let bin_width = histogram.bin_width();
+
+        let bars: Vec<Bar> = histogram
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let lower = histogram.min + i as f64 * bin_width;
+                Bar::default()
+                    .value(*count)
+                    .label(format_value(lower, &self.unit))
+            })
+            .collect();
+
+        let bins = self.bins as u16;
+        let bar_width = (inner.width / bins.max(1)).saturating_sub(1).max(1);
+        let chart = BarChart::default()
+            .block(block)
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(bar_width)
+            .bar_gap(1)
+            .bar_style(theme.accent)
+            .value_style(theme.cursor_focused)
+            .label_style(theme.muted);
+        frame.render_widget(chart, area);
+    }
+}
+
+impl Component for SeriesHistogram {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        self.render(frame, area, ctx.theme());
+    }
+}
+
+/// A gauge showing the latest value of a `TimeSeries` within a range.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::SeriesGauge;
+///
+/// let queue = SeriesGauge::new(queue_depth.clone())
+///     .title("Queue depth")
+///     .range(0.0, 1024.0);
+/// ```
+pub struct SeriesGauge {
+    series: TimeSeries,
+    title: Option<String>,
+    min: f64,
+    max: f64,
+    unit: String,
+}
+
+impl SeriesGauge {
+    /// Create a gauge over the range `0..=100`.
+    pub fn new(series: TimeSeries) -> Self {
+        Self {
+            series,
+            title: None,
+            min: 0.0,
+            max: 100.0,
+            unit: String::new(),
+        }
+    }
+
+    /// Set the title shown in the border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the range mapped to an empty and a full gauge.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set the unit appended to the value.
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Render the gauge with an explicit theme.
+    ///
+    /// Use this when the chart is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let block = chart_block(self.title.as_deref(), None, theme);
+        let latest = self.series.latest().map(|sample| sample.value);
+        let span = self.max - self.min;
+        let ratio = match latest {
+            Some(value) if span > 0.0 => ((value - self.min) / span).clamp(0.0, 1.0),
+            _ => 0.0,
+        };
+        let label = latest.map_or_else(|| "–".to_string(), |v| format_value(v, &self.unit));
+        let gauge = Gauge::default()
+            .block(block)
+            .gauge_style(theme.accent)
+            .ratio(ratio)
+            .label(label)
+            .use_unicode(true);
+        frame.render_widget(gauge, area);
+    }
+}
+
+impl Component for SeriesGauge {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        self.render(frame, area, ctx.theme());
+    }
+}
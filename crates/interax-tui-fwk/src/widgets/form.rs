@@ -0,0 +1,1008 @@
+//! Form widget with labeled fields, validation and typed submission.
+
+use std::cell::Cell;
+use std::fmt;
+
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::block_title;
+use super::input::TextInput;
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::{Event, KeyCode, KeyModifiers};
+use crate::focus::{EventResult, FocusManager};
+use crate::theme::Theme;
+use crate::unicode::display_width;
+
+/// Focus ID of the submit button inside a form's `FocusManager`.
+const SUBMIT_ID: &str = ":submit";
+
+/// Validator for a single field value.
+pub type FieldValidator = Box<dyn Fn(&FieldValue) -> Result<(), String> + Send>;
+
+/// Validator run against all values of a form.
+pub type FormValidator = Box<dyn Fn(&FormValues) -> Result<(), FormError> + Send>;
+
+type SubmitFn<T> = Box<dyn Fn(&FormValues) -> Result<T, FormError> + Send>;
+
+/// The value of a form field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// Value of a text field.
+    Text(String),
+    /// Value of a number field, `None` if left empty.
+    Number(Option<f64>),
+    /// Value of a checkbox.
+    Bool(bool),
+    /// Selected option of a select or radio field.
+    Choice {
+        /// Index of the selected option.
+        index: usize,
+        /// Label of the selected option.
+        value: String,
+    },
+}
+
+/// The values of all fields of a form, in field order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormValues {
+    values: Vec<(String, FieldValue)>,
+}
+
+impl FormValues {
+    /// Get the value of a field by name.
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Get the value of a text field.
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match self.get(name)? {
+            FieldValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a number field.
+    ///
+    /// Returns `None` if the field is missing or was left empty.
+    pub fn number(&self, name: &str) -> Option<f64> {
+        match self.get(name)? {
+            FieldValue::Number(number) => *number,
+            _ => None,
+        }
+    }
+
+    /// Get the value of a checkbox.
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        match self.get(name)? {
+            FieldValue::Bool(checked) => Some(*checked),
+            _ => None,
+        }
+    }
+
+    /// Get the selected option of a select or radio field.
+    pub fn choice(&self, name: &str) -> Option<&str> {
+        match self.get(name)? {
+            FieldValue::Choice { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the selected option index of a select or radio field.
+    pub fn choice_index(&self, name: &str) -> Option<usize> {
+        match self.get(name)? {
+            FieldValue::Choice { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Iterate over `(name, value)` pairs in field order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+/// A validation or submission error.
+///
+/// Errors with a `field` are shown inline below that field; others are
+/// shown below the submit button.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormError {
+    /// Name of the field the error belongs to, if any.
+    pub field: Option<String>,
+    /// Error message.
+    pub message: String,
+}
+
+impl FormError {
+    /// Create an error for the whole form.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            field: None,
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for a specific field.
+    pub fn field(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: Some(name.into()),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FormError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for FormError {}
+
+enum FieldKind {
+    Text(TextInput),
+    Number {
+        input: TextInput,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Checkbox(bool),
+    Select {
+        options: Vec<String>,
+        selected: usize,
+    },
+    Radio {
+        options: Vec<String>,
+        selected: usize,
+    },
+}
+
+/// A labeled field of a `Form`.
+///
+/// Create fields with `Field::text`, `Field::number`, `Field::checkbox`,
+/// `Field::select` or `Field::radio` and configure them builder-style.
+/// Builder methods that don't apply to a field's kind are ignored.
+pub struct Field {
+    name: String,
+    label: String,
+    kind: FieldKind,
+    required: bool,
+    validators: Vec<FieldValidator>,
+    error: Option<String>,
+    /// Whether the field has been validated once (on blur or submit).
+    /// Touched fields re-validate on every change.
+    touched: bool,
+}
+
+impl Field {
+    fn new(name: impl Into<String>, label: impl Into<String>, kind: FieldKind) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            kind,
+            required: false,
+            validators: Vec::new(),
+            error: None,
+            touched: false,
+        }
+    }
+
+    /// Create a single-line text field.
+    pub fn text(name: impl Into<String>, label: impl Into<String>) -> Self {
+        let name = name.into();
+        let input = TextInput::new(name.clone());
+        Self::new(name, label, FieldKind::Text(input))
+    }
+
+    /// Create a number field.
+    pub fn number(name: impl Into<String>, label: impl Into<String>) -> Self {
+        let name = name.into();
+        let input = TextInput::new(name.clone());
+        Self::new(
+            name,
+            label,
+            FieldKind::Number {
+                input,
+                min: None,
+                max: None,
+            },
+        )
+    }
+
+    /// Create a checkbox.
+    pub fn checkbox(name: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(name, label, FieldKind::Checkbox(false))
+    }
+
+    /// Create a select field that cycles through `options`.
+    pub fn select<I, S>(name: impl Into<String>, label: impl Into<String>, options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let options = options.into_iter().map(Into::into).collect();
+        Self::new(
+            name,
+            label,
+            FieldKind::Select {
+                options,
+                selected: 0,
+            },
+        )
+    }
+
+    /// Create a radio group showing all `options` side by side.
+    pub fn radio<I, S>(name: impl Into<String>, label: impl Into<String>, options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let options = options.into_iter().map(Into::into).collect();
+        Self::new(
+            name,
+            label,
+            FieldKind::Radio {
+                options,
+                selected: 0,
+            },
+        )
+    }
+
+    /// Set the initial value of a text or number field.
+    pub fn value(mut self, value: &str) -> Self {
+        if let Some(input) = self.input_mut() {
+            input.set_text(value);
+            input.buffer_mut().clear_history();
+        }
+        self
+    }
+
+    /// Set the placeholder of a text or number field.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        if let Some(input) = self.input_mut() {
+            let taken = std::mem::replace(input, TextInput::new(""));
+            *input = taken.placeholder(placeholder);
+        }
+        self
+    }
+
+    /// Mask a text field with `mask` (for secrets).
+    pub fn masked(mut self, mask: char) -> Self {
+        if let FieldKind::Text(input) = &mut self.kind {
+            let taken = std::mem::replace(input, TextInput::new(""));
+            *input = taken.masked(mask);
+        }
+        self
+    }
+
+    /// Set the allowed range of a number field.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        if let FieldKind::Number {
+            min: field_min,
+            max: field_max,
+            ..
+        } = &mut self.kind
+        {
+            *field_min = Some(min);
+            *field_max = Some(max);
+        }
+        self
+    }
+
+    /// Set the initial state of a checkbox.
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let FieldKind::Checkbox(value) = &mut self.kind {
+            *value = checked;
+        }
+        self
+    }
+
+    /// Set the initially selected option of a select or radio field.
+    pub fn selected(mut self, index: usize) -> Self {
+        if let FieldKind::Select { options, selected } | FieldKind::Radio { options, selected } =
+            &mut self.kind
+        {
+            *selected = index.min(options.len().saturating_sub(1));
+        }
+        self
+    }
+
+    /// Require a non-empty text or number field.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Add a validator for this field's value.
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&FieldValue) -> Result<(), String> + Send + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Name of the field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Label of the field.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The current inline error, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn input_mut(&mut self) -> Option<&mut TextInput> {
+        match &mut self.kind {
+            FieldKind::Text(input) | FieldKind::Number { input, .. } => Some(input),
+            _ => None,
+        }
+    }
+
+    /// Parse the current value without running validators.
+    fn parse(&self) -> Result<FieldValue, String> {
+        match &self.kind {
+            FieldKind::Text(input) => {
+                if self.required && input.text().trim().is_empty() {
+                    return Err("required".to_string());
+                }
+                Ok(FieldValue::Text(input.text().to_string()))
+            }
+            FieldKind::Number { input, min, max } => {
+                let text = input.text().trim();
+                if text.is_empty() {
+                    if self.required {
+                        return Err("required".to_string());
+                    }
+                    return Ok(FieldValue::Number(None));
+                }
+                let number: f64 = text.parse().map_err(|_| "expected a number".to_string())?;
+                if let Some(min) = min.filter(|min| number < *min) {
+                    return Err(format!("must be at least {}", min));
+                }
+                if let Some(max) = max.filter(|max| number > *max) {
+                    return Err(format!("must be at most {}", max));
+                }
+                Ok(FieldValue::Number(Some(number)))
+            }
+            FieldKind::Checkbox(checked) => Ok(FieldValue::Bool(*checked)),
+            FieldKind::Select { options, selected } | FieldKind::Radio { options, selected } => {
+                options
+                    .get(*selected)
+                    .map(|value| FieldValue::Choice {
+                        index: *selected,
+                        value: value.clone(),
+                    })
+                    .ok_or_else(|| "no options available".to_string())
+            }
+        }
+    }
+
+    /// Parse and validate the value, updating the inline error.
+    fn validate(&mut self) -> Option<FieldValue> {
+        self.touched = true;
+        let result = self.parse().and_then(|value| {
+            self.validators
+                .iter()
+                .try_for_each(|validate| validate(&value))
+                .map(|_| value)
+        });
+        match result {
+            Ok(value) => {
+                self.error = None;
+                Some(value)
+            }
+            Err(error) => {
+                self.error = Some(error);
+                None
+            }
+        }
+    }
+
+    fn handle_input(&mut self, event: &Event) -> EventResult {
+        let result = match &mut self.kind {
+            FieldKind::Text(input) | FieldKind::Number { input, .. } => input.handle_input(event),
+            FieldKind::Checkbox(checked) => {
+                if event.is_key(KeyCode::Char(' ')) {
+                    *checked = !*checked;
+                    EventResult::Handled
+                } else {
+                    EventResult::Unhandled
+                }
+            }
+            FieldKind::Select { options, selected } | FieldKind::Radio { options, selected } => {
+                let len = options.len().max(1);
+                if event.is_key(KeyCode::Left) {
+                    *selected = (*selected + len - 1) % len;
+                    EventResult::Handled
+                } else if event.is_key(KeyCode::Right) || event.is_key(KeyCode::Char(' ')) {
+                    *selected = (*selected + 1) % len;
+                    EventResult::Handled
+                } else {
+                    EventResult::Unhandled
+                }
+            }
+        };
+        if result.is_handled() && self.touched {
+            self.validate();
+        }
+        result
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, current: bool, focused: bool, theme: &Theme) {
+        let highlight = if current {
+            theme.cursor(focused)
+        } else {
+            theme.text
+        };
+        let line = match &self.kind {
+            FieldKind::Text(input) | FieldKind::Number { input, .. } => {
+                input.render_text(frame, area, current && focused, theme);
+                return;
+            }
+            FieldKind::Checkbox(checked) => {
+                Line::styled(if *checked { "[x]" } else { "[ ]" }, highlight)
+            }
+            FieldKind::Select { options, selected } => {
+                let value = options.get(*selected).map(String::as_str).unwrap_or("");
+                Line::styled(format!("◂ {} ▸", value), highlight)
+            }
+            FieldKind::Radio { options, selected } => {
+                let mut spans = Vec::with_capacity(options.len() * 2);
+                for (i, option) in options.iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Span::raw("  "));
+                    }
+                    let (marker, style) = if i == *selected {
+                        ("(•)", if current { highlight } else { theme.accent })
+                    } else {
+                        ("( )", theme.text)
+                    };
+                    spans.push(Span::styled(format!("{} {}", marker, option), style));
+                }
+                Line::from(spans)
+            }
+        };
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+/// A row of the rendered form.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FormRow {
+    Field(usize),
+    FieldError(usize),
+    Blank,
+    Submit,
+    FormError,
+}
+
+/// A focusable form of labeled fields.
+///
+/// Fields are laid out one per row with their labels aligned. Focus moves
+/// between fields (and the submit button) through the form's own
+/// `FocusManager`. Field validators run when a field loses focus and on
+/// submit, after which the field re-validates on every change. Form
+/// validators run on submit and can check several fields at once.
+///
+/// On a successful submit the values are passed to the `on_submit`
+/// callback, whose typed result is kept until `take_submitted()` is called.
+///
+/// As a `Component`, the form only handles events while it is focused.
+/// Esc is left unhandled so the parent can cancel.
+///
+/// # Key bindings
+///
+/// - Tab/Down: Next field
+/// - Shift+Tab/Up: Previous field
+/// - Enter: Submit
+/// - Space: Toggle a checkbox
+/// - Left/Right: Change a select or radio field
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::{Field, Form, FormError};
+///
+/// struct Invoke {
+///     endpoint: u16,
+///     command: String,
+///     timed: bool,
+/// }
+///
+/// let form = Form::new("invoke")
+///     .title("Invoke command")
+///     .field(Field::number("endpoint", "Endpoint").range(0.0, 65534.0).required())
+///     .field(Field::select("command", "Command", ["On", "Off", "Toggle"]))
+///     .field(Field::checkbox("timed", "Timed"))
+///     .field(Field::number("timeout", "Timeout (ms)").value("1000"))
+///     .validator(|values| match (values.bool("timed"), values.number("timeout")) {
+///         (Some(true), Some(t)) if t <= 0.0 => {
+///             Err(FormError::field("timeout", "timed commands need a timeout"))
+///         }
+///         _ => Ok(()),
+///     })
+///     .on_submit(|values| {
+///         Ok(Invoke {
+///             endpoint: values.number("endpoint").unwrap_or_default() as u16,
+///             command: values.choice("command").unwrap_or_default().to_string(),
+///             timed: values.bool("timed").unwrap_or_default(),
+///         })
+///     });
+///
+/// // After forwarding an event:
+/// if let Some(invoke) = form.take_submitted() {
+///     // send the command
+/// }
+/// ```
+pub struct Form<T = FormValues> {
+    focus_id: String,
+    title: Option<String>,
+    submit_label: String,
+    fields: Vec<Field>,
+    validators: Vec<FormValidator>,
+    on_submit: SubmitFn<T>,
+    focus: FocusManager,
+    error: Option<String>,
+    submitted: Option<T>,
+    /// First visible row, updated while drawing.
+    offset: Cell<usize>,
+}
+
+impl Form<FormValues> {
+    /// Create a new empty form with the given focus ID.
+    ///
+    /// Submitting yields the raw `FormValues` until `on_submit` is set.
+    pub fn new(focus_id: impl Into<String>) -> Self {
+        let mut focus = FocusManager::new();
+        focus.register(SUBMIT_ID);
+        focus.set_focus(SUBMIT_ID);
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            submit_label: "Submit".to_string(),
+            fields: Vec::new(),
+            validators: Vec::new(),
+            on_submit: Box::new(|values| Ok(values.clone())),
+            focus,
+            error: None,
+            submitted: None,
+            offset: Cell::new(0),
+        }
+    }
+}
+
+impl<T> Form<T> {
+    /// Set the title shown in the form border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the label of the submit button.
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Add a field after the existing ones.
+    pub fn field(mut self, field: Field) -> Self {
+        self.focus.register_at(&field.name, Some(self.fields.len()));
+        if self.fields.is_empty() {
+            self.focus.set_focus(&field.name);
+        }
+        self.fields.push(field);
+        self
+    }
+
+    /// Add a validator run against all values on submit.
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&FormValues) -> Result<(), FormError> + Send + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Set the callback that turns valid values into the submitted result.
+    ///
+    /// Returning an error shows it like a validation error.
+    pub fn on_submit<U, F>(self, on_submit: F) -> Form<U>
+    where
+        F: Fn(&FormValues) -> Result<U, FormError> + Send + 'static,
+    {
+        Form {
+            focus_id: self.focus_id,
+            title: self.title,
+            submit_label: self.submit_label,
+            fields: self.fields,
+            validators: self.validators,
+            on_submit: Box::new(on_submit),
+            focus: self.focus,
+            error: self.error,
+            submitted: None,
+            offset: self.offset,
+        }
+    }
+
+    /// Get the fields in order.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Get a field by name.
+    pub fn get_field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Name of the focused field, or `None` if the submit button is focused.
+    pub fn focused_field(&self) -> Option<&str> {
+        self.focus.focused_id().filter(|id| *id != SUBMIT_ID)
+    }
+
+    /// Focus a field by name.
+    pub fn focus_field(&mut self, name: &str) -> bool {
+        self.focus.set_focus(name)
+    }
+
+    /// The form-level error from the last submit, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Take the result of the last successful submit.
+    pub fn take_submitted(&mut self) -> Option<T> {
+        self.submitted.take()
+    }
+
+    /// Validate every field and run the form validators.
+    ///
+    /// Shows inline errors and returns the values if everything is valid.
+    pub fn validate(&mut self) -> Result<FormValues, FormError> {
+        self.error = None;
+        let mut values = FormValues::default();
+        let mut first_error = None;
+        for field in &mut self.fields {
+            match field.validate() {
+                Some(value) => values.values.push((field.name.clone(), value)),
+                None => {
+                    first_error.get_or_insert_with(|| {
+                        FormError::field(&field.name, field.error.clone().unwrap_or_default())
+                    });
+                }
+            }
+        }
+        if let Some(error) = first_error {
+            return Err(error);
+        }
+
+        let result = self
+            .validators
+            .iter()
+            .try_for_each(|validate| validate(&values));
+        if let Err(error) = result {
+            self.show_error(&error);
+            return Err(error);
+        }
+        Ok(values)
+    }
+
+    /// Validate and submit the form.
+    ///
+    /// On success the result is available from `take_submitted()` and
+    /// `true` is returned. On failure the first invalid field is focused.
+    pub fn submit(&mut self) -> bool {
+        let result = self.validate().and_then(|values| (self.on_submit)(&values));
+        match result {
+            Ok(submitted) => {
+                self.submitted = Some(submitted);
+                true
+            }
+            Err(error) => {
+                self.show_error(&error);
+                if let Some(name) = &error.field {
+                    self.focus.set_focus(name);
+                }
+                false
+            }
+        }
+    }
+
+    fn show_error(&mut self, error: &FormError) {
+        let field = error
+            .field
+            .as_deref()
+            .and_then(|name| self.fields.iter_mut().find(|field| field.name == name));
+        match field {
+            Some(field) => field.error = Some(error.message.clone()),
+            None => self.error = Some(error.message.clone()),
+        }
+    }
+
+    fn focused_field_mut(&mut self) -> Option<&mut Field> {
+        let name = self.focus.focused_id()?;
+        self.fields.iter_mut().find(|field| field.name == name)
+    }
+
+    /// Move focus, validating the field that loses it.
+    fn move_focus(&mut self, forward: bool) {
+        if let Some(field) = self.focused_field_mut() {
+            field.validate();
+        }
+        if forward {
+            self.focus.focus_next();
+        } else {
+            self.focus.focus_prev();
+        }
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the form is embedded in a `Tab`; the caller is
+    /// responsible for only forwarding events while the form is focused.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        if let Event::Key(key) = event {
+            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+            match key.code {
+                KeyCode::Tab if shift => self.move_focus(false),
+                KeyCode::Tab | KeyCode::Down => self.move_focus(true),
+                KeyCode::BackTab | KeyCode::Up => self.move_focus(false),
+                KeyCode::Enter => {
+                    self.submit();
+                }
+                _ => {
+                    return self
+                        .focused_field_mut()
+                        .map_or(EventResult::Unhandled, |field| field.handle_input(event));
+                }
+            }
+            return EventResult::Handled;
+        }
+
+        self.focused_field_mut()
+            .map_or(EventResult::Unhandled, |field| field.handle_input(event))
+    }
+
+    /// Layout of the form rows, top to bottom.
+    fn rows(&self) -> Vec<FormRow> {
+        let mut rows = Vec::with_capacity(self.fields.len() + 3);
+        for (i, field) in self.fields.iter().enumerate() {
+            rows.push(FormRow::Field(i));
+            if field.error.is_some() {
+                rows.push(FormRow::FieldError(i));
+            }
+        }
+        rows.push(FormRow::Blank);
+        rows.push(FormRow::Submit);
+        if self.error.is_some() {
+            rows.push(FormRow::FormError);
+        }
+        rows
+    }
+
+    /// Render the form with an explicit focus state and theme.
+    ///
+    /// Use this when the form is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border(focused));
+        if let Some(title) = &self.title {
+            block = block.title(block_title(title, "", area.width));
+        }
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let rows = self.rows();
+        let focused_id = self.focus.focused_id();
+        let current_row = rows.iter().position(|row| match row {
+            FormRow::Field(i) => focused_id == Some(self.fields[*i].name.as_str()),
+            FormRow::Submit => focused_id == Some(SUBMIT_ID),
+            _ => false,
+        });
+
+        // Scroll so the focused row (and its error) stays visible
+        let height = inner.height as usize;
+        let mut offset = self.offset.get().min(rows.len().saturating_sub(1));
+        if let Some(current) = current_row {
+            let has_error = matches!(rows.get(current + 1), Some(FormRow::FieldError(_)));
+            let last = current + usize::from(has_error);
+            if current < offset {
+                offset = current;
+            } else if last >= offset + height {
+                offset = (last + 1).saturating_sub(height);
+            }
+        }
+        self.offset.set(offset);
+
+        let label_width = self
+            .fields
+            .iter()
+            .map(|field| display_width(&field.label))
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let value_x = inner.x + (label_width as u16).min(inner.width);
+        let value_width = inner.width.saturating_sub(label_width as u16);
+
+        for (y, row) in (inner.y..inner.bottom()).zip(rows.iter().skip(offset)) {
+            let line_area = Rect::new(inner.x, y, inner.width, 1);
+            let value_area = Rect::new(value_x, y, value_width, 1);
+            match *row {
+                FormRow::Field(i) => {
+                    let field = &self.fields[i];
+                    let current = focused_id == Some(field.name.as_str());
+                    let label_style = if current && focused {
+                        theme.accent
+                    } else {
+                        theme.text
+                    };
+                    let label =
+                        Paragraph::new(Line::styled(format!("{}:", field.label), label_style));
+                    frame.render_widget(label, line_area);
+                    field.render(frame, value_area, current, focused, theme);
+                }
+                FormRow::FieldError(i) => {
+                    let error = self.fields[i].error.as_deref().unwrap_or_default();
+                    let line = Line::styled(format!("✗ {}", error), theme.error);
+                    frame.render_widget(Paragraph::new(line), value_area);
+                }
+                FormRow::Blank => {}
+                FormRow::Submit => {
+                    let style = if focused_id == Some(SUBMIT_ID) {
+                        theme.cursor(focused)
+                    } else {
+                        theme.accent
+                    };
+                    let button = Line::styled(format!("[ {} ]", self.submit_label), style);
+                    frame.render_widget(Paragraph::new(button), value_area);
+                }
+                FormRow::FormError => {
+                    let error = self.error.as_deref().unwrap_or_default();
+                    let line = Line::styled(format!("✗ {}", error), theme.error);
+                    frame.render_widget(Paragraph::new(line), value_area);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Send> Component for Form<T> {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if !ctx.focus().is_focused(&self.focus_id) {
+            return EventResult::Unhandled;
+        }
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_str(form: &mut Form<impl Sized>, text: &str) {
+        for c in text.chars() {
+            form.handle_input(&key(KeyCode::Char(c)));
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Invoke {
+        endpoint: u16,
+        command: String,
+        timed: bool,
+    }
+
+    fn form() -> Form<Invoke> {
+        Form::new("invoke")
+            .field(
+                Field::number("endpoint", "Endpoint")
+                    .range(0.0, 10.0)
+                    .required(),
+            )
+            .field(Field::select("command", "Command", ["On", "Off", "Toggle"]))
+            .field(Field::checkbox("timed", "Timed"))
+            .field(Field::text("timeout", "Timeout"))
+            .validator(|values| {
+                if values.bool("timed") == Some(true) && values.text("timeout") == Some("") {
+                    return Err(FormError::field("timeout", "required for timed commands"));
+                }
+                Ok(())
+            })
+            .on_submit(|values| {
+                Ok(Invoke {
+                    endpoint: values.number("endpoint").unwrap_or_default() as u16,
+                    command: values.choice("command").unwrap_or_default().to_string(),
+                    timed: values.bool("timed").unwrap_or_default(),
+                })
+            })
+    }
+
+    #[test]
+    fn test_form_focus_and_field_validation() {
+        let mut form = form();
+        assert_eq!(form.focused_field(), Some("endpoint"));
+
+        type_str(&mut form, "42");
+        form.handle_input(&key(KeyCode::Tab));
+        assert_eq!(form.focused_field(), Some("command"));
+        assert_eq!(
+            form.get_field("endpoint").and_then(Field::error),
+            Some("must be at most 10")
+        );
+
+        // Touched fields re-validate on change
+        form.handle_input(&key(KeyCode::BackTab));
+        form.handle_input(&key(KeyCode::Backspace));
+        assert_eq!(form.get_field("endpoint").and_then(Field::error), None);
+
+        // Wraps around through the submit button
+        form.handle_input(&key(KeyCode::BackTab));
+        assert_eq!(form.focused_field(), None);
+    }
+
+    #[test]
+    fn test_form_cross_field_validation_and_submit() {
+        let mut form = form();
+        type_str(&mut form, "3");
+        form.handle_input(&key(KeyCode::Tab));
+        form.handle_input(&key(KeyCode::Right));
+        form.handle_input(&key(KeyCode::Tab));
+        form.handle_input(&key(KeyCode::Char(' ')));
+
+        form.handle_input(&key(KeyCode::Enter));
+        assert!(form.take_submitted().is_none());
+        assert_eq!(form.focused_field(), Some("timeout"));
+        assert_eq!(
+            form.get_field("timeout").and_then(Field::error),
+            Some("required for timed commands")
+        );
+
+        type_str(&mut form, "500");
+        form.handle_input(&key(KeyCode::Enter));
+        assert_eq!(
+            form.take_submitted(),
+            Some(Invoke {
+                endpoint: 3,
+                command: "Off".to_string(),
+                timed: true,
+            })
+        );
+    }
+}
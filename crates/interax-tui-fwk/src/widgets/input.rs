@@ -0,0 +1,318 @@
+//! Single-line text input widget.
+
+use std::cell::Cell;
+
+use ratatui::{
+    layout::{Position, Rect},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::text::{styled_line, TextBuffer, Validator};
+use super::{block_title, is_copy_key};
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::Event;
+use crate::focus::EventResult;
+#[cfg(feature = "persist")]
+use crate::persist::{Persist, Session};
+use crate::theme::Theme;
+
+/// A focusable single-line text input.
+///
+/// Supports grapheme-aware editing, word navigation, selection,
+/// undo/redo, paste, placeholder text, masking for secrets and
+/// validation callbacks.
+///
+/// As a `Component`, the input only handles events while it is focused.
+/// Enter, Tab and Esc are left unhandled so the parent can submit or
+/// move focus.
+///
+/// # Key bindings
+///
+/// - Left/Right, Home/End: Move the cursor
+/// - Ctrl+Left/Right, Alt+b/f: Move by word
+/// - Shift + movement: Extend the selection
+/// - Backspace/Delete: Delete a character (Ctrl: a word)
+/// - Ctrl+W: Delete the previous word
+/// - Ctrl+A: Select all
+/// - Ctrl+Z / Ctrl+Y: Undo / redo
+/// - Alt+C, Ctrl+Insert: Copy the selection (or the whole text)
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::TextInput;
+///
+/// let password = TextInput::new("password")
+///     .title("Password")
+///     .placeholder("at least 8 characters")
+///     .masked('•')
+///     .validator(|text| {
+///         if text.len() >= 8 { Ok(()) } else { Err("too short".into()) }
+///     });
+/// ```
+pub struct TextInput {
+    focus_id: String,
+    title: Option<String>,
+    placeholder: Option<String>,
+    mask: Option<char>,
+    buffer: TextBuffer,
+    validator: Option<Validator>,
+    error: Option<String>,
+    /// Horizontal scroll offset in columns, updated while drawing.
+    scroll: Cell<usize>,
+}
+
+impl TextInput {
+    /// Create a new empty input with the given focus ID.
+    pub fn new(focus_id: impl Into<String>) -> Self {
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            placeholder: None,
+            mask: None,
+            buffer: TextBuffer::new(),
+            validator: None,
+            error: None,
+            scroll: Cell::new(0),
+        }
+    }
+
+    /// Set the title shown in the input border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the placeholder shown while the input is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Mask every character with `mask` when rendering (for secrets).
+    pub fn masked(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Set the initial value.
+    pub fn value(mut self, value: &str) -> Self {
+        self.buffer.set_text(value);
+        self.buffer.clear_history();
+        self.validate();
+        self
+    }
+
+    /// Set a validation callback, run on every change.
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self.validate();
+        self
+    }
+
+    /// Replace the placeholder shown while the input is empty.
+    pub fn set_placeholder(&mut self, placeholder: impl Into<String>) {
+        self.placeholder = Some(placeholder.into());
+    }
+
+    /// Get the current text.
+    pub fn text(&self) -> &str {
+        self.buffer.text()
+    }
+
+    /// Replace the text.
+    pub fn set_text(&mut self, text: &str) {
+        self.buffer.set_text(text);
+        self.validate();
+    }
+
+    /// Access the underlying editing buffer.
+    pub fn buffer(&self) -> &TextBuffer {
+        &self.buffer
+    }
+
+    /// Access the underlying editing buffer mutably.
+    ///
+    /// Call `validate()` after editing the buffer directly.
+    pub fn buffer_mut(&mut self) -> &mut TextBuffer {
+        &mut self.buffer
+    }
+
+    /// The current validation error, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Check if the current text passes validation.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Run the validator against the current text.
+    pub fn validate(&mut self) -> bool {
+        self.error = self
+            .validator
+            .as_ref()
+            .and_then(|validate| validate(self.buffer.text()).err());
+        self.error.is_none()
+    }
+
+    /// The text to copy if `event` is the copy key (Alt+C or
+    /// Ctrl+Insert): the selection, or the whole text.
+    ///
+    /// Masked inputs never copy their secret.
+    ///
+    /// As a `Component`, the input copies it to `AppContext::clipboard`.
+    pub fn copy_text(&self, event: &Event) -> Option<String> {
+        if self.mask.is_some() || !is_copy_key(event) {
+            return None;
+        }
+        Some(self.buffer.copy_text().to_string())
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the input is embedded in a `Tab`; the caller is
+    /// responsible for only forwarding events while the input is focused.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        let version = self.buffer.version();
+        let result = match event {
+            Event::Key(key) => self.buffer.handle_key(key, 1),
+            Event::Paste(text) => {
+                self.buffer.insert_str(text);
+                EventResult::Handled
+            }
+            _ => EventResult::Unhandled,
+        };
+        if self.buffer.version() != version {
+            self.validate();
+        }
+        result
+    }
+
+    /// Render the input with an explicit focus state and theme.
+    ///
+    /// Use this when the input is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let border = if self.error.is_some() {
+            theme.error
+        } else {
+            theme.border(focused)
+        };
+        let mut block = Block::default().borders(Borders::ALL).border_style(border);
+        if let Some(title) = &self.title {
+            block = block.title(block_title(title, "", area.width));
+        }
+        if let Some(error) = &self.error {
+            block = block.title_bottom(Line::styled(format!(" {} ", error), theme.error));
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        self.render_text(frame, inner, focused, theme);
+    }
+
+    /// Render only the text line (no border) into `area`.
+    pub(crate) fn render_text(&self, frame: &mut Frame, inner: Rect, focused: bool, theme: &Theme) {
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        if self.buffer.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                frame.render_widget(
+                    Paragraph::new(placeholder.as_str()).style(theme.muted),
+                    inner,
+                );
+            }
+            self.scroll.set(0);
+            if focused {
+                frame.set_cursor_position(Position::new(inner.x, inner.y));
+            }
+            return;
+        }
+
+        let text = self.buffer.text();
+        let selection = self.buffer.selection();
+        let (line, cursor_column) = styled_line(
+            text,
+            0..text.len(),
+            selection.as_ref(),
+            self.mask,
+            self.buffer.cursor(),
+            theme.text,
+            theme.text_selection,
+        );
+
+        // Scroll horizontally so the cursor stays visible
+        let width = inner.width as usize;
+        let mut scroll = self.scroll.get();
+        if cursor_column < scroll {
+            scroll = cursor_column;
+        } else if cursor_column >= scroll + width {
+            scroll = cursor_column + 1 - width;
+        }
+        self.scroll.set(scroll);
+
+        let paragraph = Paragraph::new(line).scroll((0, scroll as u16));
+        frame.render_widget(paragraph, inner);
+
+        if focused {
+            let x = inner.x + (cursor_column - scroll) as u16;
+            frame.set_cursor_position(Position::new(x, inner.y));
+        }
+    }
+}
+
+/// Saves the text.
+#[cfg(feature = "persist")]
+impl Persist for TextInput {
+    type State = String;
+
+    fn save(&self) -> String {
+        self.text().to_string()
+    }
+
+    fn restore(&mut self, state: String) {
+        self.set_text(&state);
+    }
+}
+
+impl Component for TextInput {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if !ctx.focus().is_focused(&self.focus_id) {
+            return EventResult::Unhandled;
+        }
+        if let Some(text) = self.copy_text(event) {
+            ctx.clipboard().copy(text);
+            return EventResult::Handled;
+        }
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+
+    #[cfg(feature = "persist")]
+    fn save_state(&self, session: &mut Session) {
+        session.save(&self.focus_id, self);
+    }
+
+    #[cfg(feature = "persist")]
+    fn restore_state(&mut self, session: &Session) {
+        let key = self.focus_id.clone();
+        session.restore(&key, self);
+    }
+}
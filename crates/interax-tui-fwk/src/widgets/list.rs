@@ -0,0 +1,214 @@
+//! Selectable list widget.
+
+use std::collections::BTreeSet;
+
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use super::{block_title, ScrollCursor};
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::{Event, KeyCode, KeyModifiers};
+use crate::focus::EventResult;
+use crate::theme::Theme;
+use crate::unicode::fit_line;
+
+/// A focusable list with a cursor and optional multi-select.
+///
+/// Only the visible items are rendered, so long lists stay cheap.
+///
+/// # Key bindings
+///
+/// - Up/Down/PageUp/PageDown/Home/End: Move the cursor
+/// - Space: Toggle selection of the cursor item (multi-select only)
+/// - Ctrl+A: Select all items (multi-select only)
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::SelectableList;
+///
+/// let list = SelectableList::new("clusters")
+///     .title("Clusters")
+///     .items(vec!["OnOff".into(), "LevelControl".into()]);
+/// ```
+pub struct SelectableList {
+    focus_id: String,
+    title: Option<String>,
+    items: Vec<String>,
+    cursor: ScrollCursor,
+    multi_select: bool,
+    selected: BTreeSet<usize>,
+}
+
+impl SelectableList {
+    /// Create a new empty list with the given focus ID.
+    pub fn new(focus_id: impl Into<String>) -> Self {
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            items: Vec::new(),
+            cursor: ScrollCursor::default(),
+            multi_select: false,
+            selected: BTreeSet::new(),
+        }
+    }
+
+    /// Set the title shown in the list border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the initial items.
+    pub fn items(mut self, items: Vec<String>) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    /// Enable or disable multi-select.
+    pub fn multi_select(mut self, enabled: bool) -> Self {
+        self.multi_select = enabled;
+        self
+    }
+
+    /// Replace all items and clear the selection.
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.items = items;
+        self.selected.clear();
+        self.cursor.set(self.cursor.cursor(), self.items.len());
+    }
+
+    /// Append an item.
+    pub fn push(&mut self, item: impl Into<String>) {
+        self.items.push(item.into());
+    }
+
+    /// Get all items.
+    pub fn get_items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Get the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the list has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Index of the item under the cursor.
+    pub fn cursor_index(&self) -> Option<usize> {
+        (!self.items.is_empty()).then(|| self.cursor.cursor())
+    }
+
+    /// The item under the cursor.
+    pub fn cursor_item(&self) -> Option<&str> {
+        self.cursor_index().map(|i| self.items[i].as_str())
+    }
+
+    /// Move the cursor to the given index.
+    pub fn set_cursor(&mut self, index: usize) {
+        self.cursor.set(index, self.items.len());
+    }
+
+    /// Indices of the selected items.
+    ///
+    /// Without multi-select this is the cursor item.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        if self.multi_select {
+            self.selected.iter().copied().collect()
+        } else {
+            self.cursor_index().into_iter().collect()
+        }
+    }
+
+    /// Toggle selection of the item under the cursor.
+    pub fn toggle_selected(&mut self) {
+        if let Some(index) = self.cursor_index() {
+            if !self.selected.remove(&index) {
+                self.selected.insert(index);
+            }
+        }
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the list is embedded in a `Tab`.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Unhandled;
+        };
+
+        if self.cursor.handle_key(key.code, self.items.len()) {
+            return EventResult::Handled;
+        }
+
+        match key.code {
+            KeyCode::Char(' ') if self.multi_select => self.toggle_selected(),
+            KeyCode::Char('a')
+                if self.multi_select && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                self.selected = (0..self.items.len()).collect();
+            }
+            _ => return EventResult::Unhandled,
+        }
+        EventResult::Handled
+    }
+
+    /// Render the list with an explicit focus state and theme.
+    ///
+    /// Use this when the list is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border(focused));
+        if let Some(title) = &self.title {
+            block = block.title(block_title(
+                title,
+                if focused { " ●" } else { " ○" },
+                area.width,
+            ));
+        }
+
+        let height = area.height.saturating_sub(2) as usize;
+        let width = area.width.saturating_sub(2) as usize;
+        let visible = self.cursor.visible_range(self.items.len(), height);
+        let cursor = self.cursor.cursor();
+
+        let items: Vec<ListItem> = visible
+            .map(|index| {
+                let style = if index == cursor {
+                    theme.cursor(focused)
+                } else if self.selected.contains(&index) {
+                    theme.selected
+                } else {
+                    theme.text
+                };
+                ListItem::new(fit_line(&self.items[index], width)).style(style)
+            })
+            .collect();
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
+
+impl Component for SelectableList {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut AppContext) -> EventResult {
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+}
@@ -0,0 +1,511 @@
+//! Log viewer widget for records captured by a `LogBuffer`.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use tracing::Level;
+use unicode_width::UnicodeWidthStr;
+
+use super::input::TextInput;
+use super::scroll::{ScrollContent, ScrollOffset, ScrollView};
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext, TabEventContext};
+use crate::event::{Event, KeyCode};
+use crate::focus::EventResult;
+use crate::logging::{LogBuffer, LogRecord};
+use crate::tabs::Tab;
+use crate::theme::Theme;
+
+/// Which records a `LogView` shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Most verbose level shown (`TRACE` shows everything).
+    pub max_level: Level,
+    /// Only show records whose target starts with this prefix.
+    pub target: Option<String>,
+    /// Only show records whose message contains this text (case-insensitive).
+    pub search: Option<String>,
+}
+
+impl LogFilter {
+    /// Check if a record passes the filter.
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        record.level <= self.max_level
+            && self
+                .target
+                .as_deref()
+                .is_none_or(|target| record.target.starts_with(target))
+            && self.search.as_deref().is_none_or(|search| {
+                record
+                    .message
+                    .to_lowercase()
+                    .contains(&search.to_lowercase())
+            })
+    }
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            max_level: Level::TRACE,
+            target: None,
+            search: None,
+        }
+    }
+}
+
+/// Style for a log level.
+fn level_style(level: Level, theme: &Theme) -> Style {
+    match level {
+        Level::ERROR => theme.error,
+        Level::WARN => Style::default().fg(Color::Yellow),
+        Level::INFO => Style::default().fg(Color::Green),
+        Level::DEBUG => Style::default().fg(Color::Blue),
+        Level::TRACE => theme.muted,
+    }
+}
+
+/// The next level in the `l` key cycle, from most to least verbose.
+fn next_level(level: Level) -> Level {
+    match level {
+        Level::TRACE => Level::DEBUG,
+        Level::DEBUG => Level::INFO,
+        Level::INFO => Level::WARN,
+        Level::WARN => Level::ERROR,
+        Level::ERROR => Level::TRACE,
+    }
+}
+
+/// Columns taken by the time, level and separators before the target.
+const PREFIX_WIDTH: usize = "HH:MM:SS.mmm LEVEL ".len();
+
+/// Filtered view of a `LogBuffer`, shown in a `ScrollView`.
+struct LogContent {
+    buffer: LogBuffer,
+    filter: LogFilter,
+    /// When paused, only records up to this sequence number are shown.
+    paused_at: Option<u64>,
+}
+
+impl LogContent {
+    fn is_visible(&self, record: &LogRecord) -> bool {
+        self.paused_at.is_none_or(|seq| record.seq <= seq) && self.filter.matches(record)
+    }
+
+    /// Split `message` into spans, highlighting matches of the search text.
+    fn message_spans<'a>(&self, message: &'a str, theme: &Theme) -> Vec<Span<'a>> {
+        let Some(search) = self.filter.search.as_deref().filter(|s| !s.is_empty()) else {
+            return vec![Span::styled(message, theme.text)];
+        };
+        // Lowercasing can change byte lengths; only highlight when it doesn't
+        let lower = message.to_lowercase();
+        if lower.len() != message.len() {
+            return vec![Span::styled(message, theme.text)];
+        }
+        let search = search.to_lowercase();
+        let mut spans = Vec::new();
+        let mut start = 0;
+        for (index, matched) in lower.match_indices(&search) {
+            if index > start {
+                spans.push(Span::styled(&message[start..index], theme.text));
+            }
+            let end = index + matched.len();
+            spans.push(Span::styled(&message[index..end], theme.text_selection));
+            start = end;
+        }
+        if start < message.len() {
+            spans.push(Span::styled(&message[start..], theme.text));
+        }
+        spans
+    }
+}
+
+impl ScrollContent for LogContent {
+    fn height(&self, _width: u16) -> usize {
+        let records = self.buffer.lock();
+        records.iter().filter(|r| self.is_visible(r)).count()
+    }
+
+    fn width(&self) -> usize {
+        let records = self.buffer.lock();
+        records
+            .iter()
+            .filter(|r| self.is_visible(r))
+            .map(|r| PREFIX_WIDTH + r.target.width() + 2 + r.message.width())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, offset: ScrollOffset, theme: &Theme) {
+        let records = self.buffer.lock();
+        let lines: Vec<Line> = records
+            .iter()
+            .filter(|r| self.is_visible(r))
+            .skip(offset.y)
+            .take(area.height as usize)
+            .map(|record| {
+                let mut spans = vec![
+                    Span::styled(record.time(), theme.muted),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("{:>5}", record.level),
+                        level_style(record.level, theme),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(format!("{}: ", record.target), theme.muted),
+                ];
+                spans.extend(self.message_spans(&record.message, theme));
+                Line::from(spans)
+            })
+            .collect();
+        let paragraph = Paragraph::new(lines).scroll((0, offset.x.min(u16::MAX as usize) as u16));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// What the prompt at the bottom of a `LogView` edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prompt {
+    Search,
+    Target,
+}
+
+/// A component and tab showing records from a `LogBuffer`.
+///
+/// Records are colored by level and can be filtered by level, target
+/// prefix and message text. The view follows new records until scrolled
+/// up; pausing freezes the view while records keep being captured.
+///
+/// # Key bindings
+///
+/// - Up/Down/PageUp/PageDown/Home/End, mouse wheel: Scroll
+/// - /: Search messages
+/// - t: Filter by target prefix
+/// - l: Cycle the most verbose level shown
+/// - Esc: Clear the search and target filters
+/// - p: Pause/resume
+/// - f: Toggle following new records
+/// - e: Export the shown records to the export path
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::{LogBuffer, LogView};
+///
+/// let logs = LogBuffer::new(10_000);
+/// let view = LogView::new("logs", "Logs", logs.clone()).export_path("/tmp/interax.log");
+/// ```
+pub struct LogView {
+    id: String,
+    title: String,
+    view: ScrollView<LogContent>,
+    prompt: Option<(Prompt, TextInput)>,
+    export_path: PathBuf,
+    status: Option<String>,
+}
+
+impl LogView {
+    /// Create a view over `buffer` with the given ID (used as tab ID and
+    /// focus ID) and title.
+    pub fn new(id: impl Into<String>, title: impl Into<String>, buffer: LogBuffer) -> Self {
+        let id = id.into();
+        let title = title.into();
+        let content = LogContent {
+            buffer,
+            filter: LogFilter::default(),
+            paused_at: None,
+        };
+        Self {
+            view: ScrollView::new(id.clone(), content)
+                .title(title.clone())
+                .follow_tail(true),
+            id,
+            title,
+            prompt: None,
+            export_path: PathBuf::from("logs.txt"),
+            status: None,
+        }
+    }
+
+    /// Set the file that `e` exports to.
+    pub fn export_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.export_path = path.into();
+        self
+    }
+
+    /// Get the current filter.
+    pub fn filter(&self) -> &LogFilter {
+        &self.view.content().filter
+    }
+
+    /// Replace the filter.
+    pub fn set_filter(&mut self, filter: LogFilter) {
+        self.view.content_mut().filter = filter;
+    }
+
+    /// Check if the view is paused.
+    pub fn is_paused(&self) -> bool {
+        self.view.content().paused_at.is_some()
+    }
+
+    /// Pause or resume the view.
+    pub fn set_paused(&mut self, paused: bool) {
+        let content = self.view.content_mut();
+        content.paused_at = if paused {
+            Some(
+                content
+                    .buffer
+                    .lock()
+                    .iter()
+                    .next_back()
+                    .map_or(0, |r| r.seq),
+            )
+        } else {
+            None
+        };
+    }
+
+    /// Check if the view follows new records.
+    pub fn is_following(&self) -> bool {
+        self.view.is_following()
+    }
+
+    /// Write the shown records (respecting filters and pause) to `path`.
+    ///
+    /// Returns the number of records written.
+    pub fn export(&self, path: &Path) -> io::Result<usize> {
+        let content = self.view.content();
+        let mut file = BufWriter::new(File::create(path)?);
+        let mut count = 0;
+        for record in content.buffer.lock().iter() {
+            if content.is_visible(record) {
+                writeln!(file, "{}", record)?;
+                count += 1;
+            }
+        }
+        file.flush()?;
+        Ok(count)
+    }
+
+    fn open_prompt(&mut self, prompt: Prompt) {
+        let filter = self.filter();
+        let (title, value) = match prompt {
+            Prompt::Search => ("Search", filter.search.clone()),
+            Prompt::Target => ("Target prefix", filter.target.clone()),
+        };
+        let input = TextInput::new(format!("{}.prompt", self.id))
+            .title(title)
+            .value(value.as_deref().unwrap_or_default());
+        self.prompt = Some((prompt, input));
+    }
+
+    fn handle_prompt(&mut self, event: &Event) -> EventResult {
+        let Some((prompt, input)) = &mut self.prompt else {
+            return EventResult::Unhandled;
+        };
+        if event.is_key(KeyCode::Esc) {
+            self.prompt = None;
+        } else if event.is_key(KeyCode::Enter) {
+            let value = Some(input.text().to_string()).filter(|v| !v.is_empty());
+            let prompt = *prompt;
+            let filter = &mut self.view.content_mut().filter;
+            match prompt {
+                Prompt::Search => filter.search = value,
+                Prompt::Target => filter.target = value,
+            }
+            self.prompt = None;
+        } else {
+            input.handle_input(event);
+        }
+        // The prompt is modal
+        EventResult::Handled
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the view is embedded in another `Tab`.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        if self.prompt.is_some() {
+            return self.handle_prompt(event);
+        }
+
+        let Event::Key(key) = event else {
+            return self.view.handle_input(event);
+        };
+        match key.code {
+            KeyCode::Char('/') => self.open_prompt(Prompt::Search),
+            KeyCode::Char('t') => self.open_prompt(Prompt::Target),
+            KeyCode::Char('l') => {
+                let filter = &mut self.view.content_mut().filter;
+                filter.max_level = next_level(filter.max_level);
+            }
+            KeyCode::Esc => {
+                let filter = &mut self.view.content_mut().filter;
+                if filter.search.is_none() && filter.target.is_none() {
+                    return EventResult::Unhandled;
+                }
+                filter.search = None;
+                filter.target = None;
+            }
+            KeyCode::Char('p') => self.set_paused(!self.is_paused()),
+            KeyCode::Char('f') => {
+                if self.view.is_following() {
+                    self.view.set_follow_tail(false);
+                } else {
+                    self.view.scroll_to_bottom();
+                }
+            }
+            KeyCode::Char('e') => {
+                self.status = Some(match self.export(&self.export_path) {
+                    Ok(count) => format!(
+                        "exported {} records to {}",
+                        count,
+                        self.export_path.display()
+                    ),
+                    Err(e) => format!("export failed: {}", e),
+                });
+            }
+            _ => return self.view.handle_input(event),
+        }
+        EventResult::Handled
+    }
+
+    /// Render the view with an explicit focus state and theme.
+    ///
+    /// Use this when the view is embedded in another `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let prompt_height = if self.prompt.is_some() { 3 } else { 0 };
+        let [status_area, view_area, prompt_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(prompt_height),
+        ])
+        .areas(area);
+
+        let content = self.view.content();
+        let mut status = vec![Span::styled(
+            format!("level ≤ {}", content.filter.max_level),
+            theme.muted,
+        )];
+        if let Some(target) = &content.filter.target {
+            status.push(Span::styled(format!(" · target: {}", target), theme.muted));
+        }
+        if let Some(search) = &content.filter.search {
+            status.push(Span::styled(format!(" · search: {}", search), theme.muted));
+        }
+        if self.is_paused() {
+            status.push(Span::styled(" · PAUSED", theme.accent));
+        } else if self.view.is_following() {
+            status.push(Span::styled(" · following", theme.muted));
+        }
+        status.push(Span::styled(
+            format!(" · {} records", content.buffer.len()),
+            theme.muted,
+        ));
+        if let Some(message) = &self.status {
+            status.push(Span::styled(format!(" · {}", message), theme.accent));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status)), status_area);
+
+        self.view
+            .render(frame, view_area, focused && self.prompt.is_none(), theme);
+
+        if let Some((_, input)) = &self.prompt {
+            input.render(frame, prompt_area, focused, theme);
+        }
+    }
+}
+
+impl Component for LogView {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if matches!(event, Event::Key(_)) && !ctx.focus().is_focused(&self.id) {
+            return EventResult::Unhandled;
+        }
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
+impl Tab for LogView {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        self.render(frame, area, true, &Theme::default());
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut TabEventContext) -> EventResult {
+        self.handle_input(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(crossterm::event::KeyEvent::new(
+            code,
+            crate::event::KeyModifiers::NONE,
+        ))
+    }
+
+    #[test]
+    fn test_log_view_filters() {
+        let buffer = LogBuffer::new(100);
+        buffer.push(Level::INFO, "interax::bus", "attribute changed");
+        buffer.push(Level::DEBUG, "interax::bus", "frame received");
+        buffer.push(Level::WARN, "interax::net", "Attribute timeout");
+
+        let mut view = LogView::new("logs", "Logs", buffer.clone());
+        assert_eq!(view.view.content().height(80), 3);
+
+        // Cycle TRACE -> DEBUG -> INFO
+        view.handle_input(&key(KeyCode::Char('l')));
+        view.handle_input(&key(KeyCode::Char('l')));
+        assert_eq!(view.view.content().height(80), 2);
+
+        view.handle_input(&key(KeyCode::Char('/')));
+        for c in "attr".chars() {
+            view.handle_input(&key(KeyCode::Char(c)));
+        }
+        view.handle_input(&key(KeyCode::Enter));
+        assert_eq!(view.filter().search.as_deref(), Some("attr"));
+        assert_eq!(view.view.content().height(80), 2);
+
+        view.set_filter(LogFilter {
+            target: Some("interax::net".to_string()),
+            ..view.filter().clone()
+        });
+        assert_eq!(view.view.content().height(80), 1);
+
+        // Pausing hides records captured afterwards
+        view.handle_input(&key(KeyCode::Esc));
+        view.handle_input(&key(KeyCode::Char('p')));
+        buffer.push(Level::ERROR, "interax::net", "disconnected");
+        assert_eq!(view.view.content().height(80), 2);
+        view.handle_input(&key(KeyCode::Char('p')));
+        assert_eq!(view.view.content().height(80), 3);
+    }
+}
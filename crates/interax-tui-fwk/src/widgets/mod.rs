@@ -0,0 +1,170 @@
+//! Built-in widgets for the TUI framework.
+//!
+//! This module provides first-party `Component` implementations for common
+//! interactive widgets. All widgets:
+//!
+//! - Have a focus ID and pick their focused/unfocused styles from the
+//!   `Theme` in `DrawContext`
+//! - Only render the rows that are visible, so they scale to large data sets
+//! - Cut text by display width without splitting characters, and align
+//!   right-to-left text to the right (see `crate::unicode`)
+//! - Expose a context-free `handle_input` and `render`, so they can be used
+//!   inside a `Tab` (which has no `DrawContext`) as well as a `Component`
+
+use std::cell::Cell;
+use std::ops::Range;
+
+use crate::event::{Event, KeyCode, KeyModifiers};
+use crate::unicode::{display_width, truncate};
+
+pub mod chart;
+pub mod form;
+pub mod input;
+pub mod list;
+#[cfg(feature = "tracing")]
+pub mod log;
+pub mod progress;
+pub mod scroll;
+pub mod table;
+pub mod text;
+pub mod textarea;
+pub mod tree;
+
+pub use chart::{SeriesGauge, SeriesHistogram, SeriesLineChart, SeriesSparkline};
+pub use form::{Field, FieldValidator, FieldValue, Form, FormError, FormValidator, FormValues};
+pub use input::TextInput;
+pub use list::SelectableList;
+#[cfg(feature = "tracing")]
+pub use log::{LogFilter, LogView};
+pub use progress::{ProgressBar, Spinner};
+pub use scroll::{ScrollContent, ScrollOffset, ScrollView};
+pub use table::{SelectableTable, SortOrder, TableColumn};
+pub use text::{TextBuffer, Validator};
+pub use textarea::TextArea;
+pub use tree::{TreeNode, TreeView};
+
+/// Format a block title, padded with a space on each side and cut to fit
+/// the top border of a block `width` columns wide.
+///
+/// `suffix` (a focus or mode marker) is never cut.
+pub(crate) fn block_title(title: &str, suffix: &str, width: u16) -> String {
+    // Two corners and the padding
+    let room = (width as usize).saturating_sub(4 + display_width(suffix));
+    format!(" {}{} ", truncate(title, room), suffix)
+}
+
+/// Check if `event` is the copy key of the text widgets: Alt+C, or
+/// Ctrl+Insert (Ctrl+C quits the app).
+pub(crate) fn is_copy_key(event: &Event) -> bool {
+    let Event::Key(key) = event else {
+        return false;
+    };
+    match key.code {
+        KeyCode::Char('c') => key.modifiers == KeyModifiers::ALT,
+        KeyCode::Insert => key.modifiers == KeyModifiers::CONTROL,
+        _ => false,
+    }
+}
+
+/// Cursor position and scroll offset shared by the list-like widgets.
+///
+/// The offset and viewport height are updated while drawing (which only
+/// has `&self`), so they live in `Cell`s.
+#[derive(Debug, Default)]
+pub(crate) struct ScrollCursor {
+    cursor: usize,
+    offset: Cell<usize>,
+    viewport: Cell<usize>,
+}
+
+impl ScrollCursor {
+    /// Index of the cursor row.
+    pub(crate) fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Move the cursor to `index`, clamped to `len`.
+    pub(crate) fn set(&mut self, index: usize, len: usize) {
+        self.cursor = index.min(len.saturating_sub(1));
+    }
+
+    /// Move the cursor by `delta` rows, clamped to `len`.
+    pub(crate) fn move_by(&mut self, delta: isize, len: usize) {
+        let index = self.cursor.saturating_add_signed(delta);
+        self.set(index, len);
+    }
+
+    /// Number of rows moved by PageUp/PageDown.
+    fn page(&self) -> isize {
+        self.viewport.get().max(1) as isize
+    }
+
+    /// Handle the navigation keys shared by all list-like widgets.
+    ///
+    /// Returns `true` if the key was a navigation key.
+    pub(crate) fn handle_key(&mut self, code: KeyCode, len: usize) -> bool {
+        match code {
+            KeyCode::Up => self.move_by(-1, len),
+            KeyCode::Down => self.move_by(1, len),
+            KeyCode::PageUp => self.move_by(-self.page(), len),
+            KeyCode::PageDown => self.move_by(self.page(), len),
+            KeyCode::Home => self.set(0, len),
+            KeyCode::End => self.set(len.saturating_sub(1), len),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Compute the range of rows visible in a viewport of `height` rows.
+    ///
+    /// The offset is adjusted so the cursor stays visible.
+    pub(crate) fn visible_range(&self, len: usize, height: usize) -> Range<usize> {
+        let mut offset = self.offset.get();
+        if self.cursor < offset {
+            offset = self.cursor;
+        } else if height > 0 && self.cursor >= offset + height {
+            offset = self.cursor + 1 - height;
+        }
+        offset = offset.min(len.saturating_sub(height));
+
+        self.offset.set(offset);
+        self.viewport.set(height);
+        offset..(offset + height).min(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_cursor_keeps_cursor_visible() {
+        let mut cursor = ScrollCursor::default();
+        assert_eq!(cursor.visible_range(100, 10), 0..10);
+
+        cursor.set(15, 100);
+        assert_eq!(cursor.visible_range(100, 10), 6..16);
+
+        cursor.set(3, 100);
+        assert_eq!(cursor.visible_range(100, 10), 3..13);
+
+        cursor.handle_key(KeyCode::End, 100);
+        assert_eq!(cursor.cursor(), 99);
+        assert_eq!(cursor.visible_range(100, 10), 90..100);
+
+        cursor.handle_key(KeyCode::PageUp, 100);
+        assert_eq!(cursor.cursor(), 89);
+    }
+
+    #[test]
+    fn test_scroll_cursor_short_content() {
+        let mut cursor = ScrollCursor::default();
+        cursor.handle_key(KeyCode::Down, 3);
+        cursor.handle_key(KeyCode::PageDown, 3);
+        assert_eq!(cursor.cursor(), 2);
+        assert_eq!(cursor.visible_range(3, 10), 0..3);
+
+        cursor.handle_key(KeyCode::Home, 0);
+        assert_eq!(cursor.visible_range(0, 10), 0..0);
+    }
+}
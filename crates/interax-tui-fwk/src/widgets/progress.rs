@@ -0,0 +1,273 @@
+//! Spinner and progress bar widgets.
+//!
+//! Both animate through an `Animator` handle given when they start or
+//! move, so they keep animating inside a `Tab` too. A stopped spinner or
+//! a progress bar at rest requests no frames.
+
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+use crate::animation::{Animator, Tween};
+use crate::component::Component;
+use crate::context::DrawContext;
+use crate::theme::Theme;
+
+/// Braille glyphs turning clockwise.
+const DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Time a `ProgressBar` takes to reach a new value, unless set.
+const DEFAULT_PROGRESS_DURATION: Duration = Duration::from_millis(250);
+
+/// A spinner with a label, shown while something is in progress.
+///
+/// # Example
+///
+/// ```ignore
+/// // When a request starts
+/// self.spinner.start(ctx.animator());
+/// // When it completes
+/// self.spinner.stop();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Spinner {
+    frames: &'static [&'static str],
+    interval: Duration,
+    label: String,
+    style: Option<Style>,
+    running: Option<(Instant, Animator)>,
+}
+
+impl Spinner {
+    /// Create a stopped spinner.
+    pub fn new() -> Self {
+        Self {
+            frames: DOTS,
+            interval: Duration::from_millis(80),
+            label: String::new(),
+            style: None,
+            running: None,
+        }
+    }
+
+    /// Set the glyphs shown in turn.
+    pub fn frames(mut self, frames: &'static [&'static str]) -> Self {
+        if !frames.is_empty() {
+            self.frames = frames;
+        }
+        self
+    }
+
+    /// Set the time each glyph is shown.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Set the text shown after the glyph.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Change the text shown after the glyph.
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = label.into();
+    }
+
+    /// Set the glyph style (defaults to the theme accent).
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Start spinning, requesting frames from `animator` until stopped.
+    ///
+    /// Does nothing if it is already spinning.
+    pub fn start(&mut self, animator: &Animator) {
+        if self.running.is_none() {
+            self.running = Some((Instant::now(), animator.clone()));
+            animator.request_frame();
+        }
+    }
+
+    /// Stop spinning.
+    pub fn stop(&mut self) {
+        self.running = None;
+    }
+
+    /// Check if the spinner is spinning.
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    /// The glyph shown at `now`, or `None` when stopped.
+    pub fn glyph_at(&self, now: Instant) -> Option<&'static str> {
+        let (started, _) = self.running.as_ref()?;
+        let step = self.step(*started, now);
+        Some(self.frames[(step % self.frames.len() as u128) as usize])
+    }
+
+    fn step(&self, started: Instant, now: Instant) -> u128 {
+        now.saturating_duration_since(started).as_nanos() / self.interval.as_nanos()
+    }
+
+    /// Render the spinner with an explicit theme.
+    ///
+    /// Use this when the spinner is embedded in a `Tab`. While spinning,
+    /// this requests the frame showing the next glyph.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let now = Instant::now();
+        let mut spans = Vec::new();
+        if let (Some(glyph), Some((started, animator))) = (self.glyph_at(now), &self.running) {
+            let next = self.step(*started, now) + 1;
+            animator.request_frame_at(*started + self.interval * next as u32);
+            spans.push(Span::styled(glyph, self.style.unwrap_or(theme.accent)));
+            spans.push(Span::raw(" "));
+        }
+        spans.push(Span::styled(self.label.as_str(), theme.text));
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for Spinner {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        self.render(frame, area, ctx.theme());
+    }
+}
+
+/// A progress bar that moves smoothly to each new value.
+///
+/// # Example
+///
+/// ```ignore
+/// // When a task reports progress
+/// self.upload.set_progress(sent as f64 / total as f64, ctx.animator());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    ratio: Tween,
+    duration: Duration,
+    title: Option<String>,
+    style: Option<Style>,
+}
+
+impl ProgressBar {
+    /// Create an empty progress bar.
+    pub fn new() -> Self {
+        Self {
+            ratio: Tween::new(0.0),
+            duration: DEFAULT_PROGRESS_DURATION,
+            title: None,
+            style: None,
+        }
+    }
+
+    /// Set the block title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the time taken to reach a new value (`Duration::ZERO` to jump).
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the bar style (defaults to the theme accent).
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Move to `ratio`, clamped between 0 and 1, requesting frames from
+    /// `animator` until it gets there.
+    pub fn set_progress(&mut self, ratio: f64, animator: &Animator) {
+        let ratio = ratio.clamp(0.0, 1.0);
+        if ratio != self.ratio.target() {
+            self.ratio.animate_to(ratio, self.duration);
+            animator.animate(&self.ratio);
+        }
+    }
+
+    /// The progress set last, from 0 to 1.
+    pub fn progress(&self) -> f64 {
+        self.ratio.target()
+    }
+
+    /// Render the progress bar with an explicit theme.
+    ///
+    /// Use this when the progress bar is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut gauge = Gauge::default()
+            .ratio(self.ratio.value().clamp(0.0, 1.0))
+            .label(format!("{:.0}%", self.progress() * 100.0))
+            .gauge_style(self.style.unwrap_or(theme.accent));
+        if let Some(title) = &self.title {
+            gauge = gauge.block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_unfocused)
+                    .title(Span::styled(format!(" {} ", title), theme.text)),
+            );
+        }
+        frame.render_widget(gauge, area);
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for ProgressBar {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        self.render(frame, area, ctx.theme());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinner_and_progress_request_frames_while_running() {
+        let animator = Animator::new();
+        let mut spinner = Spinner::new().frames(&["a", "b"]);
+        assert_eq!(spinner.glyph_at(Instant::now()), None);
+        spinner.start(&animator);
+        let started = spinner.running.as_ref().unwrap().0;
+        assert_eq!(spinner.glyph_at(started), Some("a"));
+        assert_eq!(
+            spinner.glyph_at(started + Duration::from_millis(90)),
+            Some("b")
+        );
+        assert_eq!(
+            spinner.glyph_at(started + Duration::from_millis(170)),
+            Some("a")
+        );
+        assert!(animator.is_animating());
+        spinner.stop();
+        assert!(!spinner.is_running());
+
+        let animator = Animator::new();
+        let mut bar = ProgressBar::new();
+        bar.set_progress(1.5, &animator);
+        assert_eq!(bar.progress(), 1.0);
+        assert!(animator.is_animating());
+    }
+}
@@ -0,0 +1,459 @@
+//! Scrollable viewport container.
+
+use std::cell::Cell;
+
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Text,
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+use super::block_title;
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::{Event, KeyCode, KeyModifiers, MouseEventKind};
+use crate::focus::EventResult;
+#[cfg(feature = "persist")]
+use crate::persist::{Persist, Session};
+use crate::theme::Theme;
+
+/// Rows (or columns) moved per mouse wheel notch.
+const WHEEL_STEP: usize = 3;
+
+/// Scroll position of a `ScrollView`, in columns and rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollOffset {
+    /// First visible column.
+    pub x: usize,
+    /// First visible row.
+    pub y: usize,
+}
+
+/// Content that can be shown in a `ScrollView`.
+///
+/// Only the visible part is rendered, so content can be arbitrarily large.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::{ScrollContent, ScrollOffset};
+///
+/// struct Rows(Vec<String>);
+///
+/// impl ScrollContent for Rows {
+///     fn height(&self, _width: u16) -> usize {
+///         self.0.len()
+///     }
+///
+///     fn render_content(&self, frame: &mut Frame, area: Rect, offset: ScrollOffset, theme: &Theme) {
+///         let visible: Vec<Line> = self.0[offset.y..]
+///             .iter()
+///             .take(area.height as usize)
+///             .map(|row| Line::raw(row.as_str()))
+///             .collect();
+///         frame.render_widget(Paragraph::new(visible).style(theme.text), area);
+///     }
+/// }
+/// ```
+pub trait ScrollContent {
+    /// Number of rows when laid out `width` columns wide.
+    fn height(&self, width: u16) -> usize;
+
+    /// Number of columns of the widest row.
+    ///
+    /// The default of 0 disables horizontal scrolling.
+    fn width(&self) -> usize {
+        0
+    }
+
+    /// Render the part of the content starting at `offset` into `area`.
+    fn render_content(&self, frame: &mut Frame, area: Rect, offset: ScrollOffset, theme: &Theme);
+}
+
+impl ScrollContent for Text<'_> {
+    fn height(&self, _width: u16) -> usize {
+        self.lines.len()
+    }
+
+    fn width(&self) -> usize {
+        Text::width(self)
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, offset: ScrollOffset, theme: &Theme) {
+        let visible: Vec<_> = self
+            .lines
+            .iter()
+            .skip(offset.y)
+            .take(area.height as usize)
+            .cloned()
+            .collect();
+        let paragraph = Paragraph::new(visible)
+            .style(theme.text)
+            .scroll((0, offset.x.min(u16::MAX as usize) as u16));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Viewport geometry from the last draw, used to handle input.
+#[derive(Debug, Clone, Copy, Default)]
+struct Viewport {
+    /// Area of the whole view, including the border.
+    area: Rect,
+    /// Visible columns and rows.
+    width: usize,
+    height: usize,
+    /// Content size.
+    content_width: usize,
+    content_height: usize,
+}
+
+impl Viewport {
+    fn max_x(&self) -> usize {
+        self.content_width.saturating_sub(self.width)
+    }
+
+    fn max_y(&self) -> usize {
+        self.content_height.saturating_sub(self.height)
+    }
+}
+
+/// A focusable container for content larger than its area.
+///
+/// Tracks the scroll offset on both axes, draws scrollbars when the
+/// content overflows and, in follow-tail mode, keeps the last row in view
+/// as content is appended (for logs and other streams). Scrolling up
+/// leaves follow-tail mode; End enters it again.
+///
+/// # Key bindings
+///
+/// - Up/Down/Left/Right: Scroll by one row or column
+/// - PageUp/PageDown: Scroll by one page
+/// - Home/End: Jump to the top/bottom (End also follows the tail)
+/// - Mouse wheel: Scroll vertically (Shift: horizontally)
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::ScrollView;
+/// use ratatui::text::Text;
+///
+/// let mut events = ScrollView::new("events", Text::default())
+///     .title("Events")
+///     .follow_tail(true);
+///
+/// events.content_mut().lines.push("endpoint 1: OnOff changed".into());
+/// ```
+pub struct ScrollView<C> {
+    focus_id: String,
+    title: Option<String>,
+    content: C,
+    offset: Cell<ScrollOffset>,
+    follow_tail: Cell<bool>,
+    viewport: Cell<Viewport>,
+}
+
+impl<C: ScrollContent> ScrollView<C> {
+    /// Create a new scroll view over `content` with the given focus ID.
+    pub fn new(focus_id: impl Into<String>, content: C) -> Self {
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            content,
+            offset: Cell::new(ScrollOffset::default()),
+            follow_tail: Cell::new(false),
+            viewport: Cell::new(Viewport::default()),
+        }
+    }
+
+    /// Set the title shown in the border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Start in follow-tail mode.
+    pub fn follow_tail(self, follow: bool) -> Self {
+        self.follow_tail.set(follow);
+        self
+    }
+
+    /// Get the content.
+    pub fn content(&self) -> &C {
+        &self.content
+    }
+
+    /// Get the content mutably.
+    ///
+    /// The offsets are re-clamped on the next draw.
+    pub fn content_mut(&mut self) -> &mut C {
+        &mut self.content
+    }
+
+    /// Replace the content.
+    pub fn set_content(&mut self, content: C) {
+        self.content = content;
+    }
+
+    /// Current scroll offset.
+    pub fn offset(&self) -> ScrollOffset {
+        self.offset.get()
+    }
+
+    /// Check if the view is following the tail of the content.
+    pub fn is_following(&self) -> bool {
+        self.follow_tail.get()
+    }
+
+    /// Enable or disable follow-tail mode.
+    pub fn set_follow_tail(&mut self, follow: bool) {
+        self.follow_tail.set(follow);
+    }
+
+    /// Scroll to an absolute offset, leaving follow-tail mode.
+    pub fn scroll_to(&mut self, offset: ScrollOffset) {
+        self.follow_tail.set(false);
+        self.offset.set(self.clamp(offset));
+    }
+
+    /// Scroll by a number of columns and rows.
+    ///
+    /// Scrolling up leaves follow-tail mode.
+    pub fn scroll_by(&mut self, dx: isize, dy: isize) {
+        if dy < 0 {
+            self.follow_tail.set(false);
+        }
+        let offset = self.offset.get();
+        self.offset.set(self.clamp(ScrollOffset {
+            x: offset.x.saturating_add_signed(dx),
+            y: offset.y.saturating_add_signed(dy),
+        }));
+    }
+
+    /// Scroll to the top.
+    pub fn scroll_to_top(&mut self) {
+        let x = self.offset.get().x;
+        self.scroll_to(ScrollOffset { x, y: 0 });
+    }
+
+    /// Scroll to the bottom and follow the tail.
+    pub fn scroll_to_bottom(&mut self) {
+        let viewport = self.viewport.get();
+        self.offset.set(ScrollOffset {
+            x: self.offset.get().x,
+            y: viewport.max_y(),
+        });
+        self.follow_tail.set(true);
+    }
+
+    fn clamp(&self, offset: ScrollOffset) -> ScrollOffset {
+        let viewport = self.viewport.get();
+        ScrollOffset {
+            x: offset.x.min(viewport.max_x()),
+            y: offset.y.min(viewport.max_y()),
+        }
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the view is embedded in a `Tab`. Mouse wheel events
+    /// are only handled over the area drawn last.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        let page = self.viewport.get().height.max(1) as isize;
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Up => self.scroll_by(0, -1),
+                KeyCode::Down => self.scroll_by(0, 1),
+                KeyCode::Left => self.scroll_by(-1, 0),
+                KeyCode::Right => self.scroll_by(1, 0),
+                KeyCode::PageUp => self.scroll_by(0, -page),
+                KeyCode::PageDown => self.scroll_by(0, page),
+                KeyCode::Home => self.scroll_to_top(),
+                KeyCode::End => self.scroll_to_bottom(),
+                _ => return EventResult::Unhandled,
+            },
+            Event::Mouse(mouse) => {
+                let area = self.viewport.get().area;
+                if !area.contains((mouse.column, mouse.row).into()) {
+                    return EventResult::Unhandled;
+                }
+                let step = WHEEL_STEP as isize;
+                let horizontal = mouse.modifiers.contains(KeyModifiers::SHIFT);
+                match mouse.kind {
+                    MouseEventKind::ScrollUp if horizontal => self.scroll_by(-step, 0),
+                    MouseEventKind::ScrollDown if horizontal => self.scroll_by(step, 0),
+                    MouseEventKind::ScrollUp => self.scroll_by(0, -step),
+                    MouseEventKind::ScrollDown => self.scroll_by(0, step),
+                    MouseEventKind::ScrollLeft => self.scroll_by(-step, 0),
+                    MouseEventKind::ScrollRight => self.scroll_by(step, 0),
+                    _ => return EventResult::Unhandled,
+                }
+            }
+            _ => return EventResult::Unhandled,
+        }
+        EventResult::Handled
+    }
+
+    /// Render the view with an explicit focus state and theme.
+    ///
+    /// Use this when the view is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border(focused));
+        if let Some(title) = &self.title {
+            let follow = if self.follow_tail.get() { " ⇣" } else { "" };
+            block = block.title(block_title(title, follow, area.width));
+        }
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let viewport = Viewport {
+            area,
+            width: inner.width as usize,
+            height: inner.height as usize,
+            content_width: self.content.width(),
+            content_height: self.content.height(inner.width),
+        };
+        self.viewport.set(viewport);
+
+        let mut offset = self.clamp(self.offset.get());
+        if self.follow_tail.get() {
+            offset.y = viewport.max_y();
+        }
+        self.offset.set(offset);
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+        self.content.render_content(frame, inner, offset, theme);
+
+        // Scrollbars sit on the border so they don't take content space
+        if viewport.max_y() > 0 {
+            let mut state = ScrollbarState::new(viewport.max_y())
+                .position(offset.y)
+                .viewport_content_length(viewport.height);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight).style(theme.border(focused)),
+                area.inner(Margin::new(0, 1)),
+                &mut state,
+            );
+        }
+        if viewport.max_x() > 0 {
+            let mut state = ScrollbarState::new(viewport.max_x())
+                .position(offset.x)
+                .viewport_content_length(viewport.width);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::HorizontalBottom).style(theme.border(focused)),
+                area.inner(Margin::new(1, 0)),
+                &mut state,
+            );
+        }
+    }
+}
+
+/// Saves the offset and follow-tail mode.
+#[cfg(feature = "persist")]
+impl<C> Persist for ScrollView<C> {
+    type State = (usize, usize, bool);
+
+    fn save(&self) -> Self::State {
+        let offset = self.offset.get();
+        (offset.x, offset.y, self.follow_tail.get())
+    }
+
+    fn restore(&mut self, (x, y, follow): Self::State) {
+        // Clamped on the next draw, once the viewport is known
+        self.offset.set(ScrollOffset { x, y });
+        self.follow_tail.set(follow);
+    }
+}
+
+impl<C: ScrollContent + Send> Component for ScrollView<C> {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        // The mouse wheel scrolls whichever view is under the pointer
+        if matches!(event, Event::Key(_)) && !ctx.focus().is_focused(&self.focus_id) {
+            return EventResult::Unhandled;
+        }
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+
+    #[cfg(feature = "persist")]
+    fn save_state(&self, session: &mut Session) {
+        session.save(&self.focus_id, self);
+    }
+
+    #[cfg(feature = "persist")]
+    fn restore_state(&mut self, session: &Session) {
+        let key = self.focus_id.clone();
+        session.restore(&key, self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn lines(count: usize) -> Text<'static> {
+        Text::from(
+            (0..count)
+                .map(|i| format!("line {}", i))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    fn draw(view: &ScrollView<Text<'static>>) {
+        let mut terminal = Terminal::new(TestBackend::new(20, 7)).unwrap();
+        terminal
+            .draw(|frame| view.render(frame, frame.area(), true, &Theme::default()))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_scroll_view_clamps_and_pages() {
+        let mut view = ScrollView::new("view", lines(20));
+        draw(&view);
+
+        view.handle_input(&key(KeyCode::PageDown));
+        assert_eq!(view.offset().y, 5);
+        view.handle_input(&key(KeyCode::End));
+        assert_eq!(view.offset().y, 15);
+        view.handle_input(&key(KeyCode::Down));
+        assert_eq!(view.offset().y, 15);
+        view.handle_input(&key(KeyCode::Home));
+        assert_eq!(view.offset().y, 0);
+    }
+
+    #[test]
+    fn test_scroll_view_follow_tail() {
+        let mut view = ScrollView::new("view", lines(10)).follow_tail(true);
+        draw(&view);
+        assert_eq!(view.offset().y, 5);
+
+        view.content_mut().lines.push("line 10".into());
+        draw(&view);
+        assert_eq!(view.offset().y, 6);
+
+        // Scrolling up stops following
+        view.handle_input(&key(KeyCode::Up));
+        view.content_mut().lines.push("line 11".into());
+        draw(&view);
+        assert!(!view.is_following());
+        assert_eq!(view.offset().y, 5);
+    }
+}
@@ -0,0 +1,487 @@
+//! Selectable table widget.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Modifier,
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+use super::{block_title, ScrollCursor};
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::{Event, KeyCode, KeyModifiers};
+use crate::focus::EventResult;
+use crate::theme::Theme;
+use crate::unicode::{display_width, fit_line};
+
+/// Minimum width a column can be resized to.
+const MIN_COLUMN_WIDTH: u16 = 3;
+
+/// Sort direction of a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest value first.
+    Ascending,
+    /// Largest value first.
+    Descending,
+}
+
+impl SortOrder {
+    /// The opposite sort order.
+    pub fn reversed(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// A column definition for `SelectableTable`.
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    /// Header title.
+    pub title: String,
+    /// Width in cells.
+    pub width: u16,
+}
+
+impl TableColumn {
+    /// Create a new column with the given title and width.
+    pub fn new(title: impl Into<String>, width: u16) -> Self {
+        Self {
+            title: title.into(),
+            width: width.max(MIN_COLUMN_WIDTH),
+        }
+    }
+}
+
+/// A focusable table with a row cursor, sortable and resizable columns,
+/// and optional multi-select.
+///
+/// Only the visible rows are rendered, so large row counts stay cheap.
+///
+/// # Key bindings
+///
+/// - Up/Down/PageUp/PageDown/Home/End: Move the cursor
+/// - `<`/`>`: Select the active column
+/// - `s`: Sort by the active column (press again to reverse)
+/// - `+`/`-`: Widen/narrow the active column
+/// - Space: Toggle selection of the cursor row (multi-select only)
+/// - Ctrl+A: Select all rows (multi-select only)
+/// - `y`: Copy the active cell of the cursor row
+/// - `Y`: Copy the cursor row, tab-separated
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::{SelectableTable, TableColumn};
+///
+/// let mut table = SelectableTable::new(
+///     "endpoints",
+///     vec![TableColumn::new("Name", 20), TableColumn::new("Status", 10)],
+/// )
+/// .title("Endpoints")
+/// .multi_select(true);
+///
+/// table.set_rows(vec![
+///     vec!["light".to_string(), "online".to_string()],
+///     vec!["lock".to_string(), "offline".to_string()],
+/// ]);
+/// ```
+pub struct SelectableTable {
+    focus_id: String,
+    title: Option<String>,
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    /// Display order: indices into `rows`.
+    order: Vec<usize>,
+    cursor: ScrollCursor,
+    active_column: usize,
+    sort: Option<(usize, SortOrder)>,
+    multi_select: bool,
+    /// Selected rows, as indices into `rows`.
+    selected: BTreeSet<usize>,
+}
+
+impl SelectableTable {
+    /// Create a new empty table with the given focus ID and columns.
+    pub fn new(focus_id: impl Into<String>, columns: Vec<TableColumn>) -> Self {
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            columns,
+            rows: Vec::new(),
+            order: Vec::new(),
+            cursor: ScrollCursor::default(),
+            active_column: 0,
+            sort: None,
+            multi_select: false,
+            selected: BTreeSet::new(),
+        }
+    }
+
+    /// Set the title shown in the table border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Enable or disable multi-select.
+    pub fn multi_select(mut self, enabled: bool) -> Self {
+        self.multi_select = enabled;
+        self
+    }
+
+    /// Replace all rows.
+    ///
+    /// The current sort order is re-applied and the selection is cleared.
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.order = (0..self.rows.len()).collect();
+        self.selected.clear();
+        if let Some((column, order)) = self.sort {
+            self.sort_by(column, order);
+        }
+        self.cursor.set(self.cursor.cursor(), self.rows.len());
+    }
+
+    /// Append a row.
+    ///
+    /// The row is placed at the end regardless of the current sort order.
+    pub fn push_row(&mut self, row: Vec<String>) {
+        self.order.push(self.rows.len());
+        self.rows.push(row);
+    }
+
+    /// Get all rows in insertion order.
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Get the number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Get the columns.
+    pub fn columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// Index (into `rows()`) of the row under the cursor.
+    pub fn cursor_index(&self) -> Option<usize> {
+        self.order.get(self.cursor.cursor()).copied()
+    }
+
+    /// The row under the cursor.
+    pub fn cursor_row(&self) -> Option<&[String]> {
+        self.cursor_index().map(|i| self.rows[i].as_slice())
+    }
+
+    /// Move the cursor to the given display position.
+    pub fn set_cursor(&mut self, position: usize) {
+        self.cursor.set(position, self.rows.len());
+    }
+
+    /// Indices (into `rows()`) of the selected rows.
+    ///
+    /// Without multi-select this is the cursor row.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        if self.multi_select {
+            self.selected.iter().copied().collect()
+        } else {
+            self.cursor_index().into_iter().collect()
+        }
+    }
+
+    /// Toggle selection of the row under the cursor.
+    pub fn toggle_selected(&mut self) {
+        if let Some(index) = self.cursor_index() {
+            if !self.selected.remove(&index) {
+                self.selected.insert(index);
+            }
+        }
+    }
+
+    /// Select all rows.
+    pub fn select_all(&mut self) {
+        self.selected = (0..self.rows.len()).collect();
+    }
+
+    /// Clear the multi-selection.
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Current sort column and order, if sorted.
+    pub fn sort_state(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sort rows by a column.
+    ///
+    /// Cells that parse as numbers on both sides are compared numerically,
+    /// everything else is compared as text. The cursor stays on the same row.
+    pub fn sort_by(&mut self, column: usize, order: SortOrder) {
+        if column >= self.columns.len() {
+            return;
+        }
+
+        let cursor_row = self.cursor_index();
+        let rows = &self.rows;
+        self.order.sort_by(|&a, &b| {
+            let a = rows[a].get(column).map(String::as_str).unwrap_or("");
+            let b = rows[b].get(column).map(String::as_str).unwrap_or("");
+            let ordering = compare_cells(a, b);
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+        self.sort = Some((column, order));
+
+        if let Some(row) = cursor_row {
+            if let Some(position) = self.order.iter().position(|&i| i == row) {
+                self.cursor.set(position, self.rows.len());
+            }
+        }
+    }
+
+    /// Sort by the active column, reversing the order if already sorted by it.
+    pub fn toggle_sort(&mut self) {
+        let order = match self.sort {
+            Some((column, order)) if column == self.active_column => order.reversed(),
+            _ => SortOrder::Ascending,
+        };
+        self.sort_by(self.active_column, order);
+    }
+
+    /// Change the width of a column by `delta` cells.
+    pub fn resize_column(&mut self, column: usize, delta: i16) {
+        if let Some(col) = self.columns.get_mut(column) {
+            col.width = col.width.saturating_add_signed(delta).max(MIN_COLUMN_WIDTH);
+        }
+    }
+
+    /// Index of the column targeted by sort and resize keys.
+    pub fn active_column(&self) -> usize {
+        self.active_column
+    }
+
+    /// The text to copy if `event` is a copy key: the active cell of the
+    /// cursor row for `y`, the whole row (tab-separated) for `Y`.
+    ///
+    /// As a `Component`, the table copies it to `AppContext::clipboard`.
+    pub fn copy_text(&self, event: &Event) -> Option<String> {
+        let Event::Key(key) = event else {
+            return None;
+        };
+        let row = self.cursor_row()?;
+        match key.code {
+            KeyCode::Char('y') => row.get(self.active_column).cloned(),
+            KeyCode::Char('Y') => Some(row.join("\t")),
+            _ => None,
+        }
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the table is embedded in a `Tab`.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Unhandled;
+        };
+
+        if self.cursor.handle_key(key.code, self.rows.len()) {
+            return EventResult::Handled;
+        }
+
+        match key.code {
+            KeyCode::Char('<') => {
+                self.active_column = self.active_column.saturating_sub(1);
+            }
+            KeyCode::Char('>') => {
+                self.active_column =
+                    (self.active_column + 1).min(self.columns.len().saturating_sub(1));
+            }
+            KeyCode::Char('s') => self.toggle_sort(),
+            KeyCode::Char('+') => self.resize_column(self.active_column, 1),
+            KeyCode::Char('-') => self.resize_column(self.active_column, -1),
+            KeyCode::Char(' ') if self.multi_select => self.toggle_selected(),
+            KeyCode::Char('a')
+                if self.multi_select && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                self.select_all()
+            }
+            _ => return EventResult::Unhandled,
+        }
+        EventResult::Handled
+    }
+
+    /// Render the table with an explicit focus state and theme.
+    ///
+    /// Use this when the table is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border(focused));
+        if let Some(title) = &self.title {
+            block = block.title(block_title(
+                title,
+                if focused { " ●" } else { " ○" },
+                area.width,
+            ));
+        }
+
+        // Borders, header and header margin
+        let body_height = area.height.saturating_sub(4) as usize;
+        let visible = self.cursor.visible_range(self.rows.len(), body_height);
+
+        let header_style = theme.header(focused);
+        let header = Row::new(self.columns.iter().enumerate().map(|(i, column)| {
+            let indicator = match self.sort {
+                Some((c, SortOrder::Ascending)) if c == i => " ▲",
+                Some((c, SortOrder::Descending)) if c == i => " ▼",
+                _ => "",
+            };
+            let mut style = header_style;
+            if focused && i == self.active_column {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            let room = (column.width as usize).saturating_sub(display_width(indicator));
+            let title = fit_line(&column.title, room).style(style);
+            Cell::from(title + Span::styled(indicator, style))
+        }))
+        .bottom_margin(1);
+
+        let cursor = self.cursor.cursor();
+        let rows = visible.map(|position| {
+            let index = self.order[position];
+            let style = if position == cursor {
+                theme.cursor(focused)
+            } else if self.selected.contains(&index) {
+                theme.selected
+            } else {
+                theme.text
+            };
+            Row::new(
+                self.rows[index]
+                    .iter()
+                    .zip(&self.columns)
+                    .map(|(cell, column)| Cell::from(fit_line(cell, column.width as usize))),
+            )
+            .style(style)
+        });
+
+        let widths = self.columns.iter().map(|c| Constraint::Length(c.width));
+        let table = Table::new(rows, widths).header(header).block(block);
+        frame.render_widget(table, area);
+    }
+}
+
+/// Compare two cells, numerically if both parse as numbers.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+impl Component for SelectableTable {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if let Some(text) = self.copy_text(event) {
+            ctx.clipboard().copy(text);
+            return EventResult::Handled;
+        }
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> SelectableTable {
+        let mut table = SelectableTable::new(
+            "table",
+            vec![TableColumn::new("Name", 10), TableColumn::new("Value", 10)],
+        )
+        .multi_select(true);
+        table.set_rows(vec![
+            vec!["beta".into(), "20".into()],
+            vec!["alpha".into(), "100".into()],
+            vec!["gamma".into(), "3".into()],
+        ]);
+        table
+    }
+
+    #[test]
+    fn test_sort_numeric_and_text() {
+        let mut table = table();
+
+        table.sort_by(1, SortOrder::Ascending);
+        assert_eq!(table.order, vec![2, 0, 1]);
+
+        table.sort_by(0, SortOrder::Descending);
+        assert_eq!(table.order, vec![2, 0, 1]);
+
+        table.sort_by(0, SortOrder::Ascending);
+        assert_eq!(table.order, vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn test_sort_keeps_cursor_row() {
+        let mut table = table();
+        table.set_cursor(0);
+        assert_eq!(table.cursor_row().unwrap()[0], "beta");
+
+        table.sort_by(0, SortOrder::Ascending);
+        assert_eq!(table.cursor_row().unwrap()[0], "beta");
+    }
+
+    #[test]
+    fn test_multi_select_and_resize() {
+        let mut table = table();
+        table.toggle_selected();
+        table.set_cursor(2);
+        table.toggle_selected();
+        assert_eq!(table.selected_indices(), vec![0, 2]);
+
+        table.resize_column(0, -20);
+        assert_eq!(table.columns()[0].width, MIN_COLUMN_WIDTH);
+        table.resize_column(0, 4);
+        assert_eq!(table.columns()[0].width, MIN_COLUMN_WIDTH + 4);
+    }
+
+    #[test]
+    fn test_copy_text() {
+        use crossterm::event::KeyEvent;
+
+        let mut table = table();
+        let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        assert_eq!(table.copy_text(&key('y')).as_deref(), Some("beta"));
+        table.handle_input(&key('>'));
+        assert_eq!(table.copy_text(&key('y')).as_deref(), Some("20"));
+        assert_eq!(table.copy_text(&key('Y')).as_deref(), Some("beta\t20"));
+        assert_eq!(table.copy_text(&key('x')), None);
+    }
+}
@@ -0,0 +1,659 @@
+//! Grapheme-aware text editing shared by `TextInput` and `TextArea`.
+
+use std::ops::Range;
+
+use crossterm::event::KeyEvent;
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::event::{KeyCode, KeyModifiers};
+use crate::focus::EventResult;
+
+/// Maximum number of undo steps kept by a `TextBuffer`.
+const UNDO_LIMIT: usize = 100;
+
+/// A validation callback for text widgets.
+///
+/// Returns `Err(message)` if the text is invalid.
+pub type Validator = Box<dyn Fn(&str) -> Result<(), String> + Send>;
+
+/// A snapshot of the buffer for undo/redo.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    text: String,
+    cursor: usize,
+}
+
+/// The kind of the last edit, used to coalesce typing into one undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Other,
+    Insert,
+}
+
+/// An editable text buffer with a cursor, selection and undo history.
+///
+/// All positions are byte offsets into the text and always lie on grapheme
+/// cluster boundaries, so combining marks, emoji sequences and wide
+/// characters are edited as a single unit.
+///
+/// This is the editing core of `TextInput` and `TextArea`, and can be
+/// used directly to build custom text widgets.
+#[derive(Debug, Clone)]
+pub struct TextBuffer {
+    text: String,
+    cursor: usize,
+    /// Other end of the selection; the selection spans anchor..cursor.
+    anchor: Option<usize>,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: EditKind,
+    multiline: bool,
+    /// Incremented on every change to the text.
+    version: u64,
+}
+
+impl TextBuffer {
+    /// Create an empty single-line buffer.
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            cursor: 0,
+            anchor: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: EditKind::Other,
+            multiline: false,
+            version: 0,
+        }
+    }
+
+    /// Create an empty multi-line buffer.
+    pub fn multiline() -> Self {
+        Self {
+            multiline: true,
+            ..Self::new()
+        }
+    }
+
+    /// Get the text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Check if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Replace the text, moving the cursor to the end.
+    ///
+    /// This is recorded as an undo step.
+    pub fn set_text(&mut self, text: &str) {
+        self.record(EditKind::Other);
+        self.text = self.sanitize(text);
+        self.cursor = self.text.len();
+        self.anchor = None;
+        self.version += 1;
+    }
+
+    /// Forget the undo and redo history.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = EditKind::Other;
+    }
+
+    /// Counter incremented on every change to the text.
+    ///
+    /// Compare before and after an operation to detect edits.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Byte offset of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Byte range of the selection, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        match self.anchor {
+            Some(anchor) if anchor != self.cursor => {
+                Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+            }
+            _ => None,
+        }
+    }
+
+    /// The selected text, if any.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.text[range])
+    }
+
+    /// The text to copy: the selection, or the whole text if nothing is
+    /// selected.
+    pub fn copy_text(&self) -> &str {
+        self.selected_text().unwrap_or(&self.text)
+    }
+
+    /// Select the whole text.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.text.len();
+    }
+
+    /// Clear the selection without moving the cursor.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    fn sanitize(&self, text: &str) -> String {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        if self.multiline {
+            text
+        } else {
+            text.replace('\n', " ")
+        }
+    }
+
+    /// Push an undo snapshot unless this edit continues the previous one.
+    fn record(&mut self, kind: EditKind) {
+        if kind == EditKind::Other || self.last_edit != kind {
+            self.undo.push(Snapshot {
+                text: self.text.clone(),
+                cursor: self.cursor,
+            });
+            if self.undo.len() > UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_edit = kind;
+    }
+
+    /// Remove the selected text, returning `true` if there was a selection.
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some(range) => {
+                self.cursor = range.start;
+                self.text.replace_range(range, "");
+                self.anchor = None;
+                self.version += 1;
+                true
+            }
+            None => {
+                self.anchor = None;
+                false
+            }
+        }
+    }
+
+    /// Insert text at the cursor, replacing the selection.
+    pub fn insert_str(&mut self, text: &str) {
+        let text = self.sanitize(text);
+        if text.is_empty() && self.selection().is_none() {
+            return;
+        }
+        // Typing a word is one undo step; whitespace starts a new one
+        let kind = if text.chars().count() == 1
+            && !text.starts_with(char::is_whitespace)
+            && self.selection().is_none()
+        {
+            EditKind::Insert
+        } else {
+            EditKind::Other
+        };
+        self.record(kind);
+        self.delete_selection();
+        self.text.insert_str(self.cursor, &text);
+        self.cursor += text.len();
+        self.version += 1;
+    }
+
+    /// Insert a character at the cursor, replacing the selection.
+    pub fn insert_char(&mut self, c: char) {
+        let mut buf = [0u8; 4];
+        self.insert_str(c.encode_utf8(&mut buf));
+    }
+
+    /// Delete from the cursor to `target`, or the selection if there is one.
+    fn delete_to(&mut self, target: usize) {
+        if self.selection().is_some() {
+            self.record(EditKind::Other);
+            self.delete_selection();
+            return;
+        }
+        let range = self.cursor.min(target)..self.cursor.max(target);
+        if range.is_empty() {
+            return;
+        }
+        self.record(EditKind::Other);
+        self.cursor = range.start;
+        self.text.replace_range(range, "");
+        self.version += 1;
+    }
+
+    /// Delete the grapheme before the cursor (or the selection).
+    pub fn delete_backward(&mut self) {
+        self.delete_to(self.prev_grapheme(self.cursor));
+    }
+
+    /// Delete the grapheme after the cursor (or the selection).
+    pub fn delete_forward(&mut self) {
+        self.delete_to(self.next_grapheme(self.cursor));
+    }
+
+    /// Delete the word before the cursor (or the selection).
+    pub fn delete_word_backward(&mut self) {
+        self.delete_to(self.prev_word(self.cursor));
+    }
+
+    /// Delete the word after the cursor (or the selection).
+    pub fn delete_word_forward(&mut self) {
+        self.delete_to(self.next_word(self.cursor));
+    }
+
+    /// Undo the last edit.
+    ///
+    /// Returns `true` if there was something to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(snapshot) => {
+                let current = Snapshot {
+                    text: std::mem::replace(&mut self.text, snapshot.text),
+                    cursor: self.cursor,
+                };
+                self.redo.push(current);
+                self.cursor = snapshot.cursor;
+                self.anchor = None;
+                self.last_edit = EditKind::Other;
+                self.version += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Redo the last undone edit.
+    ///
+    /// Returns `true` if there was something to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(snapshot) => {
+                let current = Snapshot {
+                    text: std::mem::replace(&mut self.text, snapshot.text),
+                    cursor: self.cursor,
+                };
+                self.undo.push(current);
+                self.cursor = snapshot.cursor;
+                self.anchor = None;
+                self.last_edit = EditKind::Other;
+                self.version += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // --- Boundaries ---
+
+    fn prev_grapheme(&self, index: usize) -> usize {
+        self.text[..index]
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    fn next_grapheme(&self, index: usize) -> usize {
+        self.text[index..]
+            .graphemes(true)
+            .next()
+            .map(|g| index + g.len())
+            .unwrap_or(index)
+    }
+
+    fn prev_word(&self, index: usize) -> usize {
+        let mut graphemes = self.text[..index].grapheme_indices(true).rev().peekable();
+        let mut position = index;
+        while let Some((i, _)) = graphemes.next_if(|(_, g)| !is_word(g)) {
+            position = i;
+        }
+        while let Some((i, _)) = graphemes.next_if(|(_, g)| is_word(g)) {
+            position = i;
+        }
+        position
+    }
+
+    fn next_word(&self, index: usize) -> usize {
+        let mut graphemes = self.text[index..].graphemes(true).peekable();
+        let mut position = index;
+        while let Some(g) = graphemes.next_if(|g| !is_word(g)) {
+            position += g.len();
+        }
+        while let Some(g) = graphemes.next_if(|g| is_word(g)) {
+            position += g.len();
+        }
+        position
+    }
+
+    fn line_start(&self, index: usize) -> usize {
+        self.text[..index].rfind('\n').map(|i| i + 1).unwrap_or(0)
+    }
+
+    fn line_end(&self, index: usize) -> usize {
+        self.text[index..]
+            .find('\n')
+            .map(|i| index + i)
+            .unwrap_or(self.text.len())
+    }
+
+    /// Byte offset in the line starting at `start` closest to display `column`.
+    fn offset_at_column(&self, start: usize, column: usize) -> usize {
+        let end = self.line_end(start);
+        let mut width = 0;
+        for (i, g) in self.text[start..end].grapheme_indices(true) {
+            let w = g.width();
+            if width + w > column {
+                return start + i;
+            }
+            width += w;
+        }
+        end
+    }
+
+    /// Line index and display column of the cursor.
+    pub fn cursor_line_col(&self) -> (usize, usize) {
+        let line = self.text[..self.cursor].matches('\n').count();
+        let column = self.text[self.line_start(self.cursor)..self.cursor].width();
+        (line, column)
+    }
+
+    /// Byte ranges of each line (without the newline).
+    pub fn line_ranges(&self) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut start = 0;
+        for (i, _) in self.text.match_indices('\n') {
+            ranges.push(start..i);
+            start = i + 1;
+        }
+        ranges.push(start..self.text.len());
+        ranges
+    }
+
+    // --- Movement ---
+
+    fn move_to(&mut self, position: usize, select: bool) {
+        if select {
+            if self.anchor.is_none() {
+                self.anchor = Some(self.cursor);
+            }
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+        self.last_edit = EditKind::Other;
+    }
+
+    /// Move the cursor one grapheme left.
+    pub fn move_left(&mut self, select: bool) {
+        let position = match self.selection() {
+            Some(range) if !select => range.start,
+            _ => self.prev_grapheme(self.cursor),
+        };
+        self.move_to(position, select);
+    }
+
+    /// Move the cursor one grapheme right.
+    pub fn move_right(&mut self, select: bool) {
+        let position = match self.selection() {
+            Some(range) if !select => range.end,
+            _ => self.next_grapheme(self.cursor),
+        };
+        self.move_to(position, select);
+    }
+
+    /// Move the cursor to the start of the previous word.
+    pub fn move_word_left(&mut self, select: bool) {
+        self.move_to(self.prev_word(self.cursor), select);
+    }
+
+    /// Move the cursor to the end of the next word.
+    pub fn move_word_right(&mut self, select: bool) {
+        self.move_to(self.next_word(self.cursor), select);
+    }
+
+    /// Move the cursor to the start of the line.
+    pub fn move_home(&mut self, select: bool) {
+        self.move_to(self.line_start(self.cursor), select);
+    }
+
+    /// Move the cursor to the end of the line.
+    pub fn move_end(&mut self, select: bool) {
+        self.move_to(self.line_end(self.cursor), select);
+    }
+
+    /// Move the cursor to the start of the text.
+    pub fn move_start(&mut self, select: bool) {
+        self.move_to(0, select);
+    }
+
+    /// Move the cursor to the end of the text.
+    pub fn move_to_end(&mut self, select: bool) {
+        self.move_to(self.text.len(), select);
+    }
+
+    /// Move the cursor up or down by `lines`, keeping the display column.
+    pub fn move_lines(&mut self, lines: isize, select: bool) {
+        let (line, column) = self.cursor_line_col();
+        let ranges = self.line_ranges();
+        let target = line
+            .saturating_add_signed(lines)
+            .min(ranges.len().saturating_sub(1));
+        let position = self.offset_at_column(ranges[target].start, column);
+        self.move_to(position, select);
+    }
+
+    /// Handle an editing key.
+    ///
+    /// Enter and vertical movement are only handled by multi-line buffers.
+    /// `page` is the number of lines moved by PageUp/PageDown.
+    pub fn handle_key(&mut self, key: &KeyEvent, page: usize) -> EventResult {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let page = page.max(1) as isize;
+
+        match key.code {
+            KeyCode::Char('z') if ctrl && shift => {
+                self.redo();
+            }
+            KeyCode::Char('Z') if ctrl => {
+                self.redo();
+            }
+            KeyCode::Char('z') if ctrl => {
+                self.undo();
+            }
+            KeyCode::Char('y') if ctrl => {
+                self.redo();
+            }
+            KeyCode::Char('a') if ctrl => self.select_all(),
+            KeyCode::Char('w') if ctrl => self.delete_word_backward(),
+            KeyCode::Char('b') if alt => self.move_word_left(shift),
+            KeyCode::Char('f') if alt => self.move_word_right(shift),
+            KeyCode::Char(c) if !ctrl && !alt => self.insert_char(c),
+            KeyCode::Enter if self.multiline => self.insert_char('\n'),
+            KeyCode::Backspace if ctrl || alt => self.delete_word_backward(),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete if ctrl || alt => self.delete_word_forward(),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::Left if ctrl || alt => self.move_word_left(shift),
+            KeyCode::Left => self.move_left(shift),
+            KeyCode::Right if ctrl || alt => self.move_word_right(shift),
+            KeyCode::Right => self.move_right(shift),
+            KeyCode::Home if ctrl => self.move_start(shift),
+            KeyCode::Home => self.move_home(shift),
+            KeyCode::End if ctrl => self.move_to_end(shift),
+            KeyCode::End => self.move_end(shift),
+            KeyCode::Up if self.multiline => self.move_lines(-1, shift),
+            KeyCode::Down if self.multiline => self.move_lines(1, shift),
+            KeyCode::PageUp if self.multiline => self.move_lines(-page, shift),
+            KeyCode::PageDown if self.multiline => self.move_lines(page, shift),
+            _ => return EventResult::Unhandled,
+        }
+        EventResult::Handled
+    }
+}
+
+impl Default for TextBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a grapheme is part of a word for word-wise navigation.
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Build a styled line for `text[range]`, highlighting the selection and
+/// optionally masking every grapheme.
+///
+/// Returns the line and the display column of `cursor` within it.
+pub(crate) fn styled_line(
+    text: &str,
+    range: Range<usize>,
+    selection: Option<&Range<usize>>,
+    mask: Option<char>,
+    cursor: usize,
+    style: Style,
+    selection_style: Style,
+) -> (Line<'static>, usize) {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut current = String::new();
+    let mut current_selected = false;
+    let mut cursor_column = 0;
+    let mut column = 0;
+    let mut mask_buf = [0u8; 4];
+
+    for (i, g) in text[range.clone()].grapheme_indices(true) {
+        let offset = range.start + i;
+        let selected = selection.is_some_and(|s| s.contains(&offset));
+        if selected != current_selected && !current.is_empty() {
+            let style = if current_selected {
+                selection_style
+            } else {
+                style
+            };
+            spans.push(Span::styled(std::mem::take(&mut current), style));
+        }
+        current_selected = selected;
+
+        let shown: &str = match mask {
+            Some(c) => c.encode_utf8(&mut mask_buf),
+            None => g,
+        };
+        current.push_str(shown);
+        column += shown.width();
+        if offset < cursor {
+            cursor_column = column;
+        }
+    }
+
+    if !current.is_empty() {
+        let style = if current_selected {
+            selection_style
+        } else {
+            style
+        };
+        spans.push(Span::styled(current, style));
+    }
+
+    (Line::from(spans), cursor_column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_editing() {
+        let mut buffer = TextBuffer::new();
+        // "e" + combining acute accent, then a family emoji (ZWJ sequence)
+        buffer.insert_str("ae\u{301}👨‍👩‍👧");
+        buffer.delete_backward();
+        assert_eq!(buffer.text(), "ae\u{301}");
+        buffer.move_left(false);
+        assert_eq!(buffer.cursor(), 1);
+        buffer.delete_forward();
+        assert_eq!(buffer.text(), "a");
+    }
+
+    #[test]
+    fn test_word_navigation() {
+        let mut buffer = TextBuffer::new();
+        buffer.insert_str("read attr.on_off now");
+        buffer.move_word_left(false);
+        assert_eq!(buffer.cursor(), 17);
+        buffer.move_word_left(false);
+        assert_eq!(buffer.cursor(), 10);
+        buffer.move_word_left(true);
+        assert_eq!(buffer.selected_text(), Some("attr."));
+        buffer.move_start(false);
+        buffer.move_word_right(false);
+        assert_eq!(buffer.cursor(), 4);
+        buffer.delete_word_forward();
+        assert_eq!(buffer.text(), "read.on_off now");
+    }
+
+    #[test]
+    fn test_undo_redo_coalesces_typing() {
+        let mut buffer = TextBuffer::new();
+        for c in "hello world".chars() {
+            buffer.insert_char(c);
+        }
+        buffer.undo();
+        assert_eq!(buffer.text(), "hello ");
+        buffer.undo();
+        assert_eq!(buffer.text(), "hello");
+        buffer.undo();
+        assert_eq!(buffer.text(), "");
+        assert!(!buffer.undo());
+        buffer.redo();
+        assert_eq!(buffer.text(), "hello");
+    }
+
+    #[test]
+    fn test_selection_replace_and_paste() {
+        let mut buffer = TextBuffer::new();
+        buffer.insert_str("line one\nline two");
+        assert_eq!(buffer.text(), "line one line two");
+
+        buffer.select_all();
+        buffer.insert_char('x');
+        assert_eq!(buffer.text(), "x");
+    }
+
+    #[test]
+    fn test_multiline_vertical_movement() {
+        let mut buffer = TextBuffer::multiline();
+        buffer.insert_str("abcdef\nxy\n日本語");
+        assert_eq!(buffer.cursor_line_col(), (2, 6));
+
+        buffer.move_lines(-1, false);
+        assert_eq!(buffer.cursor_line_col(), (1, 2));
+        buffer.move_lines(-1, false);
+        assert_eq!(buffer.cursor_line_col(), (0, 2));
+        buffer.move_end(false);
+        buffer.move_lines(2, false);
+        assert_eq!(buffer.cursor_line_col(), (2, 6));
+    }
+}
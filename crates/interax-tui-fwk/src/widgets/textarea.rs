@@ -0,0 +1,237 @@
+//! Multi-line text area widget.
+
+use std::cell::Cell;
+
+use ratatui::{
+    layout::{Position, Rect},
+    text::{Line, Text},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::text::{styled_line, TextBuffer};
+use super::{block_title, is_copy_key};
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::Event;
+use crate::focus::EventResult;
+use crate::theme::Theme;
+
+/// A focusable multi-line text editor.
+///
+/// Shares its editing behavior with `TextInput` and adds Enter for new
+/// lines and Up/Down/PageUp/PageDown for vertical movement. Only the
+/// visible lines are rendered; the view scrolls on both axes to keep the
+/// cursor visible.
+///
+/// As a `Component`, the text area only handles events while it is
+/// focused. Tab and Esc are left unhandled so the parent can move focus.
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::TextArea;
+///
+/// let payload = TextArea::new("payload")
+///     .title("Payload")
+///     .placeholder("{ \"on\": true }");
+/// ```
+pub struct TextArea {
+    focus_id: String,
+    title: Option<String>,
+    placeholder: Option<String>,
+    buffer: TextBuffer,
+    /// First visible line, updated while drawing.
+    scroll_y: Cell<usize>,
+    /// Horizontal scroll offset in columns, updated while drawing.
+    scroll_x: Cell<usize>,
+    /// Number of visible lines from the last draw (used for PageUp/PageDown).
+    viewport: Cell<usize>,
+}
+
+impl TextArea {
+    /// Create a new empty text area with the given focus ID.
+    pub fn new(focus_id: impl Into<String>) -> Self {
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            placeholder: None,
+            buffer: TextBuffer::multiline(),
+            scroll_y: Cell::new(0),
+            scroll_x: Cell::new(0),
+            viewport: Cell::new(1),
+        }
+    }
+
+    /// Set the title shown in the border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the placeholder shown while the text area is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the initial value.
+    pub fn value(mut self, value: &str) -> Self {
+        self.buffer.set_text(value);
+        self.buffer.clear_history();
+        self
+    }
+
+    /// Get the current text.
+    pub fn text(&self) -> &str {
+        self.buffer.text()
+    }
+
+    /// Replace the text.
+    pub fn set_text(&mut self, text: &str) {
+        self.buffer.set_text(text);
+    }
+
+    /// Access the underlying editing buffer.
+    pub fn buffer(&self) -> &TextBuffer {
+        &self.buffer
+    }
+
+    /// Access the underlying editing buffer mutably.
+    pub fn buffer_mut(&mut self) -> &mut TextBuffer {
+        &mut self.buffer
+    }
+
+    /// The text to copy if `event` is the copy key (Alt+C or
+    /// Ctrl+Insert): the selection, or the whole text.
+    ///
+    /// As a `Component`, the text area copies it to `AppContext::clipboard`.
+    pub fn copy_text(&self, event: &Event) -> Option<String> {
+        if !is_copy_key(event) {
+            return None;
+        }
+        Some(self.buffer.copy_text().to_string())
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the text area is embedded in a `Tab`; the caller is
+    /// responsible for only forwarding events while it is focused.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        match event {
+            Event::Key(key) => self.buffer.handle_key(key, self.viewport.get()),
+            Event::Paste(text) => {
+                self.buffer.insert_str(text);
+                EventResult::Handled
+            }
+            _ => EventResult::Unhandled,
+        }
+    }
+
+    /// Render the text area with an explicit focus state and theme.
+    ///
+    /// Use this when the text area is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border(focused));
+        if let Some(title) = &self.title {
+            block = block.title(block_title(title, "", area.width));
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+        let height = inner.height as usize;
+        let width = inner.width as usize;
+        self.viewport.set(height);
+
+        if self.buffer.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                frame.render_widget(
+                    Paragraph::new(placeholder.as_str()).style(theme.muted),
+                    inner,
+                );
+            }
+            self.scroll_y.set(0);
+            self.scroll_x.set(0);
+            if focused {
+                frame.set_cursor_position(Position::new(inner.x, inner.y));
+            }
+            return;
+        }
+
+        // Scroll both axes so the cursor stays visible
+        let (cursor_line, cursor_column) = self.buffer.cursor_line_col();
+        let mut scroll_y = self.scroll_y.get();
+        if cursor_line < scroll_y {
+            scroll_y = cursor_line;
+        } else if cursor_line >= scroll_y + height {
+            scroll_y = cursor_line + 1 - height;
+        }
+        self.scroll_y.set(scroll_y);
+
+        let mut scroll_x = self.scroll_x.get();
+        if cursor_column < scroll_x {
+            scroll_x = cursor_column;
+        } else if cursor_column >= scroll_x + width {
+            scroll_x = cursor_column + 1 - width;
+        }
+        self.scroll_x.set(scroll_x);
+
+        let text = self.buffer.text();
+        let selection = self.buffer.selection();
+        let lines: Vec<Line> = self
+            .buffer
+            .line_ranges()
+            .into_iter()
+            .skip(scroll_y)
+            .take(height)
+            .map(|range| {
+                styled_line(
+                    text,
+                    range,
+                    selection.as_ref(),
+                    None,
+                    self.buffer.cursor(),
+                    theme.text,
+                    theme.text_selection,
+                )
+                .0
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(Text::from(lines)).scroll((0, scroll_x as u16));
+        frame.render_widget(paragraph, inner);
+
+        if focused {
+            let x = inner.x + (cursor_column - scroll_x) as u16;
+            let y = inner.y + (cursor_line - scroll_y) as u16;
+            frame.set_cursor_position(Position::new(x, y));
+        }
+    }
+}
+
+impl Component for TextArea {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut AppContext) -> EventResult {
+        if !ctx.focus().is_focused(&self.focus_id) {
+            return EventResult::Unhandled;
+        }
+        if let Some(text) = self.copy_text(event) {
+            ctx.clipboard().copy(text);
+            return EventResult::Handled;
+        }
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+}
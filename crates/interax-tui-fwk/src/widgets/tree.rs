@@ -0,0 +1,394 @@
+//! Expandable tree view widget.
+
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use super::{block_title, ScrollCursor};
+use crate::component::Component;
+use crate::context::{AppContext, DrawContext};
+use crate::event::{Event, KeyCode};
+use crate::focus::EventResult;
+use crate::theme::Theme;
+use crate::unicode::truncate;
+
+/// A node in a `TreeView`.
+#[derive(Debug, Clone)]
+pub struct TreeNode {
+    /// Identifier of this node, unique among its siblings.
+    pub id: String,
+    /// Display label.
+    pub label: String,
+    /// Child nodes.
+    pub children: Vec<TreeNode>,
+    /// Whether the children are shown.
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    /// Create a new collapsed node with no children.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: Vec::new(),
+            expanded: false,
+        }
+    }
+
+    /// Add a child node, returning the node (builder style).
+    pub fn with_child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Check if this node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn set_expanded_recursive(&mut self, expanded: bool) {
+        self.expanded = expanded;
+        for child in &mut self.children {
+            child.set_expanded_recursive(expanded);
+        }
+    }
+}
+
+/// A row of the flattened, visible tree.
+struct VisibleNode<'a> {
+    depth: usize,
+    node: &'a TreeNode,
+    /// Child indices from the roots down to this node.
+    path: Vec<usize>,
+}
+
+/// A focusable tree with expandable nodes.
+///
+/// Only the visible rows are rendered, so large trees stay cheap.
+///
+/// # Key bindings
+///
+/// - Up/Down/PageUp/PageDown/Home/End: Move the cursor
+/// - Right: Expand the node, or move to its first child if expanded
+/// - Left: Collapse the node, or move to its parent if collapsed
+/// - Enter/Space: Toggle the node
+///
+/// # Example
+///
+/// ```ignore
+/// use interax_tui_fwk::widgets::{TreeNode, TreeView};
+///
+/// let tree = TreeView::new("topology").title("Topology").roots(vec![
+///     TreeNode::new("node-1", "Node 1")
+///         .with_child(TreeNode::new("ep-0", "Endpoint 0"))
+///         .with_child(TreeNode::new("ep-1", "Endpoint 1")),
+/// ]);
+/// ```
+pub struct TreeView {
+    focus_id: String,
+    title: Option<String>,
+    roots: Vec<TreeNode>,
+    cursor: ScrollCursor,
+}
+
+impl TreeView {
+    /// Create a new empty tree with the given focus ID.
+    pub fn new(focus_id: impl Into<String>) -> Self {
+        Self {
+            focus_id: focus_id.into(),
+            title: None,
+            roots: Vec::new(),
+            cursor: ScrollCursor::default(),
+        }
+    }
+
+    /// Set the title shown in the tree border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the initial root nodes.
+    pub fn roots(mut self, roots: Vec<TreeNode>) -> Self {
+        self.set_roots(roots);
+        self
+    }
+
+    /// Replace the root nodes.
+    pub fn set_roots(&mut self, roots: Vec<TreeNode>) {
+        self.roots = roots;
+        let len = self.visible_len();
+        self.cursor.set(self.cursor.cursor(), len);
+    }
+
+    /// Get the root nodes.
+    pub fn get_roots(&self) -> &[TreeNode] {
+        &self.roots
+    }
+
+    /// Expand every node.
+    pub fn expand_all(&mut self) {
+        for root in &mut self.roots {
+            root.set_expanded_recursive(true);
+        }
+    }
+
+    /// Collapse every node.
+    pub fn collapse_all(&mut self) {
+        for root in &mut self.roots {
+            root.set_expanded_recursive(false);
+        }
+        let len = self.visible_len();
+        self.cursor.set(self.cursor.cursor(), len);
+    }
+
+    /// The node under the cursor.
+    pub fn cursor_node(&self) -> Option<&TreeNode> {
+        self.visible()
+            .into_iter()
+            .nth(self.cursor.cursor())
+            .map(|v| v.node)
+    }
+
+    /// IDs from the root down to the node under the cursor.
+    pub fn cursor_path(&self) -> Vec<&str> {
+        let Some(visible) = self.visible().into_iter().nth(self.cursor.cursor()) else {
+            return Vec::new();
+        };
+        let mut nodes = &self.roots;
+        let mut ids = Vec::with_capacity(visible.path.len());
+        for &index in &visible.path {
+            ids.push(nodes[index].id.as_str());
+            nodes = &nodes[index].children;
+        }
+        ids
+    }
+
+    /// Flatten the expanded part of the tree into rows.
+    fn visible(&self) -> Vec<VisibleNode<'_>> {
+        fn walk<'a>(
+            nodes: &'a [TreeNode],
+            depth: usize,
+            path: &mut Vec<usize>,
+            out: &mut Vec<VisibleNode<'a>>,
+        ) {
+            for (i, node) in nodes.iter().enumerate() {
+                path.push(i);
+                out.push(VisibleNode {
+                    depth,
+                    node,
+                    path: path.clone(),
+                });
+                if node.expanded {
+                    walk(&node.children, depth + 1, path, out);
+                }
+                path.pop();
+            }
+        }
+
+        let mut out = Vec::new();
+        walk(&self.roots, 0, &mut Vec::new(), &mut out);
+        out
+    }
+
+    fn visible_len(&self) -> usize {
+        self.visible().len()
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get_mut(*first)?;
+        for &index in rest {
+            node = node.children.get_mut(index)?;
+        }
+        Some(node)
+    }
+
+    fn cursor_path_indices(&self) -> Option<Vec<usize>> {
+        self.visible()
+            .into_iter()
+            .nth(self.cursor.cursor())
+            .map(|v| v.path)
+    }
+
+    /// Move the cursor to the row with the given path.
+    fn move_to(&mut self, path: &[usize]) {
+        let visible = self.visible();
+        if let Some(position) = visible.iter().position(|v| v.path == path) {
+            let len = visible.len();
+            self.cursor.set(position, len);
+        }
+    }
+
+    fn expand(&mut self) {
+        let Some(path) = self.cursor_path_indices() else {
+            return;
+        };
+        let Some(node) = self.node_mut(&path) else {
+            return;
+        };
+        if node.is_leaf() {
+            return;
+        }
+        if node.expanded {
+            let len = self.visible_len();
+            self.cursor.move_by(1, len);
+        } else {
+            node.expanded = true;
+        }
+    }
+
+    fn collapse(&mut self) {
+        let Some(path) = self.cursor_path_indices() else {
+            return;
+        };
+        let Some(node) = self.node_mut(&path) else {
+            return;
+        };
+        if node.expanded {
+            node.expanded = false;
+        } else if path.len() > 1 {
+            self.move_to(&path[..path.len() - 1]);
+        }
+    }
+
+    fn toggle(&mut self) {
+        let Some(path) = self.cursor_path_indices() else {
+            return;
+        };
+        if let Some(node) = self.node_mut(&path) {
+            node.expanded = !node.expanded && !node.is_leaf();
+        }
+    }
+
+    /// Handle an input event without an application context.
+    ///
+    /// Use this when the tree is embedded in a `Tab`.
+    pub fn handle_input(&mut self, event: &Event) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Unhandled;
+        };
+
+        let len = self.visible_len();
+        if self.cursor.handle_key(key.code, len) {
+            return EventResult::Handled;
+        }
+
+        match key.code {
+            KeyCode::Right => self.expand(),
+            KeyCode::Left => self.collapse(),
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle(),
+            _ => return EventResult::Unhandled,
+        }
+        EventResult::Handled
+    }
+
+    /// Render the tree with an explicit focus state and theme.
+    ///
+    /// Use this when the tree is embedded in a `Tab`.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border(focused));
+        if let Some(title) = &self.title {
+            block = block.title(block_title(
+                title,
+                if focused { " ●" } else { " ○" },
+                area.width,
+            ));
+        }
+
+        let visible = self.visible();
+        let height = area.height.saturating_sub(2) as usize;
+        let width = area.width.saturating_sub(2) as usize;
+        let range = self.cursor.visible_range(visible.len(), height);
+        let cursor = self.cursor.cursor();
+
+        let items: Vec<ListItem> = range
+            .map(|position| {
+                let row = &visible[position];
+                let marker = if row.node.is_leaf() {
+                    "  "
+                } else if row.node.expanded {
+                    "▾ "
+                } else {
+                    "▸ "
+                };
+                let style = if position == cursor {
+                    theme.cursor(focused)
+                } else {
+                    theme.text
+                };
+                // Indentation and marker take two columns per level
+                let indent = "  ".repeat(row.depth);
+                let label = truncate(&row.node.label, width.saturating_sub(2 * row.depth + 2));
+                ListItem::new(format!("{}{}{}", indent, marker, label)).style(style)
+            })
+            .collect();
+
+        frame.render_widget(List::new(items).block(block), area);
+    }
+}
+
+impl Component for TreeView {
+    fn draw(&self, frame: &mut Frame, area: Rect, ctx: &DrawContext) {
+        let focused = ctx.focus().is_focused(&self.focus_id);
+        self.render(frame, area, focused, ctx.theme());
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut AppContext) -> EventResult {
+        self.handle_input(event)
+    }
+
+    fn focus_id(&self) -> Option<&str> {
+        Some(&self.focus_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(crossterm::event::KeyEvent::new(
+            code,
+            crate::event::KeyModifiers::NONE,
+        ))
+    }
+
+    fn tree() -> TreeView {
+        TreeView::new("tree").roots(vec![
+            TreeNode::new("node-1", "Node 1")
+                .with_child(TreeNode::new("ep-0", "Endpoint 0"))
+                .with_child(TreeNode::new("ep-1", "Endpoint 1")),
+            TreeNode::new("node-2", "Node 2"),
+        ])
+    }
+
+    #[test]
+    fn test_tree_expand_collapse() {
+        let mut tree = tree();
+        assert_eq!(tree.visible_len(), 2);
+
+        tree.handle_input(&key(KeyCode::Right));
+        assert_eq!(tree.visible_len(), 4);
+
+        tree.handle_input(&key(KeyCode::Right));
+        assert_eq!(tree.cursor_path(), vec!["node-1", "ep-0"]);
+
+        tree.handle_input(&key(KeyCode::Down));
+        assert_eq!(tree.cursor_node().map(|n| n.id.as_str()), Some("ep-1"));
+
+        // Leaf: Left moves to the parent, then collapses it
+        tree.handle_input(&key(KeyCode::Left));
+        assert_eq!(tree.cursor_path(), vec!["node-1"]);
+        tree.handle_input(&key(KeyCode::Left));
+        assert_eq!(tree.visible_len(), 2);
+
+        tree.handle_input(&key(KeyCode::End));
+        assert_eq!(tree.cursor_node().map(|n| n.id.as_str()), Some("node-2"));
+    }
+}
@@ -6,22 +6,24 @@
 //! - Drawing tab bar and content using DrawContext
 //! - Navigating tabs using AppContext
 //! - Enabling/disabling tabs at runtime
+//! - Nested sub-tabs via `TabGroup`
 //!
 //! Controls:
 //! - Tab/Shift+Tab: Navigate between tabs
-//! - 1-3: Select specific tabs
+//! - [/]: Navigate between sub-tabs (in the Endpoints tab)
+//! - 1-4: Select specific tabs
 //! - d: Toggle disable on the Settings tab
 //! - q/Ctrl+C: Quit
 
 use interax_tui_fwk::{
     AppBuilder, AppContext, Component, DrawContext, Event, EventResult, KeyCode, KeyModifiers,
-    MainUi, Tab,
+    MainUi, Tab, TabGroup,
 };
 use ratatui::{
+    Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     widgets::{Block, Borders, Paragraph},
-    Frame,
 };
 
 // =============================================================================
@@ -137,6 +139,65 @@ impl Tab for SettingsTab {
     }
 }
 
+/// A section of the Endpoints tab (attributes, commands or events).
+struct EndpointSectionTab {
+    id: &'static str,
+    title: &'static str,
+    description: &'static str,
+}
+
+impl EndpointSectionTab {
+    fn new(id: &'static str, title: &'static str, description: &'static str) -> Self {
+        Self {
+            id,
+            title,
+            description,
+        }
+    }
+}
+
+impl Tab for EndpointSectionTab {
+    fn id(&self) -> &str {
+        self.id
+    }
+
+    fn title(&self) -> &str {
+        self.title
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let content = Paragraph::new(self.description)
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .title(self.title)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Magenta)),
+            );
+        frame.render_widget(content, area);
+    }
+}
+
+/// Endpoints tab with Attributes, Commands and Events sub-pages.
+fn endpoints_tab() -> TabGroup {
+    TabGroup::new("endpoints", "Endpoints")
+        .with_tab(EndpointSectionTab::new(
+            "attributes",
+            "Attributes",
+            "Attributes exposed by the selected endpoint.",
+        ))
+        .with_tab(EndpointSectionTab::new(
+            "commands",
+            "Commands",
+            "Commands accepted by the selected endpoint.",
+        ))
+        .with_tab(EndpointSectionTab::new(
+            "events",
+            "Events",
+            "Events emitted by the selected endpoint.",
+        ))
+}
+
 // =============================================================================
 // Main Application
 // =============================================================================
@@ -180,7 +241,7 @@ impl Component for TabsApp {
             "disabled"
         };
         let footer_text = format!(
-            "Tab/Shift+Tab: Navigate | 1-3: Jump to tab | d: Toggle settings ({}) | q: Quit",
+            "Tab/Shift+Tab: Navigate | [/]: Sub-tabs | 1-4: Jump to tab | d: Toggle settings ({}) | q: Quit",
             settings_status
         );
         let footer = Paragraph::new(footer_text)
@@ -228,6 +289,10 @@ impl Component for TabsApp {
                     ctx.tabs().select(2);
                     EventResult::Handled
                 }
+                KeyCode::Char('4') => {
+                    ctx.tabs().select(3);
+                    EventResult::Handled
+                }
                 // Toggle settings tab enabled/disabled
                 KeyCode::Char('d') => {
                     let currently_enabled = ctx.tabs().is_enabled("settings");
@@ -257,6 +322,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .add_tab(HomeTab)
         .add_tab(DashboardTab::new())
         .add_tab(SettingsTab::new())
+        .add_tab(endpoints_tab())
         .mouse_capture(false) // Disable mouse for this example
         .build()?;
 